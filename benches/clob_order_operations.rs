@@ -4,16 +4,19 @@
 //! - Limit order building (price validation, decimal conversion, order struct creation)
 //! - Order signing (EIP-712 domain construction and cryptographic signing)
 //! - Order serialization (converting `SignedOrder` to JSON for API submission)
+//! - Batch signing (1000 orders with a per-order domain versus a cached domain separator)
 
 use std::str::FromStr as _;
 
 use alloy::signers::Signer as _;
 use alloy::signers::local::PrivateKeySigner;
+use alloy::sol_types::SolStruct as _;
 use criterion::{Criterion, criterion_group, criterion_main};
 use polymarket_client_sdk::POLYGON;
 use polymarket_client_sdk::auth::Normal;
 use polymarket_client_sdk::auth::state::Authenticated;
 use polymarket_client_sdk::clob::Client;
+use polymarket_client_sdk::clob::signing::{CachedDomainSigner, OrderSigner as _, order_domain};
 use polymarket_client_sdk::clob::types::{OrderType, Side, TickSize};
 use polymarket_client_sdk::types::{Decimal, U256};
 use rust_decimal_macros::dec;
@@ -125,6 +128,61 @@ fn bench_order_signing(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark signing a batch of orders, recomputing the EIP-712 domain for every order versus
+/// reusing the cached domain separator
+fn bench_batch_signing(c: &mut Criterion) {
+    const ORDERS: usize = 1000;
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    let (client, signer) = runtime.block_on(setup_client());
+    let token_id = U256::from_str(TOKEN_ID).expect("valid token ID");
+    let cached = CachedDomainSigner::new(signer.clone()).expect("signer has a chain id");
+
+    let mut group = c.benchmark_group("clob_order_operations/batch_signing");
+
+    let order = runtime.block_on(async {
+        client
+            .limit_order()
+            .token_id(token_id)
+            .side(Side::Buy)
+            .price(dec!(0.50))
+            .size(dec!(100.0))
+            .build()
+            .await
+            .expect("build succeeds")
+            .order
+    });
+
+    group.bench_function("per_order_domain", |b| {
+        b.iter(|| {
+            runtime.block_on(async {
+                for _ in 0..ORDERS {
+                    let domain = order_domain(POLYGON, false).expect("domain exists");
+                    let hash = order.eip712_signing_hash(&domain);
+                    std::hint::black_box(signer.sign_hash(&hash).await.expect("sign succeeds"));
+                }
+            });
+        });
+    });
+
+    group.bench_function("cached_domain", |b| {
+        b.iter(|| {
+            runtime.block_on(async {
+                for _ in 0..ORDERS {
+                    std::hint::black_box(
+                        cached
+                            .sign_order(&order, false)
+                            .await
+                            .expect("sign succeeds"),
+                    );
+                }
+            });
+        });
+    });
+
+    group.finish();
+}
+
 /// Benchmark order serialization
 fn bench_order_serializing(c: &mut Criterion) {
     let runtime = tokio::runtime::Runtime::new().expect("runtime");
@@ -163,6 +221,7 @@ criterion_group!(
     order_operations_benches,
     bench_order_building,
     bench_order_signing,
+    bench_batch_signing,
     bench_order_serializing,
 );
 
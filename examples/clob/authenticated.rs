@@ -29,6 +29,7 @@ use polymarket_client_sdk::clob::types::request::{
     BalanceAllowanceRequest, OrdersRequest, TradesRequest, UpdateBalanceAllowanceRequest,
     UserRewardsEarningRequest,
 };
+use polymarket_client_sdk::clob::types::response::NotCanceledReason;
 use polymarket_client_sdk::clob::types::{Amount, OrderType, Side};
 use polymarket_client_sdk::clob::{Client, Config};
 use polymarket_client_sdk::types::{Decimal, U256};
@@ -154,7 +155,17 @@ async fn main() -> anyhow::Result<()> {
     }
 
     match client.cancel_orders(&[order_id]).await {
-        Ok(r) => info!(endpoint = "cancel_orders", result = ?r),
+        Ok(r) => {
+            info!(endpoint = "cancel_orders", canceled = r.canceled.len());
+            for (id, reason) in r.not_canceled_reasons() {
+                match reason {
+                    NotCanceledReason::Matched => {
+                        info!(endpoint = "cancel_orders", order_id = %id, "already filled");
+                    }
+                    reason => info!(endpoint = "cancel_orders", order_id = %id, reason = ?reason),
+                }
+            }
+        }
         Err(e) => error!(endpoint = "cancel_orders", error = %e),
     }
 
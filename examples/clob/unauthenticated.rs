@@ -250,6 +250,16 @@ async fn main() -> anyhow::Result<()> {
             Err(e) => error!(endpoint = "neg_risk", token_id = %token_id, error = %e),
         }
 
+        match client.tick_sizes(&[token_id]).await {
+            Ok(tick_sizes) => info!(endpoint = "tick_sizes", count = tick_sizes.len()),
+            Err(e) => error!(endpoint = "tick_sizes", error = %e),
+        }
+
+        match client.neg_risks(&[token_id]).await {
+            Ok(neg_risks) => info!(endpoint = "neg_risks", count = neg_risks.len()),
+            Err(e) => error!(endpoint = "neg_risks", error = %e),
+        }
+
         match client.fee_rate_bps(token_id).await {
             Ok(fee_rate) => info!(
                 endpoint = "fee_rate_bps",
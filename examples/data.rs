@@ -23,7 +23,7 @@ use polymarket_client_sdk::data::types::request::{
     HoldersRequest, LiveVolumeRequest, OpenInterestRequest, PositionsRequest, TradedRequest,
     TraderLeaderboardRequest, TradesRequest, ValueRequest,
 };
-use polymarket_client_sdk::data::types::{LeaderboardCategory, TimePeriod};
+use polymarket_client_sdk::data::types::{LeaderboardCategory, Period};
 use polymarket_client_sdk::types::{Address, B256, address, b256};
 use tracing::{debug, error, info, warn};
 use tracing_subscriber::EnvFilter;
@@ -63,7 +63,7 @@ async fn main() -> anyhow::Result<()> {
         .leaderboard(
             &TraderLeaderboardRequest::builder()
                 .category(LeaderboardCategory::Overall)
-                .time_period(TimePeriod::Week)
+                .time_period(Period::Week)
                 .limit(10)?
                 .build(),
         )
@@ -290,7 +290,7 @@ async fn main() -> anyhow::Result<()> {
     match client
         .builder_leaderboard(
             &BuilderLeaderboardRequest::builder()
-                .time_period(TimePeriod::Week)
+                .time_period(Period::Week)
                 .limit(5)?
                 .build(),
         )
@@ -314,7 +314,7 @@ async fn main() -> anyhow::Result<()> {
     match client
         .builder_volume(
             &BuilderVolumeRequest::builder()
-                .time_period(TimePeriod::Week)
+                .time_period(Period::Week)
                 .build(),
         )
         .await
@@ -18,7 +18,6 @@
 use std::fs::File;
 
 use polymarket_client_sdk::gamma::Client;
-use polymarket_client_sdk::gamma::types::ParentEntityType;
 use polymarket_client_sdk::gamma::types::request::{
     CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest, EventByIdRequest,
     EventBySlugRequest, EventTagsRequest, EventsRequest, MarketByIdRequest, MarketBySlugRequest,
@@ -26,6 +25,7 @@ use polymarket_client_sdk::gamma::types::request::{
     RelatedTagsBySlugRequest, SearchRequest, SeriesByIdRequest, SeriesListRequest, TagByIdRequest,
     TagBySlugRequest, TagsRequest, TeamsRequest,
 };
+use polymarket_client_sdk::gamma::types::{ParentEntityType, SortField};
 use tracing::{debug, info};
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::layer::SubscriberExt as _;
@@ -152,9 +152,9 @@ async fn main() -> anyhow::Result<()> {
             &EventsRequest::builder()
                 .active(true)
                 .limit(20)
-                .order(vec!["volume".to_owned()])
+                .sort_by(SortField::Volume)
                 .ascending(false)
-                .build(),
+                .build()?,
         )
         .await;
 
@@ -61,7 +61,7 @@ async fn stream_events(client: &Client) -> anyhow::Result<()> {
                     .limit(limit)
                     .offset(offset)
                     .build();
-                async move { c.events(&request).await }
+                async move { c.events(&request?).await }
             },
             100,
         )
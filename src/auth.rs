@@ -13,7 +13,7 @@ use reqwest::header::HeaderMap;
 use reqwest::{Body, Request};
 /// Secret string types that redact values in debug output for security.
 pub use secrecy::{ExposeSecret, SecretString};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize, Serializer};
 use sha2::Sha256;
 /// UUID type used for API keys and identifiers.
 pub use uuid::Uuid;
@@ -27,12 +27,30 @@ pub type ApiKey = Uuid;
 /// returned when calling [`crate::clob::Client::create_or_derive_api_key`], [`crate::clob::Client::derive_api_key`], or
 /// [`crate::clob::Client::create_api_key`]. They are used by the [`state::Authenticated`] client to
 /// sign the [`Request`] when making calls to the API.
-#[derive(Clone, Debug, Default, Deserialize)]
+///
+/// Credentials can be serialized to be saved after authenticating, e.g. from
+/// [`crate::clob::Client::credentials`], and deserialized again to authenticate a later session
+/// without signing for them, see [`crate::clob::Client::with_credentials`]. Serializing exposes
+/// the secret and passphrase in plain text, so store the output as securely as the private key
+/// itself, e.g. in a secrets manager or an encrypted file that only the owner can read.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Credentials {
     #[serde(alias = "apiKey")]
     pub(crate) key: ApiKey,
+    #[serde(serialize_with = "serialize_secret")]
     pub(crate) secret: SecretString,
+    #[serde(serialize_with = "serialize_secret")]
     pub(crate) passphrase: SecretString,
+    /// The nonce the credentials were created or derived with, when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) nonce: Option<u32>,
+}
+
+fn serialize_secret<S: Serializer>(
+    secret: &SecretString,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_str(secret.expose_secret())
 }
 
 impl Credentials {
@@ -42,9 +60,18 @@ impl Credentials {
             key,
             secret: SecretString::from(secret),
             passphrase: SecretString::from(passphrase),
+            nonce: None,
         }
     }
 
+    /// Returns the nonce these credentials were created or derived with, which tells apart
+    /// several API keys of the same wallet. This is `None` for credentials built with
+    /// [`Self::new`], as the nonce is not part of the credentials themselves.
+    #[must_use]
+    pub fn nonce(&self) -> Option<u32> {
+        self.nonce
+    }
+
     /// Returns the API key.
     #[must_use]
     pub fn key(&self) -> ApiKey {
@@ -119,6 +146,11 @@ pub mod state {
 #[async_trait]
 pub trait Kind: sealed::Sealed + Clone + Send + Sync + 'static {
     async fn extra_headers(&self, request: &Request, timestamp: Timestamp) -> Result<HeaderMap>;
+
+    /// The fee charged on orders posted with this kind of authentication, only set for builders.
+    fn builder_fee(&self) -> Option<builder::Fee> {
+        None
+    }
 }
 
 /// Non-special, generic authentication. Sometimes referred to as L2 authentication.
@@ -140,6 +172,10 @@ impl Kind for builder::Builder {
     async fn extra_headers(&self, request: &Request, timestamp: Timestamp) -> Result<HeaderMap> {
         self.create_headers(request, timestamp).await
     }
+
+    fn builder_fee(&self) -> Option<builder::Fee> {
+        self.fee
+    }
 }
 
 impl sealed::Sealed for builder::Builder {}
@@ -177,7 +213,17 @@ pub(crate) mod l1 {
         }
     }
 
-    /// Returns the [`HeaderMap`] needed to obtain [`Credentials`] .
+    /// Returns the [`HeaderMap`] needed to obtain [`Credentials`].
+    ///
+    /// The signature is an EIP-712 signature over a `ClobAuth` struct in the `ClobAuthDomain`
+    /// domain (version `1`, the signer's chain id and no verifying contract):
+    ///
+    /// ```text
+    /// ClobAuth(address address,string timestamp,uint256 nonce,string message)
+    /// ```
+    ///
+    /// where `message` is a fixed attestation string and `nonce` defaults to `0`. The same
+    /// address, timestamp and nonce are sent as the `POLY_*` headers.
     pub(crate) async fn create_headers<S: Signer>(
         signer: &S,
         chain_id: ChainId,
@@ -275,6 +321,7 @@ pub mod builder {
     pub use url::Url;
 
     use crate::auth::{Credentials, body_to_string, hmac, to_message};
+    use crate::types::{Address, Decimal};
     use crate::{Result, Timestamp};
 
     pub(crate) const POLY_BUILDER_API_KEY: &str = "POLY_BUILDER_API_KEY";
@@ -316,15 +363,55 @@ pub mod builder {
         }
     }
 
+    /// The fee a builder charges on the orders it submits, in basis points of the order's USDC
+    /// notional, paid out to `recipient`.
+    ///
+    /// The CLOB order schema has no builder fee fields, so the fee is not sent with orders. It is
+    /// kept on the builder client, see
+    /// [`Client::promote_to_builder_with_fee`](crate::clob::Client::promote_to_builder_with_fee),
+    /// which reports the amount owed on each posted order's matched notional in
+    /// [`PostOrderResponse::builder_fee`](crate::clob::types::response::PostOrderResponse::builder_fee).
+    #[non_exhaustive]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Fee {
+        /// The fee rate in basis points of the USDC notional, e.g. `25` for 0.25%.
+        pub rate_bps: u32,
+        /// The address the fee is paid out to.
+        pub recipient: Address,
+    }
+
+    impl Fee {
+        #[must_use]
+        pub fn new(rate_bps: u32, recipient: Address) -> Self {
+            Self {
+                rate_bps,
+                recipient,
+            }
+        }
+
+        /// The fee owed on `notional` USDC, e.g. `25` basis points of `$100` is `$0.25`.
+        #[must_use]
+        pub fn amount(&self, notional: Decimal) -> Decimal {
+            notional * Decimal::from(self.rate_bps) / Decimal::from(10_000)
+        }
+    }
+
     /// Used to generate the Builder headers
     #[non_exhaustive]
     #[derive(Clone, Debug)]
     pub struct Builder {
         pub(crate) config: Config,
+        pub(crate) fee: Option<Fee>,
         pub(crate) client: Client,
     }
 
     impl Builder {
+        /// The fee this builder charges on its orders, if any.
+        #[must_use]
+        pub fn fee(&self) -> Option<Fee> {
+            self.fee
+        }
+
         pub(crate) async fn create_headers(
             &self,
             request: &Request,
@@ -484,6 +571,7 @@ mod tests {
                 secret: SecretString::from(
                     "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned(),
                 ),
+                nonce: None,
             },
             kind: Normal,
         };
@@ -517,6 +605,7 @@ mod tests {
                 "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned(),
             ),
             secret: SecretString::from("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned()),
+            nonce: None,
         };
         let config = Config::local(credentials);
         let request = Request::new(Method::GET, Url::parse("http://localhost/")?);
@@ -524,6 +613,7 @@ mod tests {
 
         let builder = builder::Builder {
             config,
+            fee: None,
             client: Client::default(),
         };
 
@@ -591,6 +681,32 @@ mod tests {
         assert_eq!(credentials.key(), key);
     }
 
+    #[test]
+    fn credentials_should_round_trip_through_json() -> anyhow::Result<()> {
+        let mut credentials =
+            Credentials::new(Uuid::nil(), "secret".to_owned(), "passphrase".to_owned());
+        credentials.nonce = Some(3);
+
+        let json = serde_json::to_value(&credentials)?;
+        assert_eq!(
+            json,
+            json!({
+                "key": Uuid::nil(),
+                "secret": "secret",
+                "passphrase": "passphrase",
+                "nonce": 3,
+            })
+        );
+
+        let restored: Credentials = serde_json::from_value(json)?;
+        assert_eq!(restored.key(), credentials.key());
+        assert_eq!(restored.secret().expose_secret(), "secret");
+        assert_eq!(restored.passphrase().expose_secret(), "passphrase");
+        assert_eq!(restored.nonce(), Some(3));
+
+        Ok(())
+    }
+
     #[test]
     fn debug_does_not_expose_secrets() {
         let secret_value = "my_super_secret_value_12345";
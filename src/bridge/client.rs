@@ -2,12 +2,14 @@ use reqwest::{
     Client as ReqwestClient, Method,
     header::{HeaderMap, HeaderValue},
 };
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use url::Url;
 
 use super::types::{
     DepositRequest, DepositResponse, StatusRequest, StatusResponse, SupportedAssetsResponse,
 };
-use crate::Result;
+use crate::{DEFAULT_USER_AGENT, Result, ToQueryParams as _};
 
 /// Client for the Polymarket Bridge API.
 ///
@@ -56,7 +58,7 @@ impl Client {
     pub fn new(host: &str) -> Result<Client> {
         let mut headers = HeaderMap::new();
 
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
+        headers.insert("User-Agent", HeaderValue::from_static(DEFAULT_USER_AGENT));
         headers.insert("Accept", HeaderValue::from_static("*/*"));
         headers.insert("Connection", HeaderValue::from_static("keep-alive"));
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
@@ -74,8 +76,12 @@ impl Client {
         &self.host
     }
 
+    /// Returns the underlying HTTP client, for sending other requests through the same connection
+    /// pool and TLS configuration.
+    ///
+    /// Cloning a [`reqwest::Client`] is cheap and shares the pool.
     #[must_use]
-    fn client(&self) -> &ReqwestClient {
+    pub fn http_client(&self) -> &ReqwestClient {
         &self.client
     }
 
@@ -105,7 +111,7 @@ impl Client {
     /// ```
     pub async fn deposit(&self, request: &DepositRequest) -> Result<DepositResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}deposit", self.host()))
             .json(request)
             .build()?;
@@ -141,7 +147,7 @@ impl Client {
     /// ```
     pub async fn supported_assets(&self) -> Result<SupportedAssetsResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}supported-assets", self.host()))
             .build()?;
 
@@ -179,7 +185,7 @@ impl Client {
     /// ```
     pub async fn status(&self, request: &StatusRequest) -> Result<StatusResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}status/{}", self.host(), request.address),
@@ -188,4 +194,24 @@ impl Client {
 
         crate::request(&self.client, request, None).await
     }
+
+    /// Sends a GET request to an endpoint this client does not wrap yet and deserializes the body
+    /// into `Res`.
+    ///
+    /// See [raw requests](crate::response#raw-requests) for how the request is sent and the
+    /// stability caveats.
+    pub async fn get_raw<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &Req,
+    ) -> Result<Res> {
+        let path = path.trim_start_matches('/');
+        let query = query.query_params(None);
+        let request = self
+            .http_client()
+            .request(Method::GET, format!("{}{path}{query}", self.host()))
+            .build()?;
+
+        crate::request(&self.client, request, None).await
+    }
 }
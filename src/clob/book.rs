@@ -0,0 +1,448 @@
+//! A locally maintained order book.
+//!
+//! [`OrderBook`] is seeded from a snapshot, either [`OrderBookSummaryResponse`] from
+//! [`Client::order_book`](crate::clob::Client::order_book) or a WebSocket `book` message, and kept
+//! current by applying level changes such as the WebSocket `price_change` events.
+//!
+//! [`OrderBook::simulate_fill`] walks the book to estimate how an order would fill without placing
+//! it, see also [`Client::simulate_fill`](crate::clob::Client::simulate_fill).
+
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "OrderBook is the established name for this type across the API"
+)]
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use crate::Result;
+use crate::clob::types::response::{OrderBookSummaryResponse, OrderSummary};
+use crate::clob::types::{Amount, Side};
+#[cfg(feature = "ws")]
+use crate::clob::ws::types::response::{BookUpdate, PriceChange};
+use crate::error::Error;
+use crate::types::{B256, Decimal, U256};
+
+/// An order book for a single token, with levels kept sorted by price.
+///
+/// Setting a level to a size of zero removes it, matching how the CLOB reports a level that has
+/// been fully consumed or canceled.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderBook {
+    /// The market condition ID.
+    pub market: B256,
+    pub asset_id: U256,
+    /// Bid sizes keyed by price, highest price first.
+    bids: BTreeMap<Reverse<Decimal>, Decimal>,
+    /// Ask sizes keyed by price, lowest price first.
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl OrderBook {
+    /// Creates an empty book for `asset_id`.
+    #[must_use]
+    pub fn new(market: B256, asset_id: U256) -> Self {
+        Self {
+            market,
+            asset_id,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the total size resting at `price` on `side`, where [`Side::Buy`] is the bid side and
+    /// [`Side::Sell`] the ask side. A size of zero removes the level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `side` is [`Side::Unknown`] or `size` is negative.
+    pub fn set_level(&mut self, side: Side, price: Decimal, size: Decimal) -> Result<()> {
+        if size.is_sign_negative() && !size.is_zero() {
+            return Err(Error::validation(format!(
+                "Unable to set order book level {price}: size {size} is negative"
+            )));
+        }
+
+        match side {
+            Side::Buy if size.is_zero() => {
+                self.bids.remove(&Reverse(price));
+            }
+            Side::Buy => {
+                self.bids.insert(Reverse(price), size);
+            }
+            Side::Sell if size.is_zero() => {
+                self.asks.remove(&price);
+            }
+            Side::Sell => {
+                self.asks.insert(price, size);
+            }
+            Side::Unknown => {
+                return Err(Error::validation(format!(
+                    "Unable to set order book level {price}: unknown side"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Applies the entries of a WebSocket `price_change` event that belong to this book's asset.
+    /// Entries for other assets, and entries without a size, are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an entry cannot be applied, see [`Self::set_level`]. Entries before the
+    /// failing one have already been applied.
+    #[cfg(feature = "ws")]
+    pub fn apply_change(&mut self, change: &PriceChange) -> Result<()> {
+        for entry in &change.price_changes {
+            if entry.asset_id != self.asset_id {
+                continue;
+            }
+
+            if let Some(size) = entry.size {
+                self.set_level(entry.side, entry.price, size)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces every level with those of a WebSocket `book` snapshot.
+    #[cfg(feature = "ws")]
+    pub fn apply_snapshot(&mut self, book: &BookUpdate) {
+        self.market = book.market;
+        self.asset_id = book.asset_id;
+        self.bids = levels(
+            book.bids
+                .iter()
+                .map(|level| (Reverse(level.price), level.size)),
+        );
+        self.asks = levels(book.asks.iter().map(|level| (level.price, level.size)));
+    }
+
+    /// The highest bid, if any.
+    #[must_use]
+    pub fn best_bid(&self) -> Option<OrderSummary> {
+        self.bids
+            .first_key_value()
+            .map(|(Reverse(price), size)| summary(*price, *size))
+    }
+
+    /// The lowest ask, if any.
+    #[must_use]
+    pub fn best_ask(&self) -> Option<OrderSummary> {
+        self.asks
+            .first_key_value()
+            .map(|(price, size)| summary(*price, *size))
+    }
+
+    /// The midpoint between the best bid and the best ask, if both sides have a level.
+    #[must_use]
+    pub fn mid(&self) -> Option<Decimal> {
+        let bid = self.best_bid()?.price;
+        let ask = self.best_ask()?.price;
+
+        Some((bid + ask) / Decimal::TWO)
+    }
+
+    /// The bid levels, highest price first.
+    pub fn bids(&self) -> impl Iterator<Item = OrderSummary> + '_ {
+        self.bids
+            .iter()
+            .map(|(Reverse(price), size)| summary(*price, *size))
+    }
+
+    /// The ask levels, lowest price first.
+    pub fn asks(&self) -> impl Iterator<Item = OrderSummary> + '_ {
+        self.asks.iter().map(|(price, size)| summary(*price, *size))
+    }
+
+    /// Estimates how an order for `amount` on `side` would fill against this book, best price
+    /// first, without placing anything. A buy consumes the asks and a sell consumes the bids.
+    ///
+    /// When the book is too thin the result is a partial fill, with the unfilled part in
+    /// [`FillSimulation::remaining`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `side` is [`Side::Unknown`].
+    pub fn simulate_fill(&self, side: Side, amount: Amount) -> Result<FillSimulation> {
+        match side {
+            Side::Buy => Ok(FillSimulation::walk(self.asks(), amount)),
+            Side::Sell => Ok(FillSimulation::walk(self.bids(), amount)),
+            Side::Unknown => Err(Error::validation(
+                "Unable to simulate a fill for an unknown side",
+            )),
+        }
+    }
+}
+
+/// The estimated outcome of filling an order against an [`OrderBook`].
+///
+/// `filled` and `remaining` are in the unit of the simulated [`Amount`], USDC or shares, while
+/// `shares` and `notional` always report both sides of the fill.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FillSimulation {
+    /// The volume-weighted average fill price, or `None` if nothing would fill.
+    pub avg_price: Option<Decimal>,
+    /// The part of the amount that would fill.
+    pub filled: Decimal,
+    /// The part of the amount left unfilled because the book is too thin.
+    pub remaining: Decimal,
+    /// The number of shares that would change hands.
+    pub shares: Decimal,
+    /// The USDC paid for a buy, or received for a sell, before fees.
+    pub notional: Decimal,
+    /// The number of price levels the fill would touch, including a partially consumed one.
+    pub levels_consumed: usize,
+}
+
+impl FillSimulation {
+    /// Whether the whole amount would fill.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.remaining <= Decimal::ZERO
+    }
+
+    /// Whether the book is too thin to fill the whole amount, the opposite of
+    /// [`Self::is_complete`].
+    #[must_use]
+    pub fn insufficient_liquidity(&self) -> bool {
+        !self.is_complete()
+    }
+
+    fn walk<I: Iterator<Item = OrderSummary>>(levels: I, amount: Amount) -> Self {
+        let target = amount.as_inner();
+        let in_usdc = amount.is_usdc();
+        let mut simulation = Self::default();
+
+        for level in levels {
+            let left = target - simulation.filled;
+            if left <= Decimal::ZERO {
+                break;
+            }
+
+            let available = if in_usdc {
+                level.size * level.price
+            } else {
+                level.size
+            };
+            let take = available.min(left);
+
+            let (shares, notional) = if in_usdc {
+                (take / level.price, take)
+            } else {
+                (take, take * level.price)
+            };
+
+            simulation.filled += take;
+            simulation.shares += shares;
+            simulation.notional += notional;
+            simulation.levels_consumed += 1;
+        }
+
+        simulation.remaining = (target - simulation.filled).max(Decimal::ZERO);
+        simulation.avg_price =
+            (!simulation.shares.is_zero()).then(|| simulation.notional / simulation.shares);
+
+        simulation
+    }
+}
+
+impl From<&OrderBookSummaryResponse> for OrderBook {
+    fn from(response: &OrderBookSummaryResponse) -> Self {
+        Self {
+            market: response.market,
+            asset_id: response.asset_id,
+            bids: levels(
+                response
+                    .bids
+                    .iter()
+                    .map(|level| (Reverse(level.price), level.size)),
+            ),
+            asks: levels(response.asks.iter().map(|level| (level.price, level.size))),
+        }
+    }
+}
+
+#[cfg(feature = "ws")]
+impl From<&BookUpdate> for OrderBook {
+    fn from(book: &BookUpdate) -> Self {
+        let mut order_book = Self::new(book.market, book.asset_id);
+        order_book.apply_snapshot(book);
+        order_book
+    }
+}
+
+/// Collects snapshot levels, dropping empty ones.
+fn levels<K: Ord>(levels: impl Iterator<Item = (K, Decimal)>) -> BTreeMap<K, Decimal> {
+    levels.filter(|(_, size)| !size.is_zero()).collect()
+}
+
+fn summary(price: Decimal, size: Decimal) -> OrderSummary {
+    OrderSummary { price, size }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn book() -> OrderBook {
+        let mut book = OrderBook::new(B256::ZERO, U256::from(1));
+        book.set_level(Side::Buy, dec!(0.48), dec!(100)).unwrap();
+        book.set_level(Side::Buy, dec!(0.49), dec!(50)).unwrap();
+        book.set_level(Side::Sell, dec!(0.52), dec!(75)).unwrap();
+        book.set_level(Side::Sell, dec!(0.51), dec!(25)).unwrap();
+        book
+    }
+
+    #[test]
+    fn levels_are_sorted_best_first() {
+        let book = book();
+
+        let bids: Vec<_> = book.bids().map(|level| level.price).collect();
+        let asks: Vec<_> = book.asks().map(|level| level.price).collect();
+
+        assert_eq!(bids, vec![dec!(0.49), dec!(0.48)]);
+        assert_eq!(asks, vec![dec!(0.51), dec!(0.52)]);
+        assert_eq!(book.best_bid().unwrap().size, dec!(50));
+        assert_eq!(book.best_ask().unwrap().size, dec!(25));
+        assert_eq!(book.mid(), Some(dec!(0.50)));
+    }
+
+    #[test]
+    fn set_level_adds_new_level() {
+        let mut book = book();
+
+        book.set_level(Side::Buy, dec!(0.50), dec!(10)).unwrap();
+
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.50));
+        assert_eq!(book.bids().count(), 3);
+    }
+
+    #[test]
+    fn set_level_updates_existing_level() {
+        let mut book = book();
+
+        book.set_level(Side::Sell, dec!(0.51), dec!(40)).unwrap();
+
+        assert_eq!(book.best_ask().unwrap().size, dec!(40));
+        assert_eq!(book.asks().count(), 2);
+    }
+
+    #[test]
+    fn zero_size_removes_level() {
+        let mut book = book();
+
+        book.set_level(Side::Buy, dec!(0.49), Decimal::ZERO)
+            .unwrap();
+        book.set_level(Side::Sell, dec!(0.99), Decimal::ZERO)
+            .unwrap();
+
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.48));
+        assert_eq!(book.asks().count(), 2);
+    }
+
+    #[test]
+    fn mid_requires_both_sides() {
+        let mut book = OrderBook::new(B256::ZERO, U256::from(1));
+        assert_eq!(book.mid(), None);
+
+        book.set_level(Side::Buy, dec!(0.40), dec!(1)).unwrap();
+        assert_eq!(book.mid(), None);
+    }
+
+    #[test]
+    fn invalid_levels_are_rejected() {
+        let mut book = book();
+
+        book.set_level(Side::Unknown, dec!(0.5), dec!(1))
+            .unwrap_err();
+        book.set_level(Side::Buy, dec!(0.5), dec!(-1)).unwrap_err();
+        assert_eq!(book, self::book());
+    }
+
+    #[test]
+    fn simulate_buy_in_shares_should_walk_asks() {
+        let simulation = book()
+            .simulate_fill(Side::Buy, Amount::shares(dec!(50)).unwrap())
+            .unwrap();
+
+        // 25 @ 0.51 + 25 @ 0.52
+        assert_eq!(simulation.filled, dec!(50));
+        assert_eq!(simulation.remaining, Decimal::ZERO);
+        assert_eq!(simulation.notional, dec!(25.75));
+        assert_eq!(simulation.avg_price, Some(dec!(0.515)));
+        assert_eq!(simulation.levels_consumed, 2);
+        assert!(simulation.is_complete());
+        assert!(!simulation.insufficient_liquidity());
+    }
+
+    #[test]
+    fn simulate_buy_in_usdc_should_convert_to_shares() {
+        let simulation = book()
+            .simulate_fill(Side::Buy, Amount::usdc(dec!(10.2)).unwrap())
+            .unwrap();
+
+        assert_eq!(simulation.shares, dec!(20));
+        assert_eq!(simulation.notional, dec!(10.2));
+        assert_eq!(simulation.levels_consumed, 1);
+    }
+
+    #[test]
+    fn simulate_sell_should_report_partial_fill() {
+        let simulation = book()
+            .simulate_fill(Side::Sell, Amount::shares(dec!(200)).unwrap())
+            .unwrap();
+
+        // 50 @ 0.49 + 100 @ 0.48
+        assert_eq!(simulation.filled, dec!(150));
+        assert_eq!(simulation.remaining, dec!(50));
+        assert_eq!(simulation.notional, dec!(72.5));
+        assert_eq!(simulation.levels_consumed, 2);
+        assert!(!simulation.is_complete());
+        assert!(simulation.insufficient_liquidity());
+    }
+
+    #[test]
+    fn simulate_against_empty_side_should_fill_nothing() {
+        let book = OrderBook::new(B256::ZERO, U256::from(1));
+
+        let simulation = book
+            .simulate_fill(Side::Buy, Amount::shares(dec!(1)).unwrap())
+            .unwrap();
+
+        assert_eq!(simulation.avg_price, None);
+        assert_eq!(simulation.remaining, dec!(1));
+        assert_eq!(simulation.levels_consumed, 0);
+        assert!(simulation.insufficient_liquidity());
+    }
+
+    #[cfg(feature = "ws")]
+    #[test]
+    fn apply_change_only_touches_own_asset() {
+        let mut book = book();
+        let change: PriceChange = serde_json::from_value(serde_json::json!({
+            "market": B256::ZERO,
+            "timestamp": "1",
+            "price_changes": [
+                { "asset_id": "1", "price": "0.49", "size": "0", "side": "BUY" },
+                { "asset_id": "1", "price": "0.50", "size": "5", "side": "SELL" },
+                { "asset_id": "2", "price": "0.48", "size": "0", "side": "BUY" },
+                { "asset_id": "1", "price": "0.52", "side": "SELL" }
+            ]
+        }))
+        .unwrap();
+
+        book.apply_change(&change).unwrap();
+
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.48));
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.50));
+        assert_eq!(book.asks().count(), 3);
+    }
+}
@@ -1,21 +1,23 @@
-use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 use std::marker::PhantomData;
 use std::mem;
+use std::str::FromStr as _;
 use std::sync::Arc;
-#[cfg(feature = "heartbeats")]
-use std::time::Duration;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{Duration, Instant};
 
-use alloy::dyn_abi::Eip712Domain;
-use alloy::primitives::U256;
+use alloy::primitives::{B256, U256};
 use alloy::signers::Signer;
-use alloy::sol_types::SolStruct as _;
 use async_stream::try_stream;
 use bon::Builder;
-use chrono::{NaiveDate, Utc};
+use chrono::{NaiveDate, TimeDelta, Utc};
 use dashmap::DashMap;
-use futures::Stream;
+use futures::{Stream, StreamExt as _, TryStreamExt as _, stream};
+use rand::Rng as _;
 use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::{Client as ReqwestClient, Method, Request};
+use reqwest::{Client as ReqwestClient, Method, Request, StatusCode};
+use serde::Serialize;
+use serde::de::{DeserializeOwned, IgnoredAny};
 use serde_json::json;
 #[cfg(all(feature = "tracing", feature = "heartbeats"))]
 use tracing::{debug, error};
@@ -24,10 +26,11 @@ use uuid::Uuid;
 #[cfg(feature = "heartbeats")]
 use {tokio::sync::oneshot::Receiver, tokio::time, tokio_util::sync::CancellationToken};
 
-use crate::auth::builder::{Builder, Config as BuilderConfig};
+use crate::auth::builder::{Builder, Config as BuilderConfig, Fee as BuilderFee};
 use crate::auth::state::{Authenticated, State, Unauthenticated};
 use crate::auth::{Credentials, Kind, Normal};
-use crate::clob::order_builder::{Limit, Market, OrderBuilder, generate_seed};
+use crate::clob::order_builder::{Limit, Market, OrderBuilder, USDC_DECIMALS, generate_seed};
+use crate::clob::signing::{OrderDomain, OrderSigner};
 use crate::clob::types::request::{
     BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
     LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, OrdersRequest,
@@ -35,15 +38,16 @@ use crate::clob::types::request::{
     UserRewardsEarningRequest,
 };
 use crate::clob::types::response::{
-    ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, BuilderApiKeyResponse,
-    BuilderTradeResponse, CancelOrdersResponse, CurrentRewardResponse, FeeRateResponse,
-    GeoblockResponse, HeartbeatResponse, LastTradePriceResponse, LastTradesPricesResponse,
-    MarketResponse, MarketRewardResponse, MidpointResponse, MidpointsResponse, NegRiskResponse,
-    NotificationResponse, OpenOrderResponse, OrderBookSummaryResponse, OrderScoringResponse,
-    OrdersScoringResponse, Page, PostOrderResponse, PriceHistoryResponse, PriceResponse,
-    PricesResponse, RewardsPercentagesResponse, SimplifiedMarketResponse, SpreadResponse,
-    SpreadsResponse, TickSizeResponse, TotalUserEarningResponse, TradeResponse,
-    UserEarningResponse, UserRewardsEarningResponse,
+    ApiKeyInfo, ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse,
+    BuilderApiKeyResponse, BuilderTradeResponse, CancelOrdersResponse, CurrentRewardResponse,
+    FailedCancelBatch, FeasibilityReport, FeeRateResponse, FeeRates, GeoblockResponse,
+    HeartbeatResponse, LastTradePriceResponse, LastTradesPricesResponse, MarketResponse,
+    MarketRewardResponse, MarketRewardsSummary, MidpointResponse, MidpointsResponse,
+    NegRiskResponse, NotificationResponse, OpenOrderResponse, OrderBookSummaryResponse,
+    OrderConstraints, OrderScoringResponse, OrdersScoringResponse, Page, PostOrderResponse,
+    PriceHistoryResponse, PriceResponse, PricesResponse, RewardsPercentagesResponse,
+    RewardsSummary, SimplifiedMarketResponse, SpreadResponse, SpreadsResponse, TickSizeResponse,
+    TotalUserEarningResponse, TradeResponse, UserEarningResponse, UserRewardsEarningResponse,
 };
 #[cfg(feature = "rfq")]
 use crate::clob::types::{
@@ -52,19 +56,65 @@ use crate::clob::types::{
     CreateRfqRequestRequest, CreateRfqRequestResponse, RfqQuote, RfqQuotesRequest, RfqRequest,
     RfqRequestsRequest,
 };
-use crate::clob::types::{SignableOrder, SignatureType, SignedOrder, TickSize};
-use crate::error::{Error, Kind as ErrorKind, Synchronization};
-use crate::types::Address;
+use crate::clob::types::{
+    Amount, AssetType, Side, SignableOrder, SignatureType, SignedOrder, TickSize,
+};
+use crate::clob::{FillSimulation, OrderBook, fees};
+#[cfg(feature = "data")]
+use crate::data::types::request::{ActivityRequest, PositionsRequest};
+#[cfg(feature = "data")]
+use crate::data::types::response::{Activity, Position};
+use crate::error::{ApiKeyNotFound, Error, Kind as ErrorKind, Status, Synchronization, Timeout};
+use crate::response::Response;
+use crate::types::{Address, ChainId, Decimal};
+use crate::util::fan_out_collect;
 use crate::{
-    AMOY, POLYGON, Result, Timestamp, ToQueryParams as _, auth, contract_config,
+    AMOY, DEFAULT_USER_AGENT, POLYGON, Result, Timestamp, ToQueryParams as _, auth,
     derive_proxy_wallet, derive_safe_wallet,
 };
 
-const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
-const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
-
 const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
 
+/// Header carrying the client-generated key of [`Client::post_order_with_idempotency_key`].
+const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+
+/// The delay before the first retry of [`Client::post_order_with_idempotency_key`], doubled for
+/// every further retry.
+const POST_RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+
+/// The longest delay between two attempts of [`Client::post_order_with_idempotency_key`].
+const POST_RETRY_MAX_DELAY: Duration = Duration::from_secs(2);
+
+/// The maximum number of tokens [`Client::books_map`] requests from `/books` at once.
+const MAX_BOOKS_PER_REQUEST: usize = 100;
+/// The maximum number of concurrent `/book` requests [`Client::books_map`] issues when a batch
+/// request fails.
+const BOOKS_FALLBACK_CONCURRENCY: usize = 8;
+/// The maximum number of concurrent requests [`Client::tick_sizes`] and [`Client::neg_risks`]
+/// issue for tokens that are not cached yet.
+const TOKEN_LOOKUP_CONCURRENCY: usize = 8;
+/// The maximum number of order IDs the CLOB accepts in a single `DELETE /orders` request.
+pub const DEFAULT_CANCEL_BATCH_SIZE: usize = 3000;
+/// How long a measured server time is reused by default, see [`Config`]'s `server_time_cache`.
+pub const DEFAULT_SERVER_TIME_CACHE: Duration = Duration::from_secs(30);
+/// The maximum number of concurrent `DELETE /orders` requests [`Client::cancel_orders`] issues.
+const CANCEL_CONCURRENCY: usize = 8;
+
+/// The maximum number of days [`Client::earnings_range`] will fetch in one call.
+const MAX_EARNINGS_RANGE_DAYS: i64 = 90;
+/// The maximum number of concurrent requests [`Client::earnings_range`] will issue.
+const EARNINGS_RANGE_CONCURRENCY: usize = 8;
+
+/// How long [`Client::wait_for_allowance`] initially waits between polls, unless overridden by
+/// [`Config`].
+const DEFAULT_ALLOWANCE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// The longest [`Client::wait_for_allowance`] will back off to between polls, unless the
+/// configured poll interval is longer.
+const MAX_ALLOWANCE_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Maximum number of authentication handshakes [`Client::authenticate_many`] runs at once.
+const AUTHENTICATION_CONCURRENCY: usize = 8;
+
 /// The type used to build a request to authenticate the inner [`Client<Unauthorized>`]. Calling
 /// `authenticate` on this will elevate that inner `client` into an [`Client<Authenticated<K>>`].
 pub struct AuthenticationBuilder<'signer, S: Signer, K: Kind = Normal> {
@@ -91,12 +141,22 @@ pub struct AuthenticationBuilder<'signer, S: Signer, K: Kind = Normal> {
 }
 
 impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
+    /// Sets the nonce used to create or derive the API key. Defaults to `0`.
+    ///
+    /// A wallet can hold several API keys, one per nonce, and deriving with the same nonce always
+    /// returns the same key. The nonce is part of the EIP-712 `ClobAuth` message signed for the
+    /// L1 headers, `ClobAuth(address address,string timestamp,uint256 nonce,string message)` in
+    /// the `ClobAuthDomain` domain, and is also sent as the `POLY_NONCE` header. It cannot be
+    /// combined with [`Self::credentials`].
     #[must_use]
     pub fn nonce(mut self, nonce: u32) -> Self {
         self.nonce = Some(nonce);
         self
     }
 
+    /// Authenticates with existing `credentials` instead of creating or deriving them, e.g. ones
+    /// saved from [`Client::credentials`] in an earlier session. See also
+    /// [`Client::with_credentials`].
     #[must_use]
     pub fn credentials(mut self, credentials: Credentials) -> Self {
         self.credentials = Some(credentials);
@@ -199,11 +259,15 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
                 ));
             }
             Some(credentials) => credentials,
-            None => {
-                inner
-                    .create_or_derive_api_key(self.signer, self.nonce)
-                    .await?
-            }
+            None => match inner.config.api_key_strategy {
+                ApiKeyStrategy::CreateOrDerive => {
+                    inner
+                        .create_or_derive_api_key(self.signer, self.nonce)
+                        .await?
+                }
+                ApiKeyStrategy::Derive => inner.derive_api_key(self.signer, self.nonce).await?,
+                ApiKeyStrategy::Create => inner.create_api_key(self.signer, self.nonce).await?,
+            },
         };
 
         let state = Authenticated {
@@ -229,9 +293,12 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                min_order_sizes: inner.min_order_sizes,
+                order_domains: inner.order_domains,
                 funder,
                 signature_type: self.signature_type.unwrap_or(SignatureType::Eoa),
                 salt_generator: self.salt_generator.unwrap_or(generate_seed),
+                server_time_offset: inner.server_time_offset,
             }),
             #[cfg(feature = "heartbeats")]
             heartbeat_token: DroppingCancellationToken(None),
@@ -365,22 +432,159 @@ impl Default for Client<Unauthenticated> {
 }
 
 /// Configuration for [`Client`]
-#[derive(Clone, Debug, Default, Builder)]
+///
+/// Connection settings left unset keep reqwest's defaults.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use polymarket_client_sdk::clob::{Client, Config, HttpVersion};
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = Config::builder()
+///     .use_server_time(true)
+///     .pool_idle_timeout(Duration::from_secs(30))
+///     .pool_max_idle_per_host(8)
+///     .tcp_keepalive(Duration::from_secs(10))
+///     .http_version(HttpVersion::Http1Only)
+///     .build();
+/// let client = Client::new("https://clob.polymarket.com", config)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Builder)]
 pub struct Config {
     /// Whether the [`Client`] will use the server time provided by Polymarket when creating auth
     /// headers. This adds another round trip to the requests.
     #[builder(default)]
     use_server_time: bool,
+    /// How long a measured server time is reused when `use_server_time` is set. While it is
+    /// fresh, auth headers are signed with the local clock corrected by its offset to the server
+    /// clock instead of fetching the server time for every request. The offset is shared by all
+    /// clones of the [`Client`] and kept when it is authenticated or deauthenticated. Defaults to
+    /// [`DEFAULT_SERVER_TIME_CACHE`]; [`Duration::ZERO`] fetches the server time for every
+    /// request.
+    #[builder(default = DEFAULT_SERVER_TIME_CACHE)]
+    server_time_cache: Duration,
+    /// What the [`Client`] does when `use_server_time` is set and fetching the server time fails.
+    /// Defaults to [`ServerTimePolicy::Strict`].
+    #[builder(default)]
+    server_time_policy: ServerTimePolicy,
+    /// How [`AuthenticationBuilder::authenticate`] obtains [`Credentials`] when none are
+    /// supplied. Defaults to [`ApiKeyStrategy::CreateOrDerive`].
+    #[builder(default)]
+    api_key_strategy: ApiKeyStrategy,
     /// Override for the geoblock API host. Defaults to `https://polymarket.com`.
     /// This is primarily useful for testing.
     #[builder(into)]
     geoblock_host: Option<String>,
+    /// How long [`Client::wait_for_allowance`] waits before polling again, doubling after each
+    /// poll up to ten (10) seconds. The default is one (1) second.
+    // Clippy mistakes the attributes bon generates for a field whose name starts with `allow`
+    // for hand-written `#[allow]`s, so the field is named apart from its setter.
+    #[builder(name = allowance_poll_interval)]
+    approval_poll_interval: Option<Duration>,
+    /// Override for the `User-Agent` header sent with every request. Defaults to
+    /// [`DEFAULT_USER_AGENT`].
+    #[builder(into)]
+    user_agent: Option<String>,
+    /// How long an idle pooled connection is kept open before it is closed. Defaults to
+    /// reqwest's 90 seconds.
+    pool_idle_timeout: Option<Duration>,
+    /// The maximum number of idle connections kept open per host. Defaults to reqwest's, which
+    /// is unlimited.
+    pool_max_idle_per_host: Option<usize>,
+    /// How often TCP keepalive probes are sent on open connections. Defaults to reqwest's 15
+    /// seconds.
+    tcp_keepalive: Option<Duration>,
+    /// Which HTTP version the [`Client`] speaks. Defaults to [`HttpVersion::Negotiate`].
+    #[builder(default)]
+    http_version: HttpVersion,
+    /// Whether connections are kept open for reuse. Defaults to `true`.
+    ///
+    /// When disabled, every request is sent with `Connection: close` and no idle connections are
+    /// pooled, overriding `pool_max_idle_per_host`. This suits short-lived environments such as
+    /// serverless functions, where a pooled connection may have been dropped while the process
+    /// was frozen.
+    #[builder(default = true)]
+    keep_alive: bool,
+    /// How many order IDs [`Client::cancel_orders`] sends per request. Larger inputs are split into
+    /// batches of this size. Defaults to [`DEFAULT_CANCEL_BATCH_SIZE`].
+    #[builder(default = DEFAULT_CANCEL_BATCH_SIZE)]
+    cancel_batch_size: usize,
+    /// Whether [`Client::ok`] returns whatever body the health check sends. By default any body
+    /// other than `OK`, such as a maintenance notice served with `200`, is reported as an error of
+    /// kind [`ServiceUnavailable`](crate::error::Kind::ServiceUnavailable).
+    // Named apart from its setter for the same reason as `approval_poll_interval`.
+    #[builder(default, name = allow_non_ok_health)]
+    accept_non_ok_health: bool,
     #[cfg(feature = "heartbeats")]
     #[builder(default = Duration::from_secs(5))]
     /// How often the [`Client`] will automatically submit heartbeats. The default is five (5) seconds.
     heartbeat_interval: Duration,
 }
 
+impl Default for Config {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// How the [`Client`] reacts to a failed server time request when [`Config`] enables
+/// `use_server_time`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ServerTimePolicy {
+    /// Fail the request that needed the timestamp.
+    #[default]
+    Strict,
+    /// Log a warning and sign with the local clock instead. The server accepts timestamps within a
+    /// generous tolerance, so this is usually safe on a machine with a synchronized clock.
+    FallbackToLocal,
+}
+
+/// Which HTTP version the [`Client`] uses, see [`Config`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HttpVersion {
+    /// Use HTTP/2 if the server offers it during the TLS handshake, and HTTP/1.1 otherwise.
+    #[default]
+    Negotiate,
+    /// Only use HTTP/1.1.
+    Http1Only,
+    /// Only use HTTP/2, without negotiating it first. Requests fail against servers that do not
+    /// support HTTP/2.
+    Http2Only,
+}
+
+/// How [`AuthenticationBuilder::authenticate`] obtains [`Credentials`] when none are supplied.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ApiKeyStrategy {
+    /// Create a new API key, falling back to deriving the existing one if the server rejects the
+    /// creation (e.g. because a key already exists for the nonce).
+    #[default]
+    CreateOrDerive,
+    /// Only derive the existing API key for the signer and nonce. Derived keys are the same across
+    /// processes, which suits stateless workers. Fails if no key exists yet.
+    Derive,
+    /// Only create a new API key. Fails if a key already exists for the signer and nonce.
+    Create,
+}
+
+/// Where the timestamp used to sign a request's auth headers came from.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeSource {
+    /// The server time reported by the CLOB `/time` endpoint.
+    Server,
+    /// The local clock, either because `use_server_time` is disabled or because the server time
+    /// request failed under [`ServerTimePolicy::FallbackToLocal`].
+    Local,
+}
+
 /// The default geoblock API host (separate from CLOB host)
 const DEFAULT_GEOBLOCK_HOST: &str = "https://polymarket.com";
 
@@ -401,6 +605,10 @@ struct ClientInner<S: State> {
     neg_risk: DashMap<U256, bool>,
     /// Local cache representing the fee rate in basis points per token ID
     fee_rate_bps: DashMap<U256, u32>,
+    /// Local cache of the minimum limit order size, in shares, per token ID
+    min_order_sizes: DashMap<U256, Decimal>,
+    /// Local cache of the order signing domain per chain ID
+    order_domains: DashMap<ChainId, OrderDomain>,
     /// The funder for this [`ClientInner`]. If funder is present, then `signature_type` cannot
     /// be [`SignatureType::Eoa`]. Conversely, if funder is absent, then `signature_type` cannot be
     /// [`SignatureType::Proxy`] or [`SignatureType::GnosisSafe`].
@@ -409,9 +617,82 @@ struct ClientInner<S: State> {
     signature_type: SignatureType,
     /// The salt/seed generator for use in creating [`SignableOrder`]s
     salt_generator: fn() -> u64,
+    /// The offset of the server clock from the local clock, shared with every client derived
+    /// from this one.
+    server_time_offset: Arc<ServerTimeOffset>,
+}
+
+/// The offset of the server clock from the local clock, as last measured from the server time.
+#[derive(Debug, Default)]
+struct ServerTimeOffset {
+    /// The server time minus the local time, in seconds.
+    seconds: AtomicI64,
+    /// The local Unix time, in seconds, at which `seconds` was measured, or `0` if it never was.
+    measured_at: AtomicI64,
+}
+
+impl ServerTimeOffset {
+    /// Returns the offset if it was measured less than `max_age` before the local time `now`.
+    fn get(&self, now: Timestamp, max_age: Duration) -> Option<i64> {
+        let measured_at = self.measured_at.load(Ordering::Acquire);
+        let max_age = i64::try_from(max_age.as_secs()).unwrap_or(i64::MAX);
+
+        (measured_at != 0 && now.saturating_sub(measured_at) < max_age)
+            .then(|| self.seconds.load(Ordering::Relaxed))
+    }
+
+    fn set(&self, seconds: i64, now: Timestamp) {
+        self.seconds.store(seconds, Ordering::Relaxed);
+        self.measured_at.store(now, Ordering::Release);
+    }
 }
 
 impl<S: State> ClientInner<S> {
+    /// Returns the timestamp to sign auth headers with, honoring `use_server_time` and the
+    /// [`ServerTimePolicy`], together with where it came from.
+    async fn timestamp(&self) -> Result<(Timestamp, TimeSource)> {
+        let timestamp = if self.config.use_server_time {
+            match self.server_timestamp().await {
+                Ok(timestamp) => (timestamp, TimeSource::Server),
+                Err(e) if self.config.server_time_policy == ServerTimePolicy::FallbackToLocal => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(error = %e, "Failed to fetch server time, falling back to local time");
+                    #[cfg(not(feature = "tracing"))]
+                    let _: &Error = &e;
+                    (Utc::now().timestamp(), TimeSource::Local)
+                }
+                Err(e) => return Err(e),
+            }
+        } else {
+            (Utc::now().timestamp(), TimeSource::Local)
+        };
+
+        Ok(timestamp)
+    }
+
+    /// Returns the server time, derived from the measured offset to the local clock while it is
+    /// younger than `server_time_cache` and fetched from the server otherwise.
+    async fn server_timestamp(&self) -> Result<Timestamp> {
+        let now = Utc::now().timestamp();
+        if let Some(offset) = self
+            .server_time_offset
+            .get(now, self.config.server_time_cache)
+        {
+            return Ok(now + offset);
+        }
+
+        let server_time = self.server_time().await?;
+        let now = Utc::now().timestamp();
+        self.server_time_offset.set(server_time - now, now);
+
+        Ok(server_time)
+    }
+
+    fn server_time_offset(&self) -> Option<TimeDelta> {
+        (self.server_time_offset.measured_at.load(Ordering::Acquire) != 0)
+            .then(|| TimeDelta::seconds(self.server_time_offset.seconds.load(Ordering::Relaxed)))
+    }
+
     pub async fn server_time(&self) -> Result<Timestamp> {
         let request = self
             .client
@@ -434,7 +715,11 @@ impl ClientInner<Unauthenticated> {
             .build()?;
         let headers = self.create_headers(signer, nonce).await?;
 
-        crate::request(&self.client, request, Some(headers)).await
+        let mut credentials: Credentials =
+            crate::request(&self.client, request, Some(headers)).await?;
+        credentials.nonce = Some(nonce.unwrap_or(0));
+
+        Ok(credentials)
     }
 
     pub async fn derive_api_key<S: Signer>(
@@ -448,7 +733,11 @@ impl ClientInner<Unauthenticated> {
             .build()?;
         let headers = self.create_headers(signer, nonce).await?;
 
-        crate::request(&self.client, request, Some(headers)).await
+        let mut credentials: Credentials =
+            crate::request(&self.client, request, Some(headers)).await?;
+        credentials.nonce = Some(nonce.unwrap_or(0));
+
+        Ok(credentials)
     }
 
     async fn create_or_derive_api_key<S: Signer>(
@@ -472,11 +761,7 @@ impl ClientInner<Unauthenticated> {
             "Chain id not set, be sure to provide one on the signer",
         ))?;
 
-        let timestamp = if self.config.use_server_time {
-            self.server_time().await?
-        } else {
-            Utc::now().timestamp()
-        };
+        let (timestamp, _) = self.timestamp().await?;
 
         auth::l1::create_headers(signer, chain_id, timestamp, nonce).await
     }
@@ -500,6 +785,15 @@ impl<S: State> Client<S> {
         &self.inner.host
     }
 
+    /// Returns the underlying HTTP client, for sending other requests through the same connection
+    /// pool and TLS configuration.
+    ///
+    /// Cloning a [`reqwest::Client`] is cheap and shares the pool.
+    #[must_use]
+    pub fn http_client(&self) -> &ReqwestClient {
+        &self.inner.client
+    }
+
     /// Invalidates all internal caches (tick sizes, neg risk flags, and fee rates).
     ///
     /// This method clears the cached market configuration data, forcing subsequent
@@ -509,6 +803,7 @@ impl<S: State> Client<S> {
         self.inner.tick_sizes.clear();
         self.inner.fee_rate_bps.clear();
         self.inner.neg_risk.clear();
+        self.inner.min_order_sizes.clear();
     }
 
     /// Pre-populates the tick size cache for a token, avoiding the HTTP call.
@@ -582,14 +877,21 @@ impl<S: State> Client<S> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the network request fails or the API is unreachable.
+    /// Returns an error if the network request fails or the API is unreachable. Unless [`Config`]
+    /// sets `allow_non_ok_health`, a successful response with any body other than "OK" is
+    /// reported as an error of kind [`ServiceUnavailable`](crate::error::Kind::ServiceUnavailable).
     pub async fn ok(&self) -> Result<String> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, self.host().to_owned())
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        let message = crate::request(&self.inner.client, request, None).await?;
+        if self.inner.config.accept_non_ok_health {
+            Ok(message)
+        } else {
+            crate::ensure_ok(message)
+        }
     }
 
     /// Returns the current server timestamp in milliseconds since Unix epoch.
@@ -601,6 +903,15 @@ impl<S: State> Client<S> {
         self.inner.server_time().await
     }
 
+    /// Returns the measured offset of the server clock from the local clock, or `None` if
+    /// [`Config`] does not set `use_server_time` or the server time has not been fetched yet.
+    ///
+    /// Clones of the client share the offset.
+    #[must_use]
+    pub fn server_time_offset(&self) -> Option<TimeDelta> {
+        self.inner.server_time_offset()
+    }
+
     /// Retrieves the midpoint price for a single market outcome token.
     ///
     /// The midpoint is the average of the best bid and best ask prices,
@@ -613,7 +924,7 @@ impl<S: State> Client<S> {
     pub async fn midpoint(&self, request: &MidpointRequest) -> Result<MidpointResponse> {
         let params = request.query_params(None);
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}midpoint{params}", self.host()))
             .build()?;
 
@@ -630,7 +941,7 @@ impl<S: State> Client<S> {
     /// Returns an error if the request fails or any token ID is invalid.
     pub async fn midpoints(&self, requests: &[MidpointRequest]) -> Result<MidpointsResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}midpoints", self.host()))
             .json(requests)
             .build()?;
@@ -643,13 +954,31 @@ impl<S: State> Client<S> {
     /// Returns the best available price for buying (BUY side) or selling (SELL side)
     /// the specified token. This reflects the actual executable price on the orderbook.
     ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_client_sdk::clob::{Client, Config};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// use polymarket_client_sdk::clob::types::{Side, request::PriceRequest};
+    /// use polymarket_client_sdk::types::U256;
+    ///
+    /// let client = Client::new("https://clob.polymarket.com", Config::default())?;
+    /// let request = PriceRequest::builder()
+    ///     .token_id(U256::from(1))
+    ///     .side(Side::Buy)
+    ///     .build();
+    /// let price = client.price(&request).await?.price;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the token ID is invalid.
     pub async fn price(&self, request: &PriceRequest) -> Result<PriceResponse> {
         let params = request.query_params(None);
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}price{params}", self.host()))
             .build()?;
 
@@ -666,7 +995,7 @@ impl<S: State> Client<S> {
     /// Returns an error if the request fails or any token ID is invalid.
     pub async fn prices(&self, requests: &[PriceRequest]) -> Result<PricesResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}prices", self.host()))
             .json(requests)
             .build()?;
@@ -684,7 +1013,7 @@ impl<S: State> Client<S> {
     /// Returns an error if the request fails.
     pub async fn all_prices(&self) -> Result<PricesResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}prices", self.host()))
             .build()?;
 
@@ -704,7 +1033,7 @@ impl<S: State> Client<S> {
         request: &PriceHistoryRequest,
     ) -> Result<PriceHistoryResponse> {
         let params = request.query_params(None);
-        let req = self.client().request(
+        let req = self.http_client().request(
             Method::GET,
             format!("{}prices-history{params}", self.host()),
         );
@@ -724,7 +1053,7 @@ impl<S: State> Client<S> {
     pub async fn spread(&self, request: &SpreadRequest) -> Result<SpreadResponse> {
         let params = request.query_params(None);
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}spread{params}", self.host()))
             .build()?;
 
@@ -741,7 +1070,7 @@ impl<S: State> Client<S> {
     /// Returns an error if the request fails or any token ID is invalid.
     pub async fn spreads(&self, requests: &[SpreadRequest]) -> Result<SpreadsResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}spreads", self.host()))
             .json(requests)
             .build()?;
@@ -771,7 +1100,7 @@ impl<S: State> Client<S> {
         tracing::trace!(token_id = %token_id, "cache miss: tick_size");
 
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}tick-size", self.host()))
             .query(&[("token_id", token_id.to_string())])
             .build()?;
@@ -811,7 +1140,7 @@ impl<S: State> Client<S> {
         tracing::trace!(token_id = %token_id, "cache miss: neg_risk");
 
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}neg-risk", self.host()))
             .query(&[("token_id", token_id.to_string())])
             .build()?;
@@ -826,6 +1155,42 @@ impl<S: State> Client<S> {
         Ok(response)
     }
 
+    /// Retrieves the minimum tick sizes of `token_ids`, keyed by token ID.
+    ///
+    /// The CLOB has no batch endpoint for tick sizes, so tokens that are not cached yet are
+    /// fetched through [`Self::tick_size`] with a bounded number of concurrent requests, and
+    /// cached the same way. Tokens the server has no market for are absent from the map rather
+    /// than failing the whole call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails for a reason other than the server answering
+    /// `404 Not Found`, e.g. a network error, rate limiting or a server error.
+    pub async fn tick_sizes(&self, token_ids: &[U256]) -> Result<HashMap<U256, TickSize>> {
+        lookup_tokens(token_ids, |token_id| async move {
+            Ok(self.tick_size(token_id).await?.minimum_tick_size)
+        })
+        .await
+    }
+
+    /// Checks which of `token_ids` use the negative risk (`NegRisk`) adapter, keyed by token ID.
+    ///
+    /// The CLOB has no batch endpoint for this, so tokens that are not cached yet are fetched
+    /// through [`Self::neg_risk`] with a bounded number of concurrent requests, and cached the
+    /// same way. Tokens the server has no market for are absent from the map rather than failing
+    /// the whole call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails for a reason other than the server answering
+    /// `404 Not Found`, e.g. a network error, rate limiting or a server error.
+    pub async fn neg_risks(&self, token_ids: &[U256]) -> Result<HashMap<U256, bool>> {
+        lookup_tokens(token_ids, |token_id| async move {
+            Ok(self.neg_risk(token_id).await?.neg_risk)
+        })
+        .await
+    }
+
     /// Retrieves the trading fee rate for a market outcome token.
     ///
     /// Returns the fee rate in basis points (bps) charged on trades for this token.
@@ -847,7 +1212,7 @@ impl<S: State> Client<S> {
         tracing::trace!(token_id = %token_id, "cache miss: fee_rate_bps");
 
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}fee-rate", self.host()))
             .query(&[("token_id", token_id.to_string())])
             .build()?;
@@ -862,6 +1227,94 @@ impl<S: State> Client<S> {
         Ok(response)
     }
 
+    /// Retrieves the maker and taker fee rates of the market `token_id` belongs to.
+    ///
+    /// The market is looked up through the token's order book, so this makes two requests.
+    /// Markets without fees return zero rates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either request fails or the market reports a fee rate that is not a
+    /// whole number of basis points.
+    pub async fn fee_rates(&self, token_id: U256) -> Result<FeeRates> {
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(token_id)
+            .build();
+        let book = self.order_book(&request).await?;
+
+        self.market(&book.market.to_string()).await?.fee_rates()
+    }
+
+    /// Returns what trading `size` outcome tokens of `token_id` at `price` yields after the
+    /// token's [fee rate](Self::fee_rate_bps): outcome tokens for a buy and USDC for a sell. See
+    /// [`fees::net_proceeds`] for the formula.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the fee rate cannot be fetched, or the inputs are invalid as described
+    /// in [`fees::fee`].
+    pub async fn net_proceeds(
+        &self,
+        token_id: U256,
+        side: Side,
+        price: Decimal,
+        size: Decimal,
+    ) -> Result<Decimal> {
+        let fee_rate = self.fee_rate_bps(token_id).await?;
+
+        fees::net_proceeds(side, price, size, fee_rate.base_fee)
+    }
+
+    /// Retrieves the order placement rules for a market outcome token: its tick size, minimum
+    /// order size and whether it belongs to a neg-risk market.
+    ///
+    /// All three come from a single `/book` request and are cached, so later calls (and the tick
+    /// size and neg-risk lookups made while building orders) don't hit the API again. Once
+    /// fetched, the limit order builder also rejects orders below the minimum size before they
+    /// are signed, and [`OrderConstraints::validate`] can check orders fully offline.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the token ID is invalid.
+    pub async fn order_constraints(&self, token_id: U256) -> Result<OrderConstraints> {
+        if let (Some(tick_size), Some(neg_risk), Some(min_order_size)) = (
+            self.inner.tick_sizes.get(&token_id),
+            self.inner.neg_risk.get(&token_id),
+            self.inner.min_order_sizes.get(&token_id),
+        ) {
+            return Ok(OrderConstraints {
+                token_id,
+                tick_size: *tick_size,
+                min_order_size: *min_order_size,
+                neg_risk: *neg_risk,
+            });
+        }
+
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(token_id)
+            .build();
+        let book = self.order_book(&request).await?;
+
+        self.inner.tick_sizes.insert(token_id, book.tick_size);
+        self.inner.neg_risk.insert(token_id, book.neg_risk);
+        self.inner
+            .min_order_sizes
+            .insert(token_id, book.min_order_size);
+
+        Ok(OrderConstraints {
+            token_id,
+            tick_size: book.tick_size,
+            min_order_size: book.min_order_size,
+            neg_risk: book.neg_risk,
+        })
+    }
+
+    /// Returns the cached minimum order size for `token_id`, if
+    /// [`Self::order_constraints`] has fetched it.
+    pub(crate) fn cached_min_order_size(&self, token_id: U256) -> Option<Decimal> {
+        self.inner.min_order_sizes.get(&token_id).map(|size| *size)
+    }
+
     /// Checks if the current IP address is geoblocked from accessing Polymarket.
     ///
     /// This method queries the Polymarket geoblock endpoint to determine if access
@@ -908,7 +1361,7 @@ impl<S: State> Client<S> {
     /// ```
     pub async fn check_geoblock(&self) -> Result<GeoblockResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}api/geoblock", self.inner.geoblock_host),
@@ -933,13 +1386,39 @@ impl<S: State> Client<S> {
     ) -> Result<OrderBookSummaryResponse> {
         let params = request.query_params(None);
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}book{params}", self.host()))
             .build()?;
 
         crate::request(&self.inner.client, request, None).await
     }
 
+    /// Estimates how an order for `amount` on `side` would fill against the current book,
+    /// without placing anything.
+    ///
+    /// Fetches the book for `token_id` and walks it best price first, see
+    /// [`OrderBook::simulate_fill`]. When the book is too thin, the result reports a partial fill
+    /// rather than an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the book cannot be fetched or `side` is [`Side::Unknown`].
+    pub async fn simulate_fill(
+        &self,
+        token_id: U256,
+        side: Side,
+        amount: Amount,
+    ) -> Result<FillSimulation> {
+        let book = self
+            .order_book(&OrderBookSummaryRequest {
+                token_id,
+                side: None,
+            })
+            .await?;
+
+        OrderBook::from(&book).simulate_fill(side, amount)
+    }
+
     /// Retrieves orderbooks for multiple market outcome tokens.
     ///
     /// This is the batch version of [`Self::order_book`], allowing efficient
@@ -953,7 +1432,7 @@ impl<S: State> Client<S> {
         requests: &[OrderBookSummaryRequest],
     ) -> Result<Vec<OrderBookSummaryResponse>> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}books", self.host()))
             .json(requests)
             .build()?;
@@ -961,6 +1440,66 @@ impl<S: State> Client<S> {
         crate::request(&self.inner.client, request, None).await
     }
 
+    /// Retrieves the orderbooks of `token_ids`, keyed by token ID.
+    ///
+    /// Tokens are requested from the batch `/books` endpoint in chunks of up to 100. If the server
+    /// rejects a chunk because it has no orderbook for one of its tokens, the tokens are fetched
+    /// individually with a bounded number of concurrent requests instead. Tokens the server has no
+    /// orderbook for are absent from the map rather than failing the whole call.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails for any other reason, e.g. a network error, rate
+    /// limiting or a server error.
+    pub async fn books_map(
+        &self,
+        token_ids: &[U256],
+    ) -> Result<HashMap<U256, OrderBookSummaryResponse>> {
+        let mut unique = token_ids.to_vec();
+        unique.sort_unstable();
+        unique.dedup();
+
+        let mut books = HashMap::with_capacity(unique.len());
+
+        for chunk in unique.chunks(MAX_BOOKS_PER_REQUEST) {
+            let requests: Vec<_> = chunk
+                .iter()
+                .map(|token_id| {
+                    OrderBookSummaryRequest::builder()
+                        .token_id(*token_id)
+                        .build()
+                })
+                .collect();
+
+            let chunk_books = match self.order_books(&requests).await {
+                Ok(chunk_books) => chunk_books,
+                Err(e) if is_missing_orderbook(&e) => {
+                    let results = fan_out_collect(
+                        requests,
+                        BOOKS_FALLBACK_CONCURRENCY,
+                        |request| async move { self.order_book(&request).await },
+                    )
+                    .await;
+
+                    let mut chunk_books = Vec::with_capacity(results.len());
+                    for result in results {
+                        match result {
+                            Ok(book) => chunk_books.push(book),
+                            Err(e) if is_missing_orderbook(&e) => {}
+                            Err(e) => return Err(e),
+                        }
+                    }
+                    chunk_books
+                }
+                Err(e) => return Err(e),
+            };
+
+            books.extend(chunk_books.into_iter().map(|book| (book.asset_id, book)));
+        }
+
+        Ok(books)
+    }
+
     /// Retrieves the price of the most recent trade for a market outcome token.
     ///
     /// Returns the last executed trade price, which represents the most recent
@@ -975,7 +1514,7 @@ impl<S: State> Client<S> {
     ) -> Result<LastTradePriceResponse> {
         let params = request.query_params(None);
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}last-trade-price{params}", self.host()),
@@ -998,7 +1537,7 @@ impl<S: State> Client<S> {
         token_ids: &[LastTradePriceRequest],
     ) -> Result<Vec<LastTradesPricesResponse>> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}last-trades-prices", self.host()))
             .json(token_ids)
             .build()?;
@@ -1016,7 +1555,7 @@ impl<S: State> Client<S> {
     /// Returns an error if the request fails or the condition ID is invalid.
     pub async fn market(&self, condition_id: &str) -> Result<MarketResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}markets/{condition_id}", self.host()),
@@ -1036,9 +1575,9 @@ impl<S: State> Client<S> {
     ///
     /// Returns an error if the request fails.
     pub async fn markets(&self, next_cursor: Option<String>) -> Result<Page<MarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        let cursor = next_cursor.map_or(String::new(), |c| format!("?{}", crate::cursor_param(&c)));
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}markets{cursor}", self.host()))
             .build()?;
 
@@ -1058,9 +1597,9 @@ impl<S: State> Client<S> {
         &self,
         next_cursor: Option<String>,
     ) -> Result<Page<MarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        let cursor = next_cursor.map_or(String::new(), |c| format!("?{}", crate::cursor_param(&c)));
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}sampling-markets{cursor}", self.host()),
@@ -1083,9 +1622,9 @@ impl<S: State> Client<S> {
         &self,
         next_cursor: Option<String>,
     ) -> Result<Page<SimplifiedMarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        let cursor = next_cursor.map_or(String::new(), |c| format!("?{}", crate::cursor_param(&c)));
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}simplified-markets{cursor}", self.host()),
@@ -1108,9 +1647,9 @@ impl<S: State> Client<S> {
         &self,
         next_cursor: Option<String>,
     ) -> Result<Page<SimplifiedMarketResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        let cursor = next_cursor.map_or(String::new(), |c| format!("?{}", crate::cursor_param(&c)));
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}sampling-simplified-markets{cursor}", self.host()),
@@ -1151,8 +1690,23 @@ impl<S: State> Client<S> {
         }
     }
 
-    fn client(&self) -> &ReqwestClient {
-        &self.inner.client
+    /// Returns the order signing domain for `chain_id`, computing it on first use.
+    fn order_domain(&self, chain_id: ChainId) -> OrderDomain {
+        *self
+            .inner
+            .order_domains
+            .entry(chain_id)
+            .or_insert_with(|| OrderDomain::new(chain_id))
+    }
+
+    fn get_request<Req: Serialize>(&self, path: &str, request: &Req) -> Result<Request> {
+        let path = path.trim_start_matches('/');
+        let params = request.query_params(None);
+
+        Ok(self
+            .http_client()
+            .request(Method::GET, format!("{}{path}{params}", self.host()))
+            .build()?)
     }
 }
 
@@ -1183,14 +1737,7 @@ impl Client<Unauthenticated> {
     /// # }
     /// ```
     pub fn new(host: &str, config: Config) -> Result<Client<Unauthenticated>> {
-        let mut headers = HeaderMap::new();
-
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
-        headers.insert("Accept", HeaderValue::from_static("*/*"));
-        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
-        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
+        let client = http_client(&config)?;
 
         let geoblock_host = Url::parse(
             config
@@ -1208,10 +1755,13 @@ impl Client<Unauthenticated> {
                 tick_sizes: DashMap::new(),
                 neg_risk: DashMap::new(),
                 fee_rate_bps: DashMap::new(),
+                min_order_sizes: DashMap::new(),
+                order_domains: DashMap::new(),
                 state: Unauthenticated,
                 funder: None,
                 signature_type: SignatureType::Eoa,
                 salt_generator: generate_seed,
+                server_time_offset: Arc::default(),
             }),
             #[cfg(feature = "heartbeats")]
             heartbeat_token: DroppingCancellationToken(None),
@@ -1262,6 +1812,54 @@ impl Client<Unauthenticated> {
         }
     }
 
+    /// Authenticates every signer concurrently and returns one authenticated client per signer, in
+    /// the same order as `signers`.
+    ///
+    /// Each signer is authenticated on its own client, as if by
+    /// [`Self::authentication_builder`] followed by [`AuthenticationBuilder::authenticate`], so
+    /// credentials are created or derived independently per signer according to the
+    /// configured [`ApiKeyStrategy`], and one signer failing does not affect the others. The
+    /// clients share this client's configuration and HTTP connection pool, and start from a copy
+    /// of its market caches. At most eight handshakes are in flight at once.
+    pub async fn authenticate_many<S: Signer + Sync>(
+        &self,
+        signers: &[S],
+    ) -> Vec<Result<Client<Authenticated<Normal>>>> {
+        fan_out_collect(signers, AUTHENTICATION_CONCURRENCY, |signer| {
+            self.detached()
+                .authentication_builder(signer)
+                .authenticate()
+        })
+        .await
+    }
+
+    /// Returns an independent client with the same configuration, sharing the HTTP connection
+    /// pool and starting from a copy of the caches.
+    fn detached(&self) -> Client<Unauthenticated> {
+        let inner = &self.inner;
+
+        Client {
+            inner: Arc::new(ClientInner {
+                config: inner.config.clone(),
+                host: inner.host.clone(),
+                geoblock_host: inner.geoblock_host.clone(),
+                client: inner.client.clone(),
+                tick_sizes: inner.tick_sizes.clone(),
+                neg_risk: inner.neg_risk.clone(),
+                fee_rate_bps: inner.fee_rate_bps.clone(),
+                min_order_sizes: inner.min_order_sizes.clone(),
+                order_domains: inner.order_domains.clone(),
+                state: Unauthenticated,
+                funder: inner.funder,
+                signature_type: inner.signature_type,
+                salt_generator: inner.salt_generator,
+                server_time_offset: Arc::clone(&inner.server_time_offset),
+            }),
+            #[cfg(feature = "heartbeats")]
+            heartbeat_token: DroppingCancellationToken(None),
+        }
+    }
+
     /// Attempts to create a new set of [`Credentials`] and returns an error if there already is one
     /// for the particular L2 header's (signer) `address` and `nonce`.
     pub async fn create_api_key<S: Signer>(
@@ -1292,6 +1890,33 @@ impl Client<Unauthenticated> {
     ) -> Result<Credentials> {
         self.inner.create_or_derive_api_key(signer, nonce).await
     }
+
+    /// Like [`Self::get_raw`], but returns the body together with the HTTP status and rate limit
+    /// headers. See [raw requests](crate::response#raw-requests).
+    pub async fn get_with_meta<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        request: &Req,
+    ) -> Result<Response<Res>> {
+        let request = self.get_request(path, request)?;
+
+        crate::request_with_meta(&self.inner.client, request, None).await
+    }
+
+    /// Sends a GET request to an endpoint this client does not wrap yet and deserializes the body
+    /// into `Res`.
+    ///
+    /// See [raw requests](crate::response#raw-requests) for how the request is sent and the
+    /// stability caveats.
+    pub async fn get_raw<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &Req,
+    ) -> Result<Res> {
+        self.get_with_meta(path, query)
+            .await
+            .map(Response::into_data)
+    }
 }
 
 impl<K: Kind> Client<Authenticated<K>> {
@@ -1320,10 +1945,13 @@ impl<K: Kind> Client<Authenticated<K>> {
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
+                min_order_sizes: inner.min_order_sizes,
+                order_domains: inner.order_domains,
                 // Reset the order parameters that were previously stored on the client
                 funder: None,
                 signature_type: SignatureType::Eoa,
                 salt_generator: generate_seed,
+                server_time_offset: inner.server_time_offset,
             }),
             #[cfg(feature = "heartbeats")]
             heartbeat_token: DroppingCancellationToken(None),
@@ -1348,11 +1976,22 @@ impl<K: Kind> Client<Authenticated<K>> {
         self.state().address
     }
 
+    /// Returns the [`Credentials`] this client authenticates with. When they were created or
+    /// derived during authentication, [`Credentials::nonce`] reports the nonce that was used.
+    ///
+    /// The credentials can be serialized and passed to [`Client::with_credentials`] in a later
+    /// session to skip signing for them again. The serialized form contains the API secret and
+    /// passphrase in plain text, so it must be stored securely.
+    #[must_use]
+    pub fn credentials(&self) -> &Credentials {
+        &self.state().credentials
+    }
+
     /// Return all API keys associated with the address corresponding to the inner signer in
     /// [`Authenticated<K>`].
     pub async fn api_keys(&self) -> Result<ApiKeysResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}auth/api-keys", self.host()))
             .build()?;
         let headers = self.create_headers(&request).await?;
@@ -1360,22 +1999,78 @@ impl<K: Kind> Client<Authenticated<K>> {
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
-    /// Deletes the current API key used by this authenticated client.
+    /// Returns [`Self::api_keys`] as [`ApiKeyInfo`]s.
+    ///
+    /// The CLOB does not report when standard API keys were created, so `created_at` is always
+    /// `None` here. Builder clients can use
+    /// [`all_api_key_infos`](Client::<Authenticated<Builder>>::all_api_key_infos) to also list
+    /// builder keys, which do carry creation times.
+    pub async fn api_key_infos(&self) -> Result<Vec<ApiKeyInfo>> {
+        let response = self.api_keys().await?;
+
+        Ok(response
+            .keys()
+            .iter()
+            .copied()
+            .map(ApiKeyInfo::from)
+            .collect())
+    }
+
+    /// Checks that the CLOB still accepts this client's credentials by listing its
+    /// [API keys](Self::api_keys), e.g. right after loading saved credentials with
+    /// [`Client::with_credentials`] and before trading.
+    ///
+    /// Returns `Ok(false)` if the CLOB rejects the credentials as expired or invalid with a
+    /// `401 Unauthorized` or `403 Forbidden`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the validity could not be determined, e.g. because the CLOB could not
+    /// be reached or replied with another non-successful status.
+    pub async fn verify_credentials(&self) -> Result<bool> {
+        match self.api_keys().await {
+            Ok(_) => Ok(true),
+            Err(e)
+                if e.downcast_ref::<Status>().is_some_and(|status| {
+                    matches!(
+                        status.status_code,
+                        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+                    )
+                }) =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Deletes the API key used by this authenticated client.
+    ///
+    /// After deletion, this client and its clones can no longer access authenticated endpoints.
+    /// [Deauthenticate](Self::deauthenticate) it and authenticate again, e.g. with a new nonce, to
+    /// keep using them.
     ///
-    /// After deletion, this client will no longer be able to access authenticated
-    /// endpoints. You will need to create or derive a new API key to continue.
+    /// The CLOB only deletes the key that signs the request, so there is no key ID to pass. To
+    /// clean up an older key when rotating credentials, authenticate with that key first, either
+    /// through [`AuthenticationBuilder::nonce`] with the nonce it was derived with or through
+    /// [`AuthenticationBuilder::credentials`], and delete it from that client.
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails or the API key cannot be deleted.
-    pub async fn delete_api_key(&self) -> Result<serde_json::Value> {
+    /// Returns an error if the request fails or the API key cannot be deleted, in which case the
+    /// key is still valid. A key that does not exist, for example because it was already deleted,
+    /// is rejected by the API and surfaces as an [`ApiKeyNotFound`] error.
+    pub async fn delete_api_key(&self) -> Result<()> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::DELETE, format!("{}auth/api-key", self.host()))
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        match crate::request::<IgnoredAny>(&self.inner.client, request, Some(headers)).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(api_key_not_found(&e).map_or(e, Error::from)),
+        }
     }
 
     /// Checks if the account is in closed-only mode (banned from opening new positions).
@@ -1389,7 +2084,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the request fails.
     pub async fn closed_only_mode(&self) -> Result<BanStatusResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}auth/ban-status/closed-only", self.host()),
@@ -1413,6 +2108,9 @@ impl<K: Kind> Client<Authenticated<K>> {
     }
 
     /// Attempts to sign the provided [`SignableOrder`] using the inner signer of [`Authenticated<K>`]
+    ///
+    /// The order is signed against the neg-risk exchange when [`Self::neg_risk`] reports the
+    /// order's token as belonging to a neg-risk market, and against the regular exchange otherwise.
     #[expect(
         clippy::missing_panics_doc,
         reason = "No need to publicly document as we are guarded by the typestate pattern. \
@@ -1433,21 +2131,35 @@ impl<K: Kind> Client<Authenticated<K>> {
             .chain_id()
             .expect("Validated not none in `authenticate`");
 
-        let exchange_contract = contract_config(chain_id, neg_risk)
-            .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
-            .exchange;
+        let hash = self.order_domain(chain_id).signing_hash(&order, neg_risk)?;
 
-        let domain = Eip712Domain {
-            name: ORDER_NAME,
-            version: VERSION,
-            chain_id: Some(U256::from(chain_id)),
-            verifying_contract: Some(exchange_contract),
-            ..Eip712Domain::default()
-        };
+        let signature = signer.sign_hash(&hash).await?;
 
-        let signature = signer
-            .sign_hash(&order.eip712_signing_hash(&domain))
-            .await?;
+        Ok(SignedOrder {
+            order,
+            signature,
+            order_type,
+            owner: self.state().credentials.key,
+            post_only,
+        })
+    }
+
+    /// Signs the provided [`SignableOrder`] with an [`OrderSigner`], such as a
+    /// [`CachedDomainSigner`](crate::clob::signing::CachedDomainSigner) kept per maker when
+    /// rotating between several makers.
+    ///
+    /// As with [`Self::sign`], the exchange is chosen based on [`Self::neg_risk`].
+    pub async fn sign_with<O: OrderSigner + ?Sized>(
+        &self,
+        signer: &O,
+        SignableOrder {
+            order,
+            order_type,
+            post_only,
+        }: SignableOrder,
+    ) -> Result<SignedOrder> {
+        let neg_risk = self.neg_risk(order.tokenId).await?.neg_risk;
+        let signature = signer.sign_order(&order, neg_risk).await?;
 
         Ok(SignedOrder {
             order,
@@ -1473,13 +2185,71 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// - The request fails
     pub async fn post_order(&self, order: SignedOrder) -> Result<PostOrderResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}order", self.host()))
             .json(&order)
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        let (headers, time_source) = self.create_headers_with_time_source(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let mut response: PostOrderResponse =
+            crate::request(&self.inner.client, request, Some(headers)).await?;
+        response.time_source = Some(time_source);
+        self.apply_builder_fee(&order, &mut response);
+        Ok(response)
+    }
+
+    /// Posts a signed order with an `Idempotency-Key` header, retrying up to `retries` more times
+    /// if the request fails to connect or times out.
+    ///
+    /// Every attempt sends the same `order` and `idempotency_key`, so a server that honors the
+    /// header can de-duplicate a retry whose original attempt did reach it. Servers that ignore the
+    /// header still accept the order; the key is then only useful to correlate attempts in your
+    /// own logs. Use a fresh key (e.g. [`Uuid::new_v4`]) for every distinct order.
+    ///
+    /// Retries wait with exponential backoff, starting at 100ms and capped at 2s, each delay
+    /// randomized by up to half so that clients retrying at once spread out. Errors other than
+    /// connection failures and timeouts, including HTTP status errors, are returned immediately
+    /// without retrying.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempt, see [`Self::post_order`].
+    pub async fn post_order_with_idempotency_key(
+        &self,
+        order: &SignedOrder,
+        idempotency_key: Uuid,
+        retries: u32,
+    ) -> Result<PostOrderResponse> {
+        let mut attempt = 0;
+
+        loop {
+            let request = self
+                .http_client()
+                .request(Method::POST, format!("{}order", self.host()))
+                .json(order)
+                .build()?;
+            let (mut headers, time_source) = self.create_headers_with_time_source(&request).await?;
+            headers.insert(
+                IDEMPOTENCY_KEY,
+                HeaderValue::from_str(&idempotency_key.to_string())?,
+            );
+
+            match crate::request::<PostOrderResponse>(&self.inner.client, request, Some(headers))
+                .await
+            {
+                Err(e) if attempt < retries && is_retryable(&e) => {
+                    tokio::time::sleep(retry_delay(attempt)).await;
+                    attempt += 1;
+                }
+                result => {
+                    return result.map(|mut response| {
+                        response.time_source = Some(time_source);
+                        self.apply_builder_fee(order, &mut response);
+                        response
+                    });
+                }
+            }
+        }
     }
 
     /// Posts multiple signed orders to the orderbook in a single request.
@@ -1493,19 +2263,40 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if any order fails validation or the request fails.
     pub async fn post_orders(&self, orders: Vec<SignedOrder>) -> Result<Vec<PostOrderResponse>> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}orders", self.host()))
             .json(&orders)
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        let (headers, time_source) = self.create_headers_with_time_source(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let mut responses: Vec<PostOrderResponse> =
+            crate::request(&self.inner.client, request, Some(headers)).await?;
+        for (order, response) in orders.iter().zip(&mut responses) {
+            response.time_source = Some(time_source);
+            self.apply_builder_fee(order, response);
+        }
+        Ok(responses)
+    }
+
+    /// Sets the builder fee owed on the USDC notional `response` matched for `order`, if this is a
+    /// builder client with a fee. A buy pays its making amount in USDC, a sell receives its taking
+    /// amount.
+    fn apply_builder_fee(&self, order: &SignedOrder, response: &mut PostOrderResponse) {
+        let Some(fee) = self.state().kind.builder_fee() else {
+            return;
+        };
+
+        let notional = match Side::try_from(order.order.side) {
+            Ok(Side::Buy) => response.making_amount,
+            _ => response.taking_amount,
+        };
+        response.builder_fee = Some(fee.amount(notional));
     }
 
     /// Attempts to return the corresponding order at the provided `order_id`
     pub async fn order(&self, order_id: &str) -> Result<OpenOrderResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}data/order/{order_id}", self.host()))
             .build()?;
         let headers = self.create_headers(&request).await?;
@@ -1529,7 +2320,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     ) -> Result<Page<OpenOrderResponse>> {
         let params = request.query_params(next_cursor.as_deref());
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}data/orders{params}", self.host()))
             .build()?;
         let headers = self.create_headers(&request).await?;
@@ -1548,33 +2339,76 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// or the request fails.
     pub async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::DELETE, format!("{}order", self.host()))
             .json(&json!({ "orderId": order_id }))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.cancel(request).await
     }
 
-    /// Cancels multiple orders by their order IDs in a single request.
+    /// Cancels multiple orders by their order IDs.
     ///
     /// This is the batch version of [`Self::cancel_order`], allowing efficient
     /// cancellation of many orders at once. All specified orders must belong
     /// to the authenticated user.
     ///
+    /// Inputs longer than [`Config`]'s `cancel_batch_size` (by default
+    /// [`DEFAULT_CANCEL_BATCH_SIZE`]) are split into batches that are sent concurrently, and the
+    /// responses are merged. `canceled` lists the IDs in the order of their batches, and every
+    /// rejected ID keeps its own reason in `not_canceled`. A batch whose request fails does not
+    /// discard the others: it is reported with its error in `failed_batches`.
+    ///
     /// # Errors
     ///
-    /// Returns an error if any order ID is invalid or the request fails.
+    /// Returns an error if `cancel_batch_size` is zero, or if the request of every batch fails,
+    /// in which case the error of the first batch is returned.
     pub async fn cancel_orders(&self, order_ids: &[&str]) -> Result<CancelOrdersResponse> {
+        let batch_size = self.inner.config.cancel_batch_size;
+        if batch_size == 0 {
+            return Err(Error::validation(
+                "Unable to cancel orders: cancel_batch_size must be greater than zero",
+            ));
+        }
+
+        if order_ids.len() <= batch_size {
+            return self.cancel_order_batch(order_ids).await;
+        }
+
+        let results = fan_out_collect(order_ids.chunks(batch_size), CANCEL_CONCURRENCY, |batch| {
+            self.cancel_order_batch(batch)
+        })
+        .await;
+
+        let mut merged = CancelOrdersResponse::default();
+        let mut any_succeeded = false;
+        for (batch, result) in order_ids.chunks(batch_size).zip(results) {
+            match result {
+                Ok(response) => {
+                    any_succeeded = true;
+                    merged.canceled.extend(response.canceled);
+                    merged.not_canceled.extend(response.not_canceled);
+                }
+                Err(error) => merged.failed_batches.push(FailedCancelBatch {
+                    order_ids: batch.iter().map(|id| (*id).to_owned()).collect(),
+                    error,
+                }),
+            }
+        }
+
+        if !any_succeeded && !merged.failed_batches.is_empty() {
+            return Err(merged.failed_batches.swap_remove(0).error);
+        }
+
+        Ok(merged)
+    }
+
+    async fn cancel_order_batch(&self, order_ids: &[&str]) -> Result<CancelOrdersResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::DELETE, format!("{}orders", self.host()))
             .json(&json!(order_ids))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.cancel(request).await
     }
 
     /// Cancels all open orders for the authenticated user.
@@ -1587,12 +2421,10 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the request fails.
     pub async fn cancel_all_orders(&self) -> Result<CancelOrdersResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::DELETE, format!("{}cancel-all", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.cancel(request).await
     }
 
     /// Attempts to cancel all open orders for a particular [`CancelMarketOrderRequest::market`]
@@ -1602,16 +2434,25 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &CancelMarketOrderRequest,
     ) -> Result<CancelOrdersResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::DELETE,
                 format!("{}cancel-market-orders", self.host()),
             )
             .json(&request)
             .build()?;
+        self.cancel(request).await
+    }
+
+    /// Sends a cancel-family request. The CLOB sometimes answers a successful cancel with an
+    /// empty or `null` body, which is returned as an empty [`CancelOrdersResponse`].
+    async fn cancel(&self, request: Request) -> Result<CancelOrdersResponse> {
         let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        let response: Option<CancelOrdersResponse> =
+            crate::request(&self.inner.client, request, Some(headers)).await?;
+
+        Ok(response.unwrap_or_default())
     }
 
     /// Retrieves a paginated list of trades for the authenticated user.
@@ -1630,7 +2471,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     ) -> Result<Page<TradeResponse>> {
         let params = request.query_params(next_cursor.as_deref());
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}data/trades{params}", self.host()))
             .build()?;
         let headers = self.create_headers(&request).await?;
@@ -1648,7 +2489,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the request fails.
     pub async fn notifications(&self) -> Result<Vec<NotificationResponse>> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}notifications", self.host()))
             .query(&[("signature_type", self.inner.signature_type as u8)])
             .build()?;
@@ -1667,8 +2508,8 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the request fails or the notification IDs are invalid.
     pub async fn delete_notifications(&self, request: &DeleteNotificationsRequest) -> Result<()> {
         let params = request.query_params(None);
-        let mut request = self
-            .client()
+        let request = self
+            .http_client()
             .request(
                 Method::DELETE,
                 format!("{}notifications{params}", self.host()),
@@ -1676,15 +2517,32 @@ impl<K: Kind> Client<Authenticated<K>> {
             .json(&request)
             .build()?;
         let headers = self.create_headers(&request).await?;
-        *request.headers_mut() = headers;
 
-        // We have to send the request separately from `self.request` because this endpoint does
-        // not return anything in the response body. Otherwise, we would get an EOF error from reqwest
-        self.client().execute(request).await?;
+        let _: IgnoredAny = crate::request(&self.inner.client, request, Some(headers)).await?;
 
         Ok(())
     }
 
+    /// Drops (acknowledges) the notifications with the given IDs so they are no longer returned
+    /// by [`Self::notifications`].
+    ///
+    /// An empty `ids` slice is a no-op and does not issue a request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn drop_notifications(&self, ids: &[String]) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let request = DeleteNotificationsRequest::builder()
+            .notification_ids(ids.to_vec())
+            .build();
+
+        self.delete_notifications(&request).await
+    }
+
     /// Retrieves the user's USDC balance and token allowances.
     ///
     /// Returns the current USDC balance in the user's wallet and the allowance
@@ -1704,7 +2562,7 @@ impl<K: Kind> Client<Authenticated<K>> {
 
         let params = request.query_params(None);
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}balance-allowance{params}", self.host()),
@@ -1715,6 +2573,135 @@ impl<K: Kind> Client<Authenticated<K>> {
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
+    /// Returns the user's collateral (USDC) balance in whole units, e.g. `12.5` for 12.5 USDC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn collateral_balance(&self) -> Result<Decimal> {
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(AssetType::Collateral)
+            .build()?;
+
+        Ok(from_base_units(
+            self.balance_allowance(request).await?.balance,
+        ))
+    }
+
+    /// Returns the user's balance of the outcome token `token_id` in shares.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn token_balance(&self, token_id: U256) -> Result<Decimal> {
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(AssetType::Conditional)
+            .token_id(token_id)
+            .build()?;
+
+        Ok(from_base_units(
+            self.balance_allowance(request).await?.balance,
+        ))
+    }
+
+    /// Checks whether the user's balance and allowance cover `order` before it is posted, so that
+    /// orders the exchange would reject for lack of funds are caught locally.
+    ///
+    /// Buy orders are checked against the collateral (USDC) balance and sell orders against the
+    /// balance of the order's outcome token, in both cases comparing with the order's maker
+    /// amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order's side is invalid or the balance request fails.
+    pub async fn check_order_feasibility(&self, order: &SignedOrder) -> Result<FeasibilityReport> {
+        let asset_type = match Side::try_from(order.order.side)? {
+            Side::Buy => AssetType::Collateral,
+            Side::Sell => AssetType::Conditional,
+            side => return Err(Error::validation(format!("Invalid side: {side}"))),
+        };
+        let token_id = (asset_type == AssetType::Conditional).then_some(order.order.tokenId);
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(asset_type.clone())
+            .maybe_token_id(token_id)
+            .build()?;
+        let response = self.balance_allowance(request).await?;
+
+        let required = Decimal::from_str_exact(&order.order.makerAmount.to_string())
+            .map(from_base_units)
+            .map_err(|e| {
+                Error::validation(format!(
+                    "Unable to read order maker amount {}: {e}",
+                    order.order.makerAmount
+                ))
+            })?;
+        let balance = from_base_units(response.balance);
+        let allowance = response
+            .allowance()
+            .and_then(|allowance| Decimal::from_str_exact(&allowance.to_string()).ok())
+            .map(from_base_units);
+
+        let available = allowance.map_or(balance, |allowance| balance.min(allowance));
+        let shortfall = (required - available).max(Decimal::ZERO);
+
+        Ok(FeasibilityReport::builder()
+            .asset_type(asset_type)
+            .required(required)
+            .balance(balance)
+            .maybe_min_allowance(allowance)
+            .sufficient(shortfall.is_zero())
+            .shortfall(shortfall)
+            .build())
+    }
+
+    /// Polls [`Client::balance_allowance`] until every allowance in the response is at least
+    /// `min`, returning that response.
+    ///
+    /// On-chain approvals take a while to be reflected by the CLOB, so call this after
+    /// [`Client::update_balance_allowance`] (e.g. when onboarding a new wallet) before placing the
+    /// first order. Polls start at the configured
+    /// [`allowance_poll_interval`](ConfigBuilder::allowance_poll_interval) and back off
+    /// exponentially.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`Kind::Timeout`](crate::error::Kind::Timeout) if the allowance is
+    /// still below `min` once `timeout` has elapsed, or any error from
+    /// [`Client::balance_allowance`].
+    pub async fn wait_for_allowance(
+        &self,
+        request: BalanceAllowanceRequest,
+        min: U256,
+        timeout: Duration,
+    ) -> Result<BalanceAllowanceResponse> {
+        let deadline = Instant::now() + timeout;
+        let base_interval = self
+            .inner
+            .config
+            .approval_poll_interval
+            .unwrap_or(DEFAULT_ALLOWANCE_POLL_INTERVAL);
+        let max_interval = base_interval.max(MAX_ALLOWANCE_POLL_INTERVAL);
+        let mut interval = base_interval;
+
+        loop {
+            let response = self.balance_allowance(request.clone()).await?;
+            if response
+                .allowance()
+                .is_some_and(|allowance| allowance >= min)
+            {
+                return Ok(response);
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Timeout { duration: timeout }.into());
+            }
+
+            tokio::time::sleep(interval.min(remaining)).await;
+            interval = interval.saturating_mul(2).min(max_interval);
+        }
+    }
+
     /// Forces an update of the cached balance and allowance data.
     ///
     /// Triggers the CLOB backend to refresh its cached view of the user's
@@ -1733,8 +2720,8 @@ impl<K: Kind> Client<Authenticated<K>> {
         }
 
         let params = request.query_params(None);
-        let mut request = self
-            .client()
+        let request = self
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}balance-allowance/update{params}", self.host()),
@@ -1742,11 +2729,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        *request.headers_mut() = headers;
-
-        // We have to send the request separately from `self.request` because this endpoint does
-        // not return anything in the response body. Otherwise, we would get an EOF error from reqwest
-        self.client().execute(request).await?;
+        let _: IgnoredAny = crate::request(&self.inner.client, request, Some(headers)).await?;
 
         Ok(())
     }
@@ -1762,7 +2745,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the order ID is invalid or the request fails.
     pub async fn is_order_scoring(&self, order_id: &str) -> Result<OrderScoringResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}order-scoring", self.host()))
             .query(&[("order_id", order_id)])
             .build()?;
@@ -1781,7 +2764,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if any order ID is invalid or the request fails.
     pub async fn are_orders_scoring(&self, order_ids: &[&str]) -> Result<OrdersScoringResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}orders-scoring", self.host()))
             .json(&order_ids)
             .build()?;
@@ -1803,9 +2786,9 @@ impl<K: Kind> Client<Authenticated<K>> {
         date: NaiveDate,
         next_cursor: Option<String>,
     ) -> Result<Page<UserEarningResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        let cursor = next_cursor.map_or(String::new(), |c| format!("?{}", crate::cursor_param(&c)));
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}rewards/user{cursor}", self.host()))
             .query(&[
                 ("date", date.to_string()),
@@ -1833,7 +2816,7 @@ impl<K: Kind> Client<Authenticated<K>> {
         date: NaiveDate,
     ) -> Result<Vec<TotalUserEarningResponse>> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}rewards/user/total", self.host()))
             .query(&[
                 ("date", date.to_string()),
@@ -1848,6 +2831,43 @@ impl<K: Kind> Client<Authenticated<K>> {
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
+    /// Retrieves total market maker earnings for each day from `start` to `end` (inclusive).
+    ///
+    /// Days are fetched concurrently with [`Self::total_earnings_for_user_for_day`], with at most
+    /// a handful of requests in flight at once, and returned in date order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `start` is after `end`, if the range spans more than 90 days, or if
+    /// any of the requests fail.
+    pub async fn earnings_range(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<(NaiveDate, Vec<TotalUserEarningResponse>)>> {
+        if start > end {
+            return Err(Error::validation(format!(
+                "Unable to fetch earnings: start date {start} is after end date {end}"
+            )));
+        }
+
+        let days = (end - start).num_days() + 1;
+        if days > MAX_EARNINGS_RANGE_DAYS {
+            return Err(Error::validation(format!(
+                "Unable to fetch earnings for {days} days, must be <= {MAX_EARNINGS_RANGE_DAYS}"
+            )));
+        }
+
+        stream::iter(start.iter_days().take_while(|date| *date <= end))
+            .map(|date| async move {
+                let earnings = self.total_earnings_for_user_for_day(date).await?;
+                Ok((date, earnings))
+            })
+            .buffered(EARNINGS_RANGE_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
     /// Retrieves user earnings along with market reward configurations.
     ///
     /// Returns earnings data combined with the reward configuration for each market,
@@ -1863,7 +2883,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     ) -> Result<Vec<UserRewardsEarningResponse>> {
         let params = request.query_params(next_cursor.as_deref());
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}rewards/user/total{params}", self.host()),
@@ -1888,7 +2908,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the request fails.
     pub async fn reward_percentages(&self) -> Result<RewardsPercentagesResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}rewards/user/percentages", self.host()),
@@ -1916,9 +2936,9 @@ impl<K: Kind> Client<Authenticated<K>> {
         &self,
         next_cursor: Option<String>,
     ) -> Result<Page<CurrentRewardResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        let cursor = next_cursor.map_or(String::new(), |c| format!("?{}", crate::cursor_param(&c)));
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}rewards/markets/current{cursor}", self.host()),
@@ -1942,9 +2962,9 @@ impl<K: Kind> Client<Authenticated<K>> {
         condition_id: &str,
         next_cursor: Option<String>,
     ) -> Result<Page<MarketRewardResponse>> {
-        let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
+        let cursor = next_cursor.map_or(String::new(), |c| format!("?{}", crate::cursor_param(&c)));
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}rewards/markets/{condition_id}{cursor}", self.host()),
@@ -1955,6 +2975,78 @@ impl<K: Kind> Client<Authenticated<K>> {
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
+    /// Summarizes the user's market making rewards for the current (UTC) day.
+    ///
+    /// Fetches [`Self::current_rewards`], [`Self::reward_percentages`], and
+    /// [`Self::earnings_for_user_for_day`] concurrently (following all pages), and combines them
+    /// into each market's accrued rewards, the user's share of the market's rewards, and the
+    /// rewards projected for a full day at that share. Markets without an active reward program
+    /// are reported with a zero `rate_per_day` and projection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the underlying requests fail.
+    pub async fn my_rewards_summary(&self) -> Result<RewardsSummary> {
+        let date = Utc::now().date_naive();
+
+        let (current_rewards, percentages, earnings) = futures::try_join!(
+            self.stream_data(Client::current_rewards)
+                .try_collect::<Vec<_>>(),
+            self.reward_percentages(),
+            self.stream_data(|client, cursor| client.earnings_for_user_for_day(date, cursor))
+                .try_collect::<Vec<_>>(),
+        )?;
+
+        let rates: HashMap<B256, Decimal> = current_rewards
+            .into_iter()
+            .map(|reward| {
+                let rate_per_day = reward
+                    .rewards_config
+                    .iter()
+                    .map(|config| config.rate_per_day)
+                    .sum();
+                (reward.condition_id, rate_per_day)
+            })
+            .collect();
+
+        let mut markets: BTreeMap<B256, (Decimal, Decimal)> = BTreeMap::new();
+        for earning in earnings {
+            markets.entry(earning.condition_id).or_default().0 += earning.earnings;
+        }
+        for (condition_id, percentage) in percentages {
+            let Ok(condition_id) = B256::from_str(&condition_id) else {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    "Skipping reward percentage for invalid condition ID {condition_id}"
+                );
+                continue;
+            };
+            markets.entry(condition_id).or_default().1 = percentage;
+        }
+
+        let markets: Vec<_> = markets
+            .into_iter()
+            .map(|(condition_id, (accrued, percentage))| {
+                let rate_per_day = rates.get(&condition_id).copied().unwrap_or_default();
+
+                MarketRewardsSummary::builder()
+                    .condition_id(condition_id)
+                    .accrued(accrued)
+                    .percentage(percentage)
+                    .rate_per_day(rate_per_day)
+                    .projected_daily(rate_per_day * percentage / Decimal::ONE_HUNDRED)
+                    .build()
+            })
+            .collect();
+
+        Ok(RewardsSummary::builder()
+            .date(date)
+            .accrued(markets.iter().map(|market| market.accrued).sum())
+            .projected_daily_total(markets.iter().map(|market| market.projected_daily).sum())
+            .markets(markets)
+            .build())
+    }
+
     /// Creates a new Builder API key for order attribution.
     ///
     /// Builder API keys allow you to attribute orders to your builder account,
@@ -1966,7 +3058,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the request fails or the account is not eligible for builder keys.
     pub async fn create_builder_api_key(&self) -> Result<Credentials> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}auth/builder-api-key", self.host()))
             .build()?;
         let headers = self.create_headers(&request).await?;
@@ -1985,7 +3077,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the request fails.
     pub async fn post_heartbeat(&self, heartbeat_id: Option<Uuid>) -> Result<HeartbeatResponse> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}v1/heartbeats", self.host()))
             .json(&json!({ "heartbeat_id": heartbeat_id }))
             .build()?;
@@ -2086,14 +3178,71 @@ impl<K: Kind> Client<Authenticated<K>> {
         self.heartbeat_token.cancel_and_wait().await
     }
 
+    /// Like [`Self::get_raw`], but returns the body together with the HTTP status and rate limit
+    /// headers. See [raw requests](crate::response#raw-requests).
+    pub async fn get_with_meta<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        request: &Req,
+    ) -> Result<Response<Res>> {
+        let request = self.get_request(path, request)?;
+        let headers = self.create_headers(&request).await?;
+
+        crate::request_with_meta(&self.inner.client, request, Some(headers)).await
+    }
+
+    /// Sends an L2-authenticated GET request to an endpoint this client does not wrap yet and
+    /// deserializes the body into `Res`.
+    ///
+    /// See [raw requests](crate::response#raw-requests) for how the request is sent and the
+    /// stability caveats.
+    pub async fn get_raw<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &Req,
+    ) -> Result<Res> {
+        self.get_with_meta(path, query)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// Sends an L2-authenticated POST request with a JSON `body` to an endpoint this client does
+    /// not wrap yet and deserializes the response into `Res`.
+    ///
+    /// The authentication headers sign the serialized body exactly as they do for the typed
+    /// methods, so endpoints that verify the L2 signature accept the request.
+    ///
+    /// See [raw requests](crate::response#raw-requests) for how the request is sent and the
+    /// stability caveats.
+    pub async fn post_raw<B: Serialize + ?Sized, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<Res> {
+        let path = path.trim_start_matches('/');
+        let request = self
+            .http_client()
+            .request(Method::POST, format!("{}{path}", self.host()))
+            .json(body)
+            .build()?;
+        let headers = self.create_headers(&request).await?;
+
+        crate::request(&self.inner.client, request, Some(headers)).await
+    }
+
     async fn create_headers(&self, request: &Request) -> Result<HeaderMap> {
-        let timestamp = if self.inner.config.use_server_time {
-            self.server_time().await?
-        } else {
-            Utc::now().timestamp()
-        };
+        Ok(self.create_headers_with_time_source(request).await?.0)
+    }
 
-        auth::l2::create_headers(self.state(), request, timestamp).await
+    /// Like [`Self::create_headers`], also returning where the signing timestamp came from.
+    async fn create_headers_with_time_source(
+        &self,
+        request: &Request,
+    ) -> Result<(HeaderMap, TimeSource)> {
+        let (timestamp, time_source) = self.inner.timestamp().await?;
+        let headers = auth::l2::create_headers(self.state(), request, timestamp).await?;
+
+        Ok((headers, time_source))
     }
 
     fn order_builder<OrderKind>(&self) -> OrderBuilder<OrderKind, K> {
@@ -2112,6 +3261,8 @@ impl<K: Kind> Client<Authenticated<K>> {
             taker: None,
             order_type: None,
             post_only: Some(false),
+            worst_price: None,
+            max_slippage: None,
             client: Client {
                 inner: Arc::clone(&self.inner),
                 #[cfg(feature = "heartbeats")]
@@ -2123,12 +3274,86 @@ impl<K: Kind> Client<Authenticated<K>> {
 }
 
 impl Client<Authenticated<Normal>> {
+    /// Creates a client authenticated with previously saved `credentials`, skipping the L1
+    /// handshake that would otherwise create or derive them. This is the same as
+    /// [`Self::new`] followed by [`Self::authentication_builder`] with
+    /// [`AuthenticationBuilder::credentials`].
+    ///
+    /// `signer` must be the wallet the credentials belong to: its address is sent along with every
+    /// authenticated request and it signs orders.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the host URL is invalid or the signer has no supported chain id.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::str::FromStr as _;
+    ///
+    /// use alloy::signers::Signer as _;
+    /// use alloy::signers::local::LocalSigner;
+    /// use polymarket_client_sdk::POLYGON;
+    /// use polymarket_client_sdk::auth::Credentials;
+    /// use polymarket_client_sdk::clob::{Client, Config};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let signer = LocalSigner::from_str("0x...")?.with_chain_id(Some(POLYGON));
+    ///
+    /// // Saved from `client.credentials()` after a previous `authenticate()`. The file holds the
+    /// // API secret in plain text, so keep it as private as the key itself.
+    /// let credentials: Credentials =
+    ///     serde_json::from_str(&std::fs::read_to_string("credentials.json")?)?;
+    ///
+    /// let client = Client::with_credentials(
+    ///     "https://clob.polymarket.com",
+    ///     Config::default(),
+    ///     &signer,
+    ///     credentials,
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_credentials<S: Signer>(
+        host: &str,
+        config: Config,
+        signer: &S,
+        credentials: Credentials,
+    ) -> Result<Self> {
+        Client::<Unauthenticated>::new(host, config)?
+            .authentication_builder(signer)
+            .credentials(credentials)
+            .authenticate()
+            .await
+    }
+
     /// Convert this [`Client<Authenticated<Normal>>`] to [`Client<Authenticated<Builder>>`] using
     /// the provided `config`.
     ///
     /// Note: If `heartbeats` feature flag is enabled, then this method _will_ cancel all
     /// outstanding orders since it will disable the background heartbeats task and then
     /// re-enable it.
+    pub async fn promote_to_builder(
+        self,
+        config: BuilderConfig,
+    ) -> Result<Client<Authenticated<Builder>>> {
+        self.into_builder(config, None).await
+    }
+
+    /// Like [`Self::promote_to_builder`], but also records the [`BuilderFee`] charged on this
+    /// builder's orders, available through
+    /// [`Client::<Authenticated<Builder>>::builder_fee`](Client::builder_fee).
+    ///
+    /// The fee is not sent with orders, since the CLOB order schema has no builder fee fields.
+    pub async fn promote_to_builder_with_fee(
+        self,
+        config: BuilderConfig,
+        fee: BuilderFee,
+    ) -> Result<Client<Authenticated<Builder>>> {
+        self.into_builder(config, Some(fee)).await
+    }
+
     #[cfg_attr(
         not(feature = "heartbeats"),
         expect(
@@ -2137,9 +3362,10 @@ impl Client<Authenticated<Normal>> {
             reason = "Nothing to await or modify when heartbeats are disabled"
         )
     )]
-    pub async fn promote_to_builder(
+    async fn into_builder(
         mut self,
         config: BuilderConfig,
+        fee: Option<BuilderFee>,
     ) -> Result<Client<Authenticated<Builder>>> {
         #[cfg(feature = "heartbeats")]
         self.heartbeat_token.cancel_and_wait().await?;
@@ -2151,6 +3377,7 @@ impl Client<Authenticated<Normal>> {
             credentials: inner.state.credentials,
             kind: Builder {
                 config,
+                fee,
                 client: inner.client.clone(),
             },
         };
@@ -2164,9 +3391,12 @@ impl Client<Authenticated<Normal>> {
             tick_sizes: inner.tick_sizes,
             neg_risk: inner.neg_risk,
             fee_rate_bps: inner.fee_rate_bps,
+            min_order_sizes: inner.min_order_sizes,
+            order_domains: inner.order_domains,
             funder: inner.funder,
             signature_type: inner.signature_type,
             salt_generator: inner.salt_generator,
+            server_time_offset: inner.server_time_offset,
         };
 
         #[cfg_attr(
@@ -2190,9 +3420,16 @@ impl Client<Authenticated<Normal>> {
 }
 
 impl Client<Authenticated<Builder>> {
+    /// The fee this builder charges on its orders, if it was promoted with
+    /// [`Client::promote_to_builder_with_fee`].
+    #[must_use]
+    pub fn builder_fee(&self) -> Option<BuilderFee> {
+        self.state().kind.fee()
+    }
+
     pub async fn builder_api_keys(&self) -> Result<Vec<BuilderApiKeyResponse>> {
         let request = self
-            .client()
+            .http_client()
             .request(Method::GET, format!("{}auth/builder-api-key", self.host()))
             .build()?;
         let headers = self.create_headers(&request).await?;
@@ -2200,9 +3437,28 @@ impl Client<Authenticated<Builder>> {
         crate::request(&self.inner.client, request, Some(headers)).await
     }
 
+    /// Returns the standard API keys followed by the builder API keys of this client's address,
+    /// with `is_builder` set for the latter. See [`Self::api_key_infos`] for which metadata is
+    /// available.
+    pub async fn all_api_key_infos(&self) -> Result<Vec<ApiKeyInfo>> {
+        let (standard, builder) =
+            futures::try_join!(self.api_key_infos(), self.builder_api_keys())?;
+
+        Ok(standard
+            .into_iter()
+            .chain(builder.into_iter().map(ApiKeyInfo::from))
+            .collect())
+    }
+
+    /// Revokes the builder API key used by this client. Builder requests fail after this until the
+    /// client is promoted again with a new builder key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the builder API key cannot be revoked.
     pub async fn revoke_builder_api_key(&self) -> Result<()> {
-        let mut request = self
-            .client()
+        let request = self
+            .http_client()
             .request(
                 Method::DELETE,
                 format!("{}auth/builder-api-key", self.host()),
@@ -2210,11 +3466,7 @@ impl Client<Authenticated<Builder>> {
             .build()?;
         let headers = self.create_headers(&request).await?;
 
-        *request.headers_mut() = headers;
-
-        // We have to send the request separately from `self.request` because this endpoint does
-        // not return anything in the response body. Otherwise, we would get an EOF error from reqwest
-        self.client().execute(request).await?;
+        let _: IgnoredAny = crate::request(&self.inner.client, request, Some(headers)).await?;
 
         Ok(())
     }
@@ -2227,7 +3479,7 @@ impl Client<Authenticated<Builder>> {
         let params = request.query_params(next_cursor.as_deref());
 
         let request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}builder/trades{params}", self.host()),
@@ -2237,6 +3489,59 @@ impl Client<Authenticated<Builder>> {
 
         crate::request(&self.inner.client, request, Some(headers)).await
     }
+
+    /// Fetches the open positions of the users trading through this builder, filtered like the
+    /// Data API's [`positions`](crate::data::Client::positions).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API returns an error response.
+    #[cfg(feature = "data")]
+    pub async fn builder_positions(&self, request: &PositionsRequest) -> Result<Vec<Position>> {
+        let params = request.query_params(None);
+
+        let request = self
+            .http_client()
+            .request(
+                Method::GET,
+                format!("{}builder/positions{params}", self.host()),
+            )
+            .build()?;
+        let headers = self.create_headers(&request).await?;
+
+        crate::request(&self.inner.client, request, Some(headers)).await
+    }
+
+    /// Fetches the on-chain activity of the users trading through this builder, filtered like the
+    /// Data API's [`activity`](crate::data::Client::activity).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request's `start` is after its `end`, the request fails or the API
+    /// returns an error response.
+    #[cfg(feature = "data")]
+    pub async fn builder_activity(&self, request: &ActivityRequest) -> Result<Vec<Activity>> {
+        if let (Some(start), Some(end)) = (request.start, request.end)
+            && start > end
+        {
+            return Err(Error::validation(format!(
+                "Unable to fetch builder activity: start {start} is after end {end}"
+            )));
+        }
+
+        let params = request.query_params(None);
+
+        let request = self
+            .http_client()
+            .request(
+                Method::GET,
+                format!("{}builder/activity{params}", self.host()),
+            )
+            .build()?;
+        let headers = self.create_headers(&request).await?;
+
+        crate::request(&self.inner.client, request, Some(headers)).await
+    }
 }
 
 #[cfg(feature = "rfq")]
@@ -2253,7 +3558,7 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &CreateRfqRequestRequest,
     ) -> Result<CreateRfqRequestResponse> {
         let http_request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}rfq/request", self.host()))
             .json(request)
             .build()?;
@@ -2271,7 +3576,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the HTTP request fails or the request cannot be canceled.
     pub async fn cancel_request(&self, request: &CancelRfqRequestRequest) -> Result<()> {
         let http_request = self
-            .client()
+            .http_client()
             .request(Method::DELETE, format!("{}rfq/request", self.host()))
             .json(request)
             .build()?;
@@ -2295,7 +3600,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     ) -> Result<Page<RfqRequest>> {
         let params = request.query_params(next_cursor);
         let http_request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}rfq/data/requests{params}", self.host()),
@@ -2316,7 +3621,7 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &CreateRfqQuoteRequest,
     ) -> Result<CreateRfqQuoteResponse> {
         let http_request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}rfq/quote", self.host()))
             .json(request)
             .build()?;
@@ -2332,7 +3637,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Returns an error if the HTTP request fails or the quote cannot be canceled.
     pub async fn cancel_quote(&self, request: &CancelRfqQuoteRequest) -> Result<()> {
         let http_request = self
-            .client()
+            .http_client()
             .request(Method::DELETE, format!("{}rfq/quote", self.host()))
             .json(request)
             .build()?;
@@ -2356,7 +3661,7 @@ impl<K: Kind> Client<Authenticated<K>> {
     ) -> Result<Page<RfqQuote>> {
         let params = request.query_params(next_cursor);
         let http_request = self
-            .client()
+            .http_client()
             .request(
                 Method::GET,
                 format!("{}rfq/data/quotes{params}", self.host()),
@@ -2380,7 +3685,7 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &AcceptRfqQuoteRequest,
     ) -> Result<AcceptRfqQuoteResponse> {
         let http_request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}rfq/request/accept", self.host()))
             .json(request)
             .build()?;
@@ -2402,7 +3707,7 @@ impl<K: Kind> Client<Authenticated<K>> {
         request: &ApproveRfqOrderRequest,
     ) -> Result<ApproveRfqOrderResponse> {
         let http_request = self
-            .client()
+            .http_client()
             .request(Method::POST, format!("{}rfq/quote/approve", self.host()))
             .json(request)
             .build()?;
@@ -2419,22 +3724,155 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// to deserialize plain text.
     async fn rfq_request_text(&self, mut request: Request, headers: HeaderMap) -> Result<()> {
         let method = request.method().clone();
-        let path = request.url().path().to_owned();
+        let path = crate::request_path(request.url());
 
         *request.headers_mut() = headers;
 
-        let response = self.inner.client.execute(request).await?;
+        let response = self
+            .inner
+            .client
+            .execute(request)
+            .await
+            .map_err(|e| Error::from(e).with_request(&method, &path))?;
         let status = response.status();
 
         if !status.is_success() {
+            let headers = response.headers().clone();
             let message = response.text().await.unwrap_or_default();
-            return Err(crate::error::Error::status(status, method, path, message));
+            return Err(crate::error::Error::status_with_headers(
+                status, method, path, message, &headers,
+            ));
         }
 
         Ok(())
     }
 }
 
+/// Builds the HTTP client, leaving every connection setting that `config` does not set at
+/// reqwest's default.
+fn http_client(config: &Config) -> Result<ReqwestClient> {
+    let mut headers = HeaderMap::new();
+
+    headers.insert(
+        "User-Agent",
+        HeaderValue::from_str(config.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT))?,
+    );
+    headers.insert("Accept", HeaderValue::from_static("*/*"));
+    headers.insert(
+        "Connection",
+        HeaderValue::from_static(if config.keep_alive {
+            "keep-alive"
+        } else {
+            "close"
+        }),
+    );
+    headers.insert("Content-Type", HeaderValue::from_static("application/json"));
+
+    let mut builder = ReqwestClient::builder().default_headers(headers);
+
+    if let Some(timeout) = config.pool_idle_timeout {
+        builder = builder.pool_idle_timeout(timeout);
+    }
+    if !config.keep_alive {
+        builder = builder.pool_max_idle_per_host(0);
+    } else if let Some(max) = config.pool_max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max);
+    }
+    if let Some(interval) = config.tcp_keepalive {
+        builder = builder.tcp_keepalive(interval);
+    }
+    builder = match config.http_version {
+        HttpVersion::Negotiate => builder,
+        HttpVersion::Http1Only => builder.http1_only(),
+        HttpVersion::Http2Only => builder.http2_prior_knowledge(),
+    };
+
+    Ok(builder.build()?)
+}
+
+/// Runs `lookup` once for each distinct token of `token_ids`, with at most
+/// [`TOKEN_LOOKUP_CONCURRENCY`] lookups in flight, and collects the results by token ID.
+///
+/// Tokens the server answers with `404 Not Found` for are left out of the map.
+async fn lookup_tokens<T, F, Fut>(token_ids: &[U256], lookup: F) -> Result<HashMap<U256, T>>
+where
+    F: FnMut(U256) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut unique = token_ids.to_vec();
+    unique.sort_unstable();
+    unique.dedup();
+
+    let results = fan_out_collect(unique.iter().copied(), TOKEN_LOOKUP_CONCURRENCY, lookup).await;
+
+    let mut values = HashMap::with_capacity(unique.len());
+    for (token_id, result) in unique.into_iter().zip(results) {
+        match result {
+            Ok(value) => {
+                values.insert(token_id, value);
+            }
+            Err(e)
+                if e.downcast_ref::<Status>()
+                    .is_some_and(|status| status.status_code == StatusCode::NOT_FOUND) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(values)
+}
+
+/// Turns the CLOB's reply to a request signed with an unknown API key, a `404` or a `401` with an
+/// invalid key message, into an [`ApiKeyNotFound`] error.
+fn api_key_not_found(error: &Error) -> Option<ApiKeyNotFound> {
+    let status = error.downcast_ref::<Status>()?;
+    let unknown = status.status_code == StatusCode::NOT_FOUND
+        || (status.status_code == StatusCode::UNAUTHORIZED
+            && status.message.to_lowercase().contains("invalid api key"));
+
+    unknown.then(|| ApiKeyNotFound {
+        status: Status {
+            status_code: status.status_code,
+            method: status.method.clone(),
+            path: status.path.clone(),
+            message: status.message.clone(),
+            rate_limit: status.rate_limit,
+        },
+    })
+}
+
+/// Whether the server rejected a request because it has no orderbook for a requested token.
+fn is_missing_orderbook(error: &Error) -> bool {
+    error.downcast_ref::<Status>().is_some_and(|status| {
+        status.status_code == StatusCode::NOT_FOUND
+            || status.message.to_lowercase().contains("no orderbook")
+    })
+}
+
+/// Whether a failed request may not have reached the server, making it worth retrying.
+fn is_retryable(error: &Error) -> bool {
+    error
+        .downcast_ref::<reqwest::Error>()
+        .is_some_and(|e| e.is_connect() || e.is_timeout())
+}
+
+/// The delay before retry number `attempt` (counting from zero): [`POST_RETRY_BASE_DELAY`]
+/// doubled `attempt` times and capped at [`POST_RETRY_MAX_DELAY`], of which a random part up to
+/// half is taken off as jitter.
+fn retry_delay(attempt: u32) -> Duration {
+    let delay = POST_RETRY_BASE_DELAY
+        .saturating_mul(2_u32.saturating_pow(attempt))
+        .min(POST_RETRY_MAX_DELAY);
+    let jitter = rand::rng().random_range(Duration::ZERO..=delay / 2);
+
+    delay.saturating_sub(jitter)
+}
+
+/// Converts a balance reported in base units (collateral and outcome tokens both use six
+/// decimals) into whole units.
+fn from_base_units(balance: Decimal) -> Decimal {
+    balance * Decimal::new(1, USDC_DECIMALS)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2443,4 +3881,14 @@ mod tests {
     fn client_default_should_succeed() {
         _ = Client::default();
     }
+
+    #[test]
+    fn retry_delay_should_grow_with_jitter_and_cap() {
+        for (attempt, full) in [(0, 100), (1, 200), (3, 800), (10, 2_000), (u32::MAX, 2_000)] {
+            let full = Duration::from_millis(full);
+            let delay = retry_delay(attempt);
+
+            assert!(delay >= full / 2 && delay <= full, "{attempt}: {delay:?}");
+        }
+    }
 }
@@ -0,0 +1,97 @@
+//! Conversions between USDC notionals and outcome token share sizes.
+//!
+//! Orders are sized in shares, which trade in steps of 0.01. Converting a notional to shares
+//! rounds down to that step, so the resulting order never costs more than the notional. The
+//! market order builder applies the same conversion when it is given an [`Amount`] in the other
+//! denomination, using the price it derives from the book.
+//!
+//! [`Amount`]: crate::clob::types::Amount
+
+use crate::Result;
+use crate::clob::order_builder::LOT_SIZE_SCALE;
+use crate::error::Error;
+use crate::types::Decimal;
+
+/// Returns how many shares `notional` USDC buys at `price`, rounded down to the share size step.
+///
+/// # Errors
+///
+/// Returns an error if `price` is not strictly between zero and one, or `notional` is negative.
+pub fn shares_for_notional(notional: Decimal, price: Decimal) -> Result<Decimal> {
+    validate("notional", notional, price)?;
+
+    Ok((notional / price)
+        .trunc_with_scale(LOT_SIZE_SCALE)
+        .normalize())
+}
+
+/// Returns the USDC notional of `shares` at `price`.
+///
+/// # Errors
+///
+/// Returns an error if `price` is not strictly between zero and one, or `shares` is negative.
+pub fn notional_for_shares(shares: Decimal, price: Decimal) -> Result<Decimal> {
+    validate("shares", shares, price)?;
+
+    Ok((shares * price).normalize())
+}
+
+fn validate(name: &str, value: Decimal, price: Decimal) -> Result<()> {
+    if price <= Decimal::ZERO || price >= Decimal::ONE {
+        return Err(Error::validation(format!(
+            "Unable to convert {name}: price {price} must be between 0 and 1"
+        )));
+    }
+
+    if value.is_sign_negative() {
+        return Err(Error::validation(format!(
+            "Unable to convert {name}: {value} is negative"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn shares_for_notional_should_round_down_to_size_step() {
+        assert_eq!(
+            shares_for_notional(dec!(100), dec!(0.5)).unwrap(),
+            dec!(200)
+        );
+        // 100 / 0.34 = 294.1176..., which would cost more than 100 if rounded up
+        assert_eq!(
+            shares_for_notional(dec!(100), dec!(0.34)).unwrap(),
+            dec!(294.11)
+        );
+        assert_eq!(
+            shares_for_notional(Decimal::ZERO, dec!(0.34)).unwrap(),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn notional_for_shares_should_be_exact() {
+        assert_eq!(
+            notional_for_shares(dec!(294.11), dec!(0.34)).unwrap(),
+            dec!(99.9974)
+        );
+        assert_eq!(
+            notional_for_shares(dec!(200), dec!(0.5)).unwrap(),
+            dec!(100)
+        );
+    }
+
+    #[test]
+    fn invalid_inputs_should_fail() {
+        shares_for_notional(dec!(10), Decimal::ZERO).unwrap_err();
+        shares_for_notional(dec!(10), Decimal::ONE).unwrap_err();
+        shares_for_notional(dec!(-10), dec!(0.5)).unwrap_err();
+        notional_for_shares(dec!(-1), dec!(0.5)).unwrap_err();
+    }
+}
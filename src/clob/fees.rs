@@ -0,0 +1,130 @@
+//! Trading fee calculations.
+//!
+//! Polymarket charges taker fees in the asset the taker receives, scaled by how far the price is
+//! from the edges of the book:
+//!
+//! - A **buy** pays `rate * min(price, 1 - price) * size / price` outcome tokens.
+//! - A **sell** pays `rate * min(price, 1 - price) * size` USDC.
+//!
+//! where `rate` is the market's fee rate in basis points divided by 10,000 and `size` is the
+//! number of outcome tokens traded. The fee is therefore symmetric for the two sides of a trade
+//! in USDC terms, and shrinks towards the edges of the book. Use
+//! [`Client::net_proceeds`](crate::clob::Client::net_proceeds) to apply the fee rate the CLOB
+//! reports for a token, or [`Client::fee_rates`](crate::clob::Client::fee_rates) to get a
+//! market's maker and taker rates.
+
+use crate::Result;
+use crate::clob::types::Side;
+use crate::error::Error;
+use crate::types::Decimal;
+
+const BPS_DIVISOR: u32 = 10_000;
+
+/// Returns the fee for trading `size` outcome tokens at `price`, in the asset the trade
+/// receives: outcome tokens for a [`Side::Buy`] and USDC for a [`Side::Sell`].
+///
+/// # Errors
+///
+/// Returns an error if `price` is not strictly between zero and one, `size` is negative, or
+/// `side` is [`Side::Unknown`].
+pub fn fee(side: Side, price: Decimal, size: Decimal, fee_rate_bps: u32) -> Result<Decimal> {
+    if price <= Decimal::ZERO || price >= Decimal::ONE {
+        return Err(Error::validation(format!(
+            "Unable to calculate fee: price {price} must be between 0 and 1"
+        )));
+    }
+
+    if size.is_sign_negative() {
+        return Err(Error::validation(format!(
+            "Unable to calculate fee: size {size} is negative"
+        )));
+    }
+
+    let rate = Decimal::from(fee_rate_bps) / Decimal::from(BPS_DIVISOR);
+    let scaled = rate * price.min(Decimal::ONE - price) * size;
+
+    match side {
+        Side::Buy => Ok(scaled / price),
+        Side::Sell => Ok(scaled),
+        side => Err(Error::validation(format!(
+            "Unable to calculate fee: invalid side {side}"
+        ))),
+    }
+}
+
+/// Returns what trading `size` outcome tokens at `price` yields after fees: the outcome tokens
+/// received for a [`Side::Buy`], which costs `price * size` USDC, and the USDC received for a
+/// [`Side::Sell`].
+///
+/// # Errors
+///
+/// See [`fee`].
+pub fn net_proceeds(
+    side: Side,
+    price: Decimal,
+    size: Decimal,
+    fee_rate_bps: u32,
+) -> Result<Decimal> {
+    let fee = fee(side, price, size, fee_rate_bps)?;
+
+    match side {
+        Side::Buy => Ok(size - fee),
+        _ => Ok(price * size - fee),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn buy_fee_should_be_charged_in_tokens() {
+        // 100 bps on 100 tokens at 0.40: 0.01 * 0.40 * 100 / 0.40 = 1 token
+        assert_eq!(fee(Side::Buy, dec!(0.40), dec!(100), 100).unwrap(), dec!(1));
+        assert_eq!(
+            net_proceeds(Side::Buy, dec!(0.40), dec!(100), 100).unwrap(),
+            dec!(99)
+        );
+
+        // Above 0.50 the fee is scaled by 1 - price: 0.01 * 0.20 * 100 / 0.80 = 0.25 tokens
+        assert_eq!(
+            net_proceeds(Side::Buy, dec!(0.80), dec!(100), 100).unwrap(),
+            dec!(99.75)
+        );
+    }
+
+    #[test]
+    fn sell_fee_should_be_charged_in_usdc() {
+        // 100 bps on 100 tokens at 0.60: 0.01 * 0.40 * 100 = 0.40 USDC out of 60 USDC
+        assert_eq!(
+            fee(Side::Sell, dec!(0.60), dec!(100), 100).unwrap(),
+            dec!(0.4)
+        );
+        assert_eq!(
+            net_proceeds(Side::Sell, dec!(0.60), dec!(100), 100).unwrap(),
+            dec!(59.6)
+        );
+    }
+
+    #[test]
+    fn zero_fee_rate_should_leave_proceeds_unchanged() {
+        assert_eq!(
+            net_proceeds(Side::Buy, dec!(0.25), dec!(10), 0).unwrap(),
+            dec!(10)
+        );
+        assert_eq!(
+            net_proceeds(Side::Sell, dec!(0.25), dec!(10), 0).unwrap(),
+            dec!(2.5)
+        );
+    }
+
+    #[test]
+    fn invalid_inputs_should_fail() {
+        fee(Side::Buy, Decimal::ZERO, dec!(10), 100).unwrap_err();
+        fee(Side::Sell, Decimal::ONE, dec!(10), 100).unwrap_err();
+        fee(Side::Sell, dec!(0.5), dec!(-1), 100).unwrap_err();
+        fee(Side::Unknown, dec!(0.5), dec!(10), 100).unwrap_err();
+    }
+}
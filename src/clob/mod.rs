@@ -143,10 +143,18 @@
 //!
 //! The default API endpoint is `https://clob.polymarket.com`.
 
+pub mod book;
 pub mod client;
+pub mod convert;
+pub mod fees;
 pub mod order_builder;
+pub mod signing;
 pub mod types;
 #[cfg(feature = "ws")]
 pub mod ws;
 
-pub use client::{Client, Config};
+pub use book::{FillSimulation, OrderBook};
+pub use client::{
+    ApiKeyStrategy, Client, Config, DEFAULT_CANCEL_BATCH_SIZE, DEFAULT_SERVER_TIME_CACHE,
+    HttpVersion, ServerTimePolicy, TimeSource,
+};
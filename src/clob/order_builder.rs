@@ -4,17 +4,18 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use alloy::primitives::U256;
 use chrono::{DateTime, Utc};
 use rand::Rng as _;
+use rust_decimal::RoundingStrategy;
 use rust_decimal::prelude::ToPrimitive as _;
 
 use crate::Result;
 use crate::auth::Kind as AuthKind;
 use crate::auth::state::Authenticated;
-use crate::clob::Client;
 use crate::clob::types::request::OrderBookSummaryRequest;
 use crate::clob::types::{
     Amount, AmountInner, Order, OrderType, Side, SignableOrder, SignatureType,
 };
-use crate::error::Error;
+use crate::clob::{Client, convert};
+use crate::error::{Error, InsufficientLiquidity};
 use crate::types::{Address, Decimal};
 
 pub(crate) const USDC_DECIMALS: u32 = 6;
@@ -49,6 +50,8 @@ pub struct OrderBuilder<OrderKind, K: AuthKind> {
     pub(crate) taker: Option<Address>,
     pub(crate) order_type: Option<OrderType>,
     pub(crate) post_only: Option<bool>,
+    pub(crate) worst_price: Option<Decimal>,
+    pub(crate) max_slippage: Option<Decimal>,
     pub(crate) funder: Option<Address>,
     pub(crate) _kind: PhantomData<OrderKind>,
 }
@@ -190,6 +193,14 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             )));
         }
 
+        if let Some(min_order_size) = self.client.cached_min_order_size(token_id)
+            && size < min_order_size
+        {
+            return Err(Error::validation(format!(
+                "Unable to build Order: Size {size} is below the minimum order size {min_order_size}"
+            )));
+        }
+
         let nonce = self.nonce.unwrap_or(0);
         let expiration = self.expiration.unwrap_or(DateTime::<Utc>::UNIX_EPOCH);
         let taker = self.taker.unwrap_or(Address::ZERO);
@@ -268,18 +279,78 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
     }
 
     /// Sets the [`Amount`] for this market order. This is a required field.
+    ///
+    /// Either denomination works for both sides: a buy in USDC spends that amount and a sell in
+    /// USDC sells enough shares to receive it. The shares of a sell are found by walking the bids
+    /// from the best price down, or, if [`Self::price`] is set, converted at that price as
+    /// described in [`convert`].
     #[must_use]
     pub fn amount(mut self, amount: Amount) -> Self {
         self.amount = Some(amount);
         self
     }
 
+    /// Sets the worst price this market order may fill at: the highest price for a buy, the
+    /// lowest for a sell. This is an optional field, and cannot be combined with
+    /// [`Self::price`] or [`Self::max_slippage`].
+    ///
+    /// The order is priced at `worst_price` instead of the depth-derived market price, making it
+    /// an aggressive limit order. [`Self::build`] fails with an [`InsufficientLiquidity`] error if
+    /// the book cannot fill the full amount within that price.
+    #[must_use]
+    pub fn worst_price(mut self, worst_price: Decimal) -> Self {
+        self.worst_price = Some(worst_price);
+        self
+    }
+
+    /// Sets the maximum slippage from the best price on the book, as a fraction (`0.02` is 2%).
+    /// This is an optional field, and cannot be combined with [`Self::price`] or
+    /// [`Self::worst_price`].
+    ///
+    /// The worst price is derived from the best opposing level when the order is built, then
+    /// applied as with [`Self::worst_price`].
+    #[must_use]
+    pub fn max_slippage(mut self, max_slippage: Decimal) -> Self {
+        self.max_slippage = Some(max_slippage);
+        self
+    }
+
+    /// Resolves the worst acceptable price from either [`Self::worst_price`] or
+    /// [`Self::max_slippage`] relative to `best`, if either is set. A slippage bound is clamped to
+    /// the valid price range `[tick_size, 1 - tick_size]`.
+    fn price_bound(
+        &self,
+        side: Side,
+        best: Decimal,
+        tick_size: Decimal,
+    ) -> Result<Option<Decimal>> {
+        match (self.worst_price, self.max_slippage) {
+            (Some(_), Some(_)) => Err(Error::validation(
+                "Cannot set both a worst price and a maximum slippage for a market order",
+            )),
+            (Some(worst_price), None) => Ok(Some(worst_price)),
+            (None, Some(slippage)) if slippage.is_sign_negative() => Err(Error::validation(
+                format!("Maximum slippage {slippage} must not be negative"),
+            )),
+            (None, Some(slippage)) => {
+                let bound = match side {
+                    Side::Buy => best * (Decimal::ONE + slippage),
+                    _ => best * (Decimal::ONE - slippage),
+                };
+                Ok(Some(bound.clamp(tick_size, Decimal::ONE - tick_size)))
+            }
+            (None, None) => Ok(None),
+        }
+    }
+
     // Attempts to calculate the market price from the top of the book for the particular token.
     // - Uses an orderbook depth search to find the cutoff price:
     //   - BUY + USDC: walk asks until notional >= USDC
     //   - BUY + Shares: walk asks until shares >= N
     //   - SELL + Shares: walk bids until shares >= N
-    async fn calculate_price(&self, order_type: OrderType) -> Result<Decimal> {
+    //   - SELL + USDC: walk bids until notional >= USDC
+    // Also returns, for a sell in USDC, the shares the walked bids take to pay out the amount.
+    async fn calculate_price(&self, order_type: OrderType) -> Result<(Decimal, Option<Decimal>)> {
         let token_id = self
             .token_id
             .expect("Token ID was already validated in `build`");
@@ -305,38 +376,86 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
 
         let (levels, amount) = match side {
             Side::Buy => (book.asks, amount.0),
-            Side::Sell => match amount.0 {
-                a @ AmountInner::Shares(_) => (book.bids, a),
-                AmountInner::Usdc(_) => {
-                    return Err(Error::validation(
-                        "Sell Orders must specify their `amount`s in shares",
-                    ));
-                }
-            },
+            Side::Sell => (book.bids, amount.0),
 
             side => return Err(Error::validation(format!("Invalid side: {side}"))),
         };
 
-        let first = levels.first().ok_or(Error::validation(format!(
-            "No opposing orders for {token_id} which means there is no market price"
-        )))?;
+        let (Some(first), Some(best)) = (levels.first(), levels.last()) else {
+            return Err(Error::validation(format!(
+                "No opposing orders for {token_id} which means there is no market price"
+            )));
+        };
+
+        // Levels are ordered worst price first, so walk them from the back and stop at the first
+        // level beyond the worst acceptable price, if there is one
+        let tick_size = self
+            .client
+            .tick_size(token_id)
+            .await?
+            .minimum_tick_size
+            .as_decimal();
+        let price_bound = self.price_bound(side, best.price, tick_size)?;
+        let within_bound = |price: Decimal| match price_bound {
+            None => true,
+            Some(bound) if side == Side::Buy => price <= bound,
+            Some(bound) => price >= bound,
+        };
 
         let mut sum = Decimal::ZERO;
-        let cutoff_price = levels.iter().rev().find_map(|level| {
-            match amount {
-                AmountInner::Usdc(_) => sum += level.size * level.price,
-                AmountInner::Shares(_) => sum += level.size,
+        let mut shares = Decimal::ZERO;
+        let cutoff_price = levels
+            .iter()
+            .rev()
+            .take_while(|level| within_bound(level.price))
+            .find_map(|level| {
+                match amount {
+                    AmountInner::Usdc(_) => {
+                        let notional = level.size * level.price;
+                        let remaining = amount.as_inner() - sum;
+                        shares += if notional >= remaining {
+                            remaining / level.price
+                        } else {
+                            level.size
+                        };
+                        sum += notional;
+                    }
+                    AmountInner::Shares(_) => sum += level.size,
+                }
+                (sum >= amount.as_inner()).then_some(level.price)
+            });
+
+        // Any amount the walked bids cannot pay out is converted at `price`, the worst level
+        let sell_shares = |price: Decimal| {
+            if side != Side::Sell || !matches!(amount, AmountInner::Usdc(_)) {
+                return None;
+            }
+            let unfilled = amount.as_inner() - sum;
+            let shares = if unfilled > Decimal::ZERO {
+                shares + unfilled / price
+            } else {
+                shares
+            };
+            Some(shares.trunc_with_scale(LOT_SIZE_SCALE))
+        };
+
+        match (cutoff_price, price_bound) {
+            (Some(price), Some(bound)) => Ok((bound, sell_shares(price))),
+            (Some(price), None) => Ok((price, sell_shares(price))),
+            (None, Some(bound)) => Err(InsufficientLiquidity {
+                token_id,
+                worst_price: bound,
+                requested: amount.as_inner(),
+                fillable: sum,
+            }
+            .into()),
+            (None, None) if matches!(order_type, OrderType::FOK) => {
+                Err(Error::validation(format!(
+                    "Insufficient liquidity to fill order for {token_id} at {}",
+                    amount.as_inner()
+                )))
             }
-            (sum >= amount.as_inner()).then_some(level.price)
-        });
-
-        match cutoff_price {
-            Some(price) => Ok(price),
-            None if matches!(order_type, OrderType::FOK) => Err(Error::validation(format!(
-                "Insufficient liquidity to fill order for {token_id} at {}",
-                amount.as_inner()
-            ))),
-            None => Ok(first.price),
+            (None, None) => Ok((first.price, sell_shares(first.price))),
         }
     }
 
@@ -372,8 +491,14 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
                 "postOnly is only supported for limit orders",
             ));
         }
-        let price = match self.price {
-            Some(price) => price,
+        let bounded = self.worst_price.is_some() || self.max_slippage.is_some();
+        let (price, sell_shares) = match self.price {
+            Some(_) if bounded => {
+                return Err(Error::validation(
+                    "Cannot set a price together with a worst price or maximum slippage",
+                ));
+            }
+            Some(price) => (price, None),
             None => self.calculate_price(order_type.clone()).await?,
         };
 
@@ -387,8 +512,13 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
 
         let decimals = minimum_tick_size.scale();
 
-        // Ensure that the market price returned internally is truncated to our tick size
-        let price = price.trunc_with_scale(decimals);
+        // Ensure that the market price returned internally is truncated to our tick size. A sell
+        // bound is a floor, so it is rounded up instead to never accept a worse price.
+        let price = if bounded && side == Side::Sell {
+            price.round_dp_with_strategy(decimals, RoundingStrategy::ToPositiveInfinity)
+        } else {
+            price.trunc_with_scale(decimals)
+        };
         if price < minimum_tick_size || price > Decimal::ONE - minimum_tick_size {
             return Err(Error::validation(format!(
                 "Price {price} is too small or too large for the minimum tick size {minimum_tick_size}"
@@ -432,10 +562,20 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
                 (usdc, raw_amount)
             }
 
+            // Sell the shares the bids take to pay out the USDC amount, for at least the cutoff
+            // `price` each
             (Side::Sell, AmountInner::Usdc(_)) => {
-                return Err(Error::validation(
-                    "Sell Orders must specify their `amount`s in shares",
-                ));
+                let shares = match sell_shares {
+                    Some(shares) => shares,
+                    None => convert::shares_for_notional(raw_amount, price)?,
+                };
+                if shares.is_zero() {
+                    return Err(Error::validation(format!(
+                        "Unable to build Order: {raw_amount} USDC is less than one share step at price {price}"
+                    )));
+                }
+                let usdc = (shares * price).trunc_with_scale(decimals + LOT_SIZE_SCALE);
+                (usdc, shares)
             }
 
             (side, _) => return Err(Error::validation(format!("Invalid side: {side}"))),
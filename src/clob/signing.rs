@@ -0,0 +1,223 @@
+//! EIP-712 order signing.
+//!
+//! Every order is signed over a hash that combines the order itself with the domain separator of
+//! the exchange contract it settles on. The separator only depends on the chain and on whether the
+//! market is neg-risk, so [`OrderDomain`] computes it once per exchange and reuses it for every
+//! order. [`CachedDomainSigner`] pairs an [`OrderDomain`] with a [`Signer`], which is convenient
+//! when signing many orders for several makers with
+//! [`Client::sign_with`](crate::clob::Client::sign_with).
+
+use std::borrow::Cow;
+
+use alloy::dyn_abi::Eip712Domain;
+use alloy::primitives::{Signature, U256, keccak256};
+use alloy::signers::Signer;
+use alloy::sol_types::SolStruct as _;
+use async_trait::async_trait;
+
+use crate::clob::types::Order;
+use crate::error::Error;
+use crate::types::{Address, B256, ChainId};
+use crate::{Result, contract_config};
+
+const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
+const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
+
+/// Returns the EIP-712 domain orders are signed against. Neg-risk markets settle on a separate
+/// exchange contract, so the verifying contract depends on `neg_risk`.
+///
+/// # Errors
+///
+/// Returns an error if there is no exchange contract configured for `chain_id` and `neg_risk`.
+pub fn order_domain(chain_id: ChainId, neg_risk: bool) -> Result<Eip712Domain> {
+    let exchange_contract = contract_config(chain_id, neg_risk)
+        .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
+        .exchange;
+
+    Ok(Eip712Domain {
+        name: ORDER_NAME,
+        version: VERSION,
+        chain_id: Some(U256::from(chain_id)),
+        verifying_contract: Some(exchange_contract),
+        ..Eip712Domain::default()
+    })
+}
+
+/// The precomputed domain separators of both exchange contracts on a chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderDomain {
+    chain_id: ChainId,
+    /// Separator of the standard exchange, if one is configured for the chain
+    exchange: Option<B256>,
+    /// Separator of the neg-risk exchange, if one is configured for the chain
+    neg_risk_exchange: Option<B256>,
+}
+
+impl OrderDomain {
+    /// Computes the domain separators for `chain_id`.
+    #[must_use]
+    pub fn new(chain_id: ChainId) -> Self {
+        let separator = |neg_risk| {
+            order_domain(chain_id, neg_risk)
+                .ok()
+                .map(|domain| domain.separator())
+        };
+
+        Self {
+            chain_id,
+            exchange: separator(false),
+            neg_risk_exchange: separator(true),
+        }
+    }
+
+    #[must_use]
+    pub const fn chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
+    /// The domain separator of the exchange matching `neg_risk`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such exchange configured for this chain.
+    pub fn separator(&self, neg_risk: bool) -> Result<B256> {
+        let separator = if neg_risk {
+            self.neg_risk_exchange
+        } else {
+            self.exchange
+        };
+
+        separator.ok_or(Error::missing_contract_config(self.chain_id, neg_risk))
+    }
+
+    /// The hash to sign for `order`, equal to `order.eip712_signing_hash(&order_domain(..))`
+    /// without recomputing the domain separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no exchange matching `neg_risk` configured for this chain.
+    pub fn signing_hash(&self, order: &Order, neg_risk: bool) -> Result<B256> {
+        let separator = self.separator(neg_risk)?;
+
+        let mut digest = [0_u8; 66];
+        digest[..2].copy_from_slice(&[0x19, 0x01]);
+        digest[2..34].copy_from_slice(separator.as_slice());
+        digest[34..].copy_from_slice(order.eip712_hash_struct().as_slice());
+
+        Ok(keccak256(digest))
+    }
+}
+
+/// Signs orders for a single maker.
+#[async_trait]
+pub trait OrderSigner: Send + Sync {
+    /// The address that signs the orders.
+    fn address(&self) -> Address;
+
+    /// Signs `order` for the exchange matching `neg_risk`.
+    async fn sign_order(&self, order: &Order, neg_risk: bool) -> Result<Signature>;
+}
+
+/// An [`OrderSigner`] that reuses the domain separators of its signer's chain across orders.
+#[derive(Clone, Debug)]
+pub struct CachedDomainSigner<S> {
+    signer: S,
+    domain: OrderDomain,
+}
+
+impl<S: Signer> CachedDomainSigner<S> {
+    /// Wraps `signer`, computing the domain separators for its chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `signer` has no chain id.
+    pub fn new(signer: S) -> Result<Self> {
+        let chain_id = signer.chain_id().ok_or(Error::validation(
+            "Chain id not set, be sure to provide one on the signer",
+        ))?;
+
+        Ok(Self {
+            signer,
+            domain: OrderDomain::new(chain_id),
+        })
+    }
+
+    #[must_use]
+    pub const fn signer(&self) -> &S {
+        &self.signer
+    }
+
+    #[must_use]
+    pub const fn domain(&self) -> &OrderDomain {
+        &self.domain
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.signer
+    }
+}
+
+#[async_trait]
+impl<S: Signer + Send + Sync> OrderSigner for CachedDomainSigner<S> {
+    fn address(&self) -> Address {
+        self.signer.address()
+    }
+
+    async fn sign_order(&self, order: &Order, neg_risk: bool) -> Result<Signature> {
+        let hash = self.domain.signing_hash(order, neg_risk)?;
+
+        Ok(self.signer.sign_hash(&hash).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AMOY, POLYGON};
+
+    #[test]
+    fn order_domain_should_use_neg_risk_exchange() {
+        let domain = order_domain(POLYGON, false).unwrap();
+        let neg_risk_domain = order_domain(POLYGON, true).unwrap();
+
+        assert_eq!(
+            domain.verifying_contract,
+            Some(contract_config(POLYGON, false).unwrap().exchange)
+        );
+        assert_eq!(
+            neg_risk_domain.verifying_contract,
+            Some(contract_config(POLYGON, true).unwrap().exchange)
+        );
+        assert_ne!(domain.separator(), neg_risk_domain.separator());
+    }
+
+    #[test]
+    fn cached_signing_hash_should_match_eip712_signing_hash() {
+        let order = Order {
+            salt: U256::from(7),
+            tokenId: U256::from(42),
+            makerAmount: U256::from(1_000_000),
+            takerAmount: U256::from(2_000_000),
+            ..Order::default()
+        };
+
+        for chain_id in [POLYGON, AMOY] {
+            let domain = OrderDomain::new(chain_id);
+
+            for neg_risk in [false, true] {
+                assert_eq!(
+                    domain.signing_hash(&order, neg_risk).unwrap(),
+                    order.eip712_signing_hash(&order_domain(chain_id, neg_risk).unwrap())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_chain_should_fail() {
+        let domain = OrderDomain::new(1);
+
+        domain.signing_hash(&Order::default(), false).unwrap_err();
+    }
+}
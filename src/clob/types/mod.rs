@@ -1,4 +1,6 @@
+use std::cmp::Ordering;
 use std::fmt;
+use std::ops::{Add, Sub};
 
 use alloy::core::sol;
 use alloy::primitives::{Signature, U256};
@@ -31,6 +33,8 @@ pub use response::{
     CreateRfqRequestResponse, RfqQuote, RfqRequest,
 };
 
+pub use crate::types::Interval;
+
 #[non_exhaustive]
 #[derive(
     Clone, Debug, Display, Default, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize,
@@ -87,36 +91,6 @@ impl TryFrom<u8> for Side {
     }
 }
 
-/// Time interval for price history queries.
-#[non_exhaustive]
-#[derive(Clone, Copy, Debug, Display, Eq, PartialEq, Serialize, Deserialize)]
-pub enum Interval {
-    /// 1 minute
-    #[serde(rename = "1m")]
-    #[strum(serialize = "1m")]
-    OneMinute,
-    /// 1 hour
-    #[serde(rename = "1h")]
-    #[strum(serialize = "1h")]
-    OneHour,
-    /// 6 hours
-    #[serde(rename = "6h")]
-    #[strum(serialize = "6h")]
-    SixHours,
-    /// 1 day
-    #[serde(rename = "1d")]
-    #[strum(serialize = "1d")]
-    OneDay,
-    /// 1 week
-    #[serde(rename = "1w")]
-    #[strum(serialize = "1w")]
-    OneWeek,
-    /// Maximum available history
-    #[serde(rename = "max")]
-    #[strum(serialize = "max")]
-    Max,
-}
-
 /// Time range specification for price history queries.
 ///
 /// The CLOB API requires either an interval or explicit start/end timestamps.
@@ -160,7 +134,7 @@ impl From<Interval> for TimeRange {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) enum AmountInner {
     Usdc(Decimal),
     Shares(Decimal),
@@ -174,7 +148,13 @@ impl AmountInner {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+/// A USDC or shares value.
+///
+/// Amounts of the same unit can be added, subtracted and compared. Mixing units is a logic error:
+/// [`Add`] and [`Sub`] panic when the units differ, [`PartialOrd`] returns `None`, and two amounts
+/// of different units are never equal. Use [`Amount::checked_add`] and [`Amount::checked_sub`] to
+/// get an error instead of a panic.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Amount(pub(crate) AmountInner);
 
 impl Amount {
@@ -216,6 +196,96 @@ impl Amount {
     pub fn is_shares(&self) -> bool {
         matches!(self.0, AmountInner::Shares(_))
     }
+
+    /// Adds two amounts of the same unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the amounts have different units or the sum overflows.
+    pub fn checked_add(self, rhs: Amount) -> Result<Amount> {
+        self.checked_op(rhs, '+', Decimal::checked_add)
+    }
+
+    /// Subtracts an amount of the same unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the amounts have different units or the difference overflows.
+    pub fn checked_sub(self, rhs: Amount) -> Result<Amount> {
+        self.checked_op(rhs, '-', Decimal::checked_sub)
+    }
+
+    fn checked_op(
+        self,
+        rhs: Amount,
+        op: char,
+        f: fn(Decimal, Decimal) -> Option<Decimal>,
+    ) -> Result<Amount> {
+        let overflow =
+            || Error::validation(format!("Unable to compute {self} {op} {rhs}: overflow"));
+
+        match (self.0, rhs.0) {
+            (AmountInner::Usdc(a), AmountInner::Usdc(b)) => f(a, b)
+                .map(|d| Amount(AmountInner::Usdc(d.normalize())))
+                .ok_or_else(overflow),
+            (AmountInner::Shares(a), AmountInner::Shares(b)) => f(a, b)
+                .map(|d| Amount(AmountInner::Shares(d.normalize())))
+                .ok_or_else(overflow),
+            _ => Err(Error::validation(format!(
+                "Unable to compute {self} {op} {rhs}: amounts have different units"
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            AmountInner::Usdc(d) => write!(f, "{d} USDC"),
+            AmountInner::Shares(d) => write!(f, "{d} shares"),
+        }
+    }
+}
+
+impl Add for Amount {
+    type Output = Amount;
+
+    /// # Panics
+    ///
+    /// Panics if the amounts have different units or the sum overflows, see
+    /// [`Amount::checked_add`] for a non-panicking alternative.
+    fn add(self, rhs: Amount) -> Amount {
+        match self.checked_add(rhs) {
+            Ok(amount) => amount,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl Sub for Amount {
+    type Output = Amount;
+
+    /// # Panics
+    ///
+    /// Panics if the amounts have different units or the difference overflows, see
+    /// [`Amount::checked_sub`] for a non-panicking alternative.
+    fn sub(self, rhs: Amount) -> Amount {
+        match self.checked_sub(rhs) {
+            Ok(amount) => amount,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+impl PartialOrd for Amount {
+    /// Compares amounts of the same unit, returning `None` when the units differ.
+    fn partial_cmp(&self, other: &Amount) -> Option<Ordering> {
+        match (self.0, other.0) {
+            (AmountInner::Usdc(a), AmountInner::Usdc(b))
+            | (AmountInner::Shares(a), AmountInner::Shares(b)) => a.partial_cmp(&b),
+            _ => None,
+        }
+    }
 }
 
 #[non_exhaustive]
@@ -329,6 +399,45 @@ pub enum TraderSide {
     Unknown(String),
 }
 
+/// The kind of event a notification was raised for.
+///
+/// The CLOB encodes this as a number in the notification's `type` field.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum NotificationType {
+    /// One of the user's orders was cancelled.
+    OrderCancellation,
+    /// One of the user's orders was (partially) filled.
+    OrderFill,
+    /// A market the user participated in was resolved.
+    MarketResolved,
+    /// Unknown notification type from the API (captures the raw value for debugging).
+    Unknown(u32),
+}
+
+impl From<u32> for NotificationType {
+    fn from(value: u32) -> Self {
+        match value {
+            1 => NotificationType::OrderCancellation,
+            2 => NotificationType::OrderFill,
+            4 => NotificationType::MarketResolved,
+            other => NotificationType::Unknown(other),
+        }
+    }
+}
+
+impl From<NotificationType> for u32 {
+    fn from(value: NotificationType) -> Self {
+        match value {
+            NotificationType::OrderCancellation => 1,
+            NotificationType::OrderFill => 2,
+            NotificationType::MarketResolved => 4,
+            NotificationType::Unknown(other) => other,
+        }
+    }
+}
+
 /// Represents the maximum number of decimal places for an order's price field
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
@@ -625,6 +734,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn amount_arithmetic_should_keep_unit() -> Result<()> {
+        let a = Amount::usdc(dec!(10.5))?;
+        let b = Amount::usdc(dec!(2.25))?;
+
+        assert_eq!(a + b, Amount::usdc(dec!(12.75))?);
+        assert_eq!(a - b, Amount::usdc(dec!(8.25))?);
+        assert_eq!(a.checked_add(b)?, a + b);
+        assert!((a - b).is_usdc());
+        assert!(a > b);
+        assert!(Amount::shares(dec!(1))? < Amount::shares(dec!(2))?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn amount_unit_mismatch_should_fail() -> Result<()> {
+        let usdc = Amount::usdc(dec!(1))?;
+        let shares = Amount::shares(dec!(1))?;
+
+        let err = usdc.checked_add(shares).unwrap_err();
+        let message = err.downcast_ref::<Validation>().unwrap();
+        assert_eq!(
+            message.reason,
+            "Unable to compute 1 USDC + 1 shares: amounts have different units"
+        );
+        usdc.checked_sub(shares).unwrap_err();
+        assert_ne!(usdc, shares);
+        assert_eq!(usdc.partial_cmp(&shares), None);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "amounts have different units")]
+    fn amount_add_unit_mismatch_should_panic() {
+        let _sum = Amount::usdc(dec!(1)).unwrap() + Amount::shares(dec!(1)).unwrap();
+    }
+
     #[test]
     fn side_to_string_should_succeed() {
         assert_eq!(Side::Buy.to_string(), "BUY");
@@ -705,4 +853,15 @@ mod tests {
 
         assert!(!object.contains_key("postOnly"));
     }
+
+    #[test]
+    fn notification_type_should_round_trip() {
+        let known: NotificationType = serde_json::from_str("2").unwrap();
+        assert_eq!(known, NotificationType::OrderFill);
+        assert_eq!(to_value(known).unwrap(), serde_json::json!(2));
+
+        let unknown: NotificationType = serde_json::from_str("99").unwrap();
+        assert_eq!(unknown, NotificationType::Unknown(99));
+        assert_eq!(to_value(unknown).unwrap(), serde_json::json!(99));
+    }
 }
@@ -97,33 +97,46 @@ pub struct CancelMarketOrderRequest {
     pub asset_id: Option<U256>,
 }
 
+/// Filters for [`Client::trades`](crate::clob::Client::trades). Each set filter is sent as a
+/// query parameter and the filters are combined with AND; unset filters match every trade, so
+/// `TradesRequest::default()` returns the user's full history.
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Default, Clone, Builder, Serialize)]
 #[builder(on(String, into))]
 pub struct TradesRequest {
+    /// Only the trade with this ID.
     pub id: Option<String>,
+    /// Only trades where this address was the taker.
     #[serde(rename = "taker")]
     pub taker_address: Option<Address>,
+    /// Only trades where this address was a maker.
     #[serde(rename = "maker")]
     pub maker_address: Option<Address>,
     /// The market condition ID to filter trades.
     pub market: Option<B256>,
+    /// Only trades of this outcome token.
     #[serde_as(as = "Option<DisplayFromStr>")]
     pub asset_id: Option<U256>,
+    /// Only trades matched before this Unix timestamp, in seconds.
     pub before: Option<i64>,
+    /// Only trades matched after this Unix timestamp, in seconds.
     pub after: Option<i64>,
 }
 
+/// Filters for [`Client::orders`](crate::clob::Client::orders). Each set filter is sent as a
+/// query parameter and the filters are combined with AND; unset filters match every open order.
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Default, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct OrdersRequest {
+    /// Only the order with this ID.
     #[serde(rename = "id")]
     pub order_id: Option<String>,
     /// The market condition ID to filter orders.
     pub market: Option<B256>,
+    /// Only orders for this outcome token.
     #[serde_as(as = "Option<DisplayFromStr>")]
     pub asset_id: Option<U256>,
 }
@@ -138,10 +151,14 @@ pub struct DeleteNotificationsRequest {
     pub notification_ids: Vec<String>,
 }
 
+/// Selects the asset whose balance and allowance are queried.
+///
+/// [`AssetType::Conditional`] requests must name the outcome token via `token_id`, which the
+/// builder enforces in `build`.
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Default, Clone, Builder, Serialize)]
-#[builder(on(String, into))]
+#[builder(on(String, into), finish_fn(name = build_unchecked, vis = ""))]
 pub struct BalanceAllowanceRequest {
     pub asset_type: AssetType,
     #[serde_as(as = "Option<DisplayFromStr>")]
@@ -149,6 +166,26 @@ pub struct BalanceAllowanceRequest {
     pub signature_type: Option<SignatureType>,
 }
 
+impl<S: balance_allowance_request_builder::IsComplete> BalanceAllowanceRequestBuilder<S> {
+    /// Builds the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `asset_type` is [`AssetType::Conditional`] and no
+    /// `token_id` was provided.
+    pub fn build(self) -> crate::Result<BalanceAllowanceRequest> {
+        let request = self.build_unchecked();
+
+        if request.asset_type == AssetType::Conditional && request.token_id.is_none() {
+            return Err(crate::error::Error::validation(
+                "Unable to build BalanceAllowanceRequest: a token_id is required for CONDITIONAL assets",
+            ));
+        }
+
+        Ok(request)
+    }
+}
+
 pub type UpdateBalanceAllowanceRequest = BalanceAllowanceRequest;
 
 #[non_exhaustive]
@@ -465,6 +502,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn trades_request_filters_by_market_and_asset() {
+        let market = b256!("0000000000000000000000000000000000000000000000000000000000010000");
+        let request = TradesRequest::builder()
+            .market(market)
+            .asset_id(U256::from(100))
+            .build();
+
+        assert_eq!(
+            request.query_params(None),
+            "?market=0x0000000000000000000000000000000000000000000000000000000000010000&asset_id=100"
+        );
+        assert_eq!(TradesRequest::default().query_params(None), "");
+    }
+
+    #[test]
+    fn orders_request_filters_by_market_and_asset() {
+        let market = b256!("0000000000000000000000000000000000000000000000000000000000010000");
+        let request = OrdersRequest::builder()
+            .market(market)
+            .asset_id(U256::from(100))
+            .build();
+
+        assert_eq!(
+            request.query_params(None),
+            "?market=0x0000000000000000000000000000000000000000000000000000000000010000&asset_id=100"
+        );
+        assert_eq!(OrdersRequest::default().query_params(None), "");
+    }
+
     #[test]
     fn delete_notifications_request_as_params_should_succeed() {
         let empty_request = DeleteNotificationsRequest::builder().build();
@@ -482,7 +549,8 @@ mod tests {
             .asset_type(AssetType::Collateral)
             .token_id(U256::from(1))
             .signature_type(SignatureType::Eoa)
-            .build();
+            .build()
+            .unwrap();
 
         assert_eq!(
             request.query_params(None),
@@ -490,6 +558,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn conditional_balance_allowance_request_should_require_token_id() {
+        let err = BalanceAllowanceRequest::builder()
+            .asset_type(AssetType::Conditional)
+            .build()
+            .unwrap_err();
+        assert_eq!(err.kind(), crate::error::Kind::Validation);
+
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(AssetType::Conditional)
+            .token_id(U256::from(1))
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.query_params(None),
+            "?asset_type=CONDITIONAL&token_id=1"
+        );
+    }
+
     #[test]
     fn user_rewards_earning_request_as_params_should_succeed() {
         let request = UserRewardsEarningRequest::builder()
@@ -4,10 +4,13 @@
 )]
 
 use std::collections::HashMap;
+use std::str::FromStr as _;
 
 use bon::Builder;
 use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive as _;
 use serde::{Deserialize, Deserializer, Serialize};
+use serde_with::formats::Flexible;
 use serde_with::{
     DefaultOnError, DefaultOnNull, NoneAsEmptyString, TimestampMilliSeconds, TimestampSeconds,
     TryFromInto, serde_as,
@@ -17,8 +20,12 @@ use uuid::Uuid;
 
 use crate::Result;
 use crate::auth::ApiKey;
-use crate::clob::types::{OrderStatusType, OrderType, Side, TickSize, TraderSide};
-use crate::serde_helpers::StringFromAny;
+use crate::clob::TimeSource;
+use crate::clob::order_builder::LOT_SIZE_SCALE;
+use crate::clob::types::{
+    AssetType, NotificationType, OrderStatusType, OrderType, Side, TickSize, TraderSide,
+};
+use crate::serde_helpers::{FlexibleDecimal, FlexibleTimestamp, FlexibleU256, StringFromAny};
 use crate::types::{Address, B256, Decimal, U256};
 
 #[non_exhaustive]
@@ -28,9 +35,11 @@ pub struct MidpointResponse {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Clone, Debug, Default, Deserialize, Builder, PartialEq)]
 #[serde(transparent)]
 pub struct MidpointsResponse {
+    #[serde_as(as = "HashMap<FlexibleU256, _>")]
     pub midpoints: HashMap<U256, Decimal>,
 }
 
@@ -41,9 +50,11 @@ pub struct PriceResponse {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Clone, Debug, Default, Deserialize, Builder, PartialEq)]
 #[serde(transparent)]
 pub struct PricesResponse {
+    #[serde_as(as = "Option<HashMap<FlexibleU256, _>>", no_default)]
     pub prices: Option<HashMap<U256, HashMap<Side, Decimal>>>,
 }
 
@@ -54,8 +65,10 @@ pub struct SpreadResponse {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Clone, Debug, Deserialize, Builder, PartialEq)]
 pub struct SpreadsResponse {
+    #[serde_as(as = "Option<HashMap<FlexibleU256, _>>")]
     pub spreads: Option<HashMap<U256, Decimal>>,
 }
 
@@ -115,8 +128,9 @@ pub struct GeoblockResponse {
 pub struct OrderBookSummaryResponse {
     /// The market condition ID.
     pub market: B256,
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
-    #[serde_as(as = "TimestampMilliSeconds<String>")]
+    #[serde_as(as = "TimestampMilliSeconds<String, Flexible>")]
     pub timestamp: DateTime<Utc>,
     #[serde(default)]
     pub hash: Option<String>,
@@ -164,9 +178,11 @@ pub struct LastTradePriceResponse {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct LastTradesPricesResponse {
+    #[serde_as(as = "FlexibleU256")]
     pub token_id: U256,
     pub price: Decimal,
     pub side: Side,
@@ -186,8 +202,12 @@ pub struct MarketResponse {
     pub closed: bool,
     pub archived: bool,
     pub accepting_orders: bool,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub accepting_order_timestamp: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleDecimal")]
     pub minimum_order_size: Decimal,
+    #[serde_as(as = "FlexibleDecimal")]
     pub minimum_tick_size: Decimal,
     /// The market condition ID (unique market identifier).
     #[serde_as(as = "NoneAsEmptyString")]
@@ -200,14 +220,26 @@ pub struct MarketResponse {
     pub question: String,
     pub description: String,
     pub market_slug: String,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub end_date_iso: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub game_start_time: Option<DateTime<Utc>>,
     pub seconds_delay: u64,
     /// The FPMM (Fixed Product Market Maker) contract address.
     #[serde_as(as = "NoneAsEmptyString")]
     #[serde(default)]
     pub fpmm: Option<Address>,
+    /// Fee rate charged to makers, in basis points. Missing for markets without fees.
+    #[builder(default)]
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub maker_base_fee: Decimal,
+    /// Fee rate charged to takers, in basis points. Missing for markets without fees.
+    #[builder(default)]
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub taker_base_fee: Decimal,
     pub notifications_enabled: bool,
     pub neg_risk: bool,
@@ -232,11 +264,14 @@ pub struct MarketResponse {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct Token {
+    #[serde_as(as = "FlexibleU256")]
     pub token_id: U256,
     pub outcome: String,
+    #[serde_as(as = "FlexibleDecimal")]
     pub price: Decimal,
     #[serde(default)]
     pub winner: bool,
@@ -272,6 +307,50 @@ pub struct ApiKeysResponse {
     keys: Option<Vec<ApiKey>>,
 }
 
+impl ApiKeysResponse {
+    /// The API keys registered for the address.
+    #[must_use]
+    pub fn keys(&self) -> &[ApiKey] {
+        self.keys.as_deref().unwrap_or_default()
+    }
+}
+
+/// An API key along with whatever metadata the CLOB reports for it.
+///
+/// `/auth/api-keys` only returns the keys themselves, so `created_at` is `None` for standard keys.
+/// Builder keys from `/auth/builder-api-key` carry their creation and revocation times.
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder, PartialEq)]
+pub struct ApiKeyInfo {
+    pub key: ApiKey,
+    pub created_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    #[builder(default)]
+    pub is_builder: bool,
+}
+
+impl From<ApiKey> for ApiKeyInfo {
+    fn from(key: ApiKey) -> Self {
+        Self {
+            key,
+            created_at: None,
+            revoked_at: None,
+            is_builder: false,
+        }
+    }
+}
+
+impl From<BuilderApiKeyResponse> for ApiKeyInfo {
+    fn from(response: BuilderApiKeyResponse) -> Self {
+        Self {
+            key: response.key,
+            created_at: response.created_at,
+            revoked_at: response.revoked_at,
+            is_builder: true,
+        }
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 pub struct BanStatusResponse {
@@ -285,9 +364,9 @@ pub struct BanStatusResponse {
 #[builder(on(String, into))]
 pub struct PostOrderResponse {
     pub error_msg: Option<String>,
-    #[serde(deserialize_with = "empty_string_as_zero")]
+    #[serde_as(as = "FlexibleDecimal")]
     pub making_amount: Decimal,
-    #[serde(deserialize_with = "empty_string_as_zero")]
+    #[serde_as(as = "FlexibleDecimal")]
     pub taking_amount: Decimal,
     #[serde(rename = "orderID")]
     pub order_id: String,
@@ -303,19 +382,28 @@ pub struct PostOrderResponse {
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub trade_ids: Vec<String>,
+    /// Where the timestamp signing the auth headers of the request that posted this order came
+    /// from. Set locally by [`Client::post_order`](crate::clob::Client::post_order) and its
+    /// variants, and useful for debugging auth failures when
+    /// [`Config`](crate::clob::Config) enables `use_server_time` with
+    /// [`ServerTimePolicy::FallbackToLocal`](crate::clob::ServerTimePolicy::FallbackToLocal).
+    #[serde(skip)]
+    pub time_source: Option<TimeSource>,
+    /// The builder fee owed on the USDC notional this order matched, computed locally with
+    /// [`Fee::amount`](crate::auth::builder::Fee::amount). Only set when the order was posted by a
+    /// client promoted with
+    /// [`Client::promote_to_builder_with_fee`](crate::clob::Client::promote_to_builder_with_fee).
+    #[serde(skip)]
+    pub builder_fee: Option<Decimal>,
 }
 
+/// Deserializes a `Decimal` from a number or numeric string, treating an empty string as zero.
+/// Prefer `#[serde_as(as = "FlexibleDecimal")]`, which this delegates to.
 pub fn empty_string_as_zero<'de, D>(deserializer: D) -> std::result::Result<Decimal, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
-
-    if s.trim().is_empty() {
-        Ok(Decimal::ZERO)
-    } else {
-        s.parse::<Decimal>().map_err(serde::de::Error::custom)
-    }
+    <FlexibleDecimal as serde_with::DeserializeAs<'de, Decimal>>::deserialize_as(deserializer)
 }
 
 #[non_exhaustive]
@@ -329,36 +417,125 @@ pub struct OpenOrderResponse {
     pub maker_address: Address,
     /// The market condition ID.
     pub market: B256,
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     pub side: Side,
+    #[serde_as(as = "FlexibleDecimal")]
     pub original_size: Decimal,
+    #[serde_as(as = "FlexibleDecimal")]
     pub size_matched: Decimal,
+    #[serde_as(as = "FlexibleDecimal")]
     pub price: Decimal,
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub associate_trades: Vec<String>,
     pub outcome: String,
-    #[serde(with = "chrono::serde::ts_seconds")]
+    #[serde_as(as = "TimestampSeconds<i64, Flexible>")]
     pub created_at: DateTime<Utc>,
-    #[serde_as(as = "TimestampSeconds<String>")]
+    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
     pub expiration: DateTime<Utc>,
     pub order_type: OrderType,
 }
 
+/// The outcome of a cancel request, listing which orders were canceled and why the others were
+/// not.
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Default, Deserialize, Builder, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelOrdersResponse {
+    /// IDs of the orders that were canceled.
     #[builder(default)]
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub canceled: Vec<String>,
+    /// IDs of the orders that were not canceled, mapped to the reason reported by the CLOB. Use
+    /// [`Self::not_canceled_reason`] to classify the reason.
     #[builder(default)]
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     #[serde(alias = "not_canceled")]
     pub not_canceled: HashMap<String, String>,
+    /// Batches of a split [`Client::cancel_orders`](crate::clob::Client::cancel_orders) call
+    /// whose request failed while other batches succeeded. Their orders are in neither
+    /// `canceled` nor `not_canceled`.
+    #[builder(default)]
+    #[serde(skip)]
+    pub failed_batches: Vec<FailedCancelBatch>,
+}
+
+/// A batch of a split cancel whose request failed, see
+/// [`CancelOrdersResponse::failed_batches`].
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct FailedCancelBatch {
+    /// IDs of the orders in the batch. Whether they were canceled is unknown, since the request
+    /// may have failed after reaching the CLOB.
+    pub order_ids: Vec<String>,
+    /// Why the request failed.
+    pub error: crate::error::Error,
+}
+
+impl PartialEq for FailedCancelBatch {
+    fn eq(&self, other: &Self) -> bool {
+        self.order_ids == other.order_ids && self.error.kind() == other.error.kind()
+    }
+}
+
+impl CancelOrdersResponse {
+    /// Returns whether the order with `order_id` was canceled by this request.
+    #[must_use]
+    pub fn is_canceled(&self, order_id: &str) -> bool {
+        self.canceled.iter().any(|id| id == order_id)
+    }
+
+    /// Returns why the order with `order_id` was not canceled, or `None` if it was not rejected.
+    #[must_use]
+    pub fn not_canceled_reason(&self, order_id: &str) -> Option<NotCanceledReason> {
+        self.not_canceled
+            .get(order_id)
+            .map(|reason| NotCanceledReason::from(reason.as_str()))
+    }
+
+    /// Iterates over the orders that were not canceled, along with the classified reason.
+    pub fn not_canceled_reasons(&self) -> impl Iterator<Item = (&str, NotCanceledReason)> {
+        self.not_canceled
+            .iter()
+            .map(|(id, reason)| (id.as_str(), NotCanceledReason::from(reason.as_str())))
+    }
+}
+
+/// Why the CLOB declined to cancel an order, classified from the message in
+/// [`CancelOrdersResponse::not_canceled`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotCanceledReason {
+    /// The order was already canceled.
+    AlreadyCanceled,
+    /// The order was matched (filled), so there is nothing left to cancel.
+    Matched,
+    /// The CLOB could not find the order. It may never have existed, or it may have since been
+    /// canceled or matched.
+    NotFound,
+    /// Any other reason, holding the message reported by the CLOB.
+    Other(String),
+}
+
+impl From<&str> for NotCanceledReason {
+    fn from(reason: &str) -> Self {
+        let lowercase = reason.to_lowercase();
+
+        if lowercase.contains("not found") || lowercase.contains("can't be found") {
+            Self::NotFound
+        } else if lowercase.contains("already canceled") || lowercase.contains("already cancelled")
+        {
+            Self::AlreadyCanceled
+        } else if lowercase.contains("matched") || lowercase.contains("filled") {
+            Self::Matched
+        } else {
+            Self::Other(reason.to_owned())
+        }
+    }
 }
 
 #[non_exhaustive]
@@ -370,15 +547,19 @@ pub struct TradeResponse {
     pub taker_order_id: String,
     /// The market condition ID.
     pub market: B256,
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     pub side: Side,
+    #[serde_as(as = "FlexibleDecimal")]
     pub size: Decimal,
+    #[serde_as(as = "FlexibleDecimal")]
     pub fee_rate_bps: Decimal,
+    #[serde_as(as = "FlexibleDecimal")]
     pub price: Decimal,
     pub status: OrderStatusType,
-    #[serde_as(as = "TimestampSeconds<String>")]
+    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
     pub match_time: DateTime<Utc>,
-    #[serde_as(as = "TimestampSeconds<String>")]
+    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
     pub last_update: DateTime<Utc>,
     pub outcome: String,
     pub bucket_index: u32,
@@ -395,17 +576,98 @@ pub struct TradeResponse {
 }
 
 #[non_exhaustive]
-#[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
+#[derive(Debug, Clone, Builder, PartialEq)]
 pub struct NotificationResponse {
-    pub r#type: u32,
+    /// The notification's ID, which can be passed to
+    /// [`Client::drop_notifications`](crate::clob::Client::drop_notifications) to mark it as read.
+    pub id: Option<String>,
+    pub r#type: NotificationType,
     pub owner: ApiKey,
-    pub payload: NotificationPayload,
+    /// The notification's payload, parsed according to its `type`.
+    pub payload: Notification,
+    /// When the notification was raised.
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+impl<'de> Deserialize<'de> for NotificationResponse {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[serde_as]
+        #[derive(Deserialize)]
+        struct RawNotification {
+            #[serde(default)]
+            #[serde_as(as = "Option<StringFromAny>")]
+            id: Option<String>,
+            r#type: NotificationType,
+            owner: ApiKey,
+            #[serde(default)]
+            payload: serde_json::Value,
+            #[serde(default)]
+            #[serde_as(as = "FlexibleTimestamp")]
+            timestamp: Option<DateTime<Utc>>,
+        }
+
+        let raw = RawNotification::deserialize(deserializer)?;
+
+        Ok(Self {
+            id: raw.id,
+            r#type: raw.r#type,
+            owner: raw.owner,
+            payload: Notification::from_payload(raw.r#type, raw.payload),
+            timestamp: raw.timestamp,
+        })
+    }
+}
+
+/// A notification payload, typed according to the notification's [`NotificationType`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Notification {
+    /// One of the user's orders was cancelled.
+    OrderCancellation(NotificationPayload),
+    /// One of the user's orders was (partially) filled.
+    OrderFill(NotificationPayload),
+    /// A market the user participated in was resolved.
+    MarketResolved(MarketResolvedPayload),
+    /// The raw payload of a notification whose type is unknown, or whose payload does not match
+    /// the shape expected for its type.
+    Unknown(serde_json::Value),
+}
+
+impl Notification {
+    fn from_payload(notification_type: NotificationType, payload: serde_json::Value) -> Self {
+        let parsed = match notification_type {
+            NotificationType::OrderCancellation => {
+                NotificationPayload::deserialize(&payload).map(Self::OrderCancellation)
+            }
+            NotificationType::OrderFill => {
+                NotificationPayload::deserialize(&payload).map(Self::OrderFill)
+            }
+            NotificationType::MarketResolved => {
+                MarketResolvedPayload::deserialize(&payload).map(Self::MarketResolved)
+            }
+            NotificationType::Unknown(_) => return Self::Unknown(payload),
+        };
+
+        parsed.unwrap_or_else(|e| {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("Unable to parse {notification_type:?} notification payload: {e}");
+            #[cfg(not(feature = "tracing"))]
+            let _: serde_json::Error = e;
+
+            Self::Unknown(payload)
+        })
+    }
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct NotificationPayload {
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     /// The market condition ID (unique market identifier).
     pub condition_id: B256,
@@ -428,13 +690,40 @@ pub struct NotificationPayload {
     #[serde(rename = "seriesSlug")]
     pub series_slug: String,
     pub side: Side,
-    pub trade_id: String,
-    /// On-chain transaction hash.
-    pub transaction_hash: B256,
+    /// The trade that filled the order. Not present on cancellations.
+    #[serde(default)]
+    pub trade_id: Option<String>,
+    /// On-chain transaction hash. Not present on cancellations.
+    #[serde(default)]
+    pub transaction_hash: Option<B256>,
     #[serde(alias = "type")]
     pub order_type: OrderType,
 }
 
+/// Payload of a [`Notification::MarketResolved`] notification.
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq)]
+#[builder(on(String, into))]
+pub struct MarketResolvedPayload {
+    /// The market condition ID (unique market identifier).
+    pub condition_id: B256,
+    #[serde(default)]
+    pub market: Option<B256>,
+    #[serde(default)]
+    pub market_slug: Option<String>,
+    #[serde(rename = "eventSlug", default)]
+    pub event_slug: Option<String>,
+    #[serde(default)]
+    pub question: Option<String>,
+    /// The outcome the market resolved to.
+    #[serde(default)]
+    pub outcome: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub image: Option<String>,
+}
+
 #[non_exhaustive]
 #[allow(
     clippy::allow_attributes,
@@ -449,6 +738,28 @@ pub struct BalanceAllowanceResponse {
     pub allowances: HashMap<Address, String>,
 }
 
+impl BalanceAllowanceResponse {
+    /// Returns the smallest allowance granted to any of the exchange contracts, in base units.
+    ///
+    /// This is the amount that can actually be traded, since an order may settle against any of
+    /// them. Returns `None` if there are no allowances or any of them cannot be parsed.
+    #[must_use]
+    pub fn allowance(&self) -> Option<U256> {
+        self.allowances
+            .values()
+            .map(|allowance| U256::from_str(allowance).ok())
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .min()
+    }
+
+    /// Returns the allowance granted to `spender`, in base units, if present and valid.
+    #[must_use]
+    pub fn allowance_for(&self, spender: Address) -> Option<U256> {
+        U256::from_str(self.allowances.get(&spender)?).ok()
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 pub struct OrderScoringResponse {
@@ -495,6 +806,7 @@ pub struct UserInfo {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct MakerOrder {
@@ -504,6 +816,7 @@ pub struct MakerOrder {
     pub matched_amount: Decimal,
     pub price: Decimal,
     pub fee_rate_bps: Decimal,
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     pub outcome: String,
     pub side: Side,
@@ -595,6 +908,160 @@ pub struct Earning {
 
 pub type RewardsPercentagesResponse = HashMap<String, Decimal>;
 
+/// A snapshot of the user's market making rewards, combining the current reward programs, the
+/// user's share of each market's rewards, and the user's earnings for the day.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder, PartialEq)]
+pub struct RewardsSummary {
+    /// The (UTC) day the earnings were accrued on.
+    pub date: NaiveDate,
+    /// Total rewards accrued on `date` across all markets.
+    pub accrued: Decimal,
+    /// Projected rewards for a full day at the user's current share of each market.
+    pub projected_daily_total: Decimal,
+    /// Markets the user has either earned rewards in on `date`, or currently has a share of the
+    /// rewards in, ordered by condition ID.
+    pub markets: Vec<MarketRewardsSummary>,
+}
+
+/// Whether the user can afford a signed order, as computed by
+/// [`Client::check_order_feasibility`](crate::clob::Client::check_order_feasibility).
+///
+/// Amounts are in whole units: USDC for buy orders and shares of the order's outcome token for
+/// sell orders.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder, PartialEq)]
+pub struct FeasibilityReport {
+    /// The asset the order spends: [`AssetType::Collateral`] for buys and
+    /// [`AssetType::Conditional`] for sells.
+    pub asset_type: AssetType,
+    /// The amount the order spends (its maker amount).
+    pub required: Decimal,
+    /// The user's current balance of the asset.
+    pub balance: Decimal,
+    /// The smallest allowance granted to the exchange contracts, or `None` if none was reported or
+    /// it is too large to represent (e.g. an unlimited approval). A `None` allowance does not
+    /// limit the order.
+    pub min_allowance: Option<Decimal>,
+    /// Whether the balance and allowance both cover `required`.
+    pub sufficient: bool,
+    /// How much more balance or allowance is needed for the order to go through. Zero when
+    /// `sufficient`.
+    pub shortfall: Decimal,
+}
+
+/// Order placement rules for a token, as returned by
+/// [`Client::order_constraints`](crate::clob::Client::order_constraints).
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder, PartialEq)]
+pub struct OrderConstraints {
+    pub token_id: U256,
+    /// Minimum price increment.
+    pub tick_size: TickSize,
+    /// Minimum size, in shares, of a limit order.
+    pub min_order_size: Decimal,
+    /// Whether the token belongs to a neg-risk market, which signs orders against the neg-risk
+    /// exchange.
+    pub neg_risk: bool,
+}
+
+impl OrderConstraints {
+    /// Checks a limit order's `price` and `size` against these constraints without any network
+    /// calls, applying the same rules as the limit order builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error describing the first rule the order breaks.
+    pub fn validate(&self, price: Decimal, size: Decimal) -> Result<()> {
+        let tick_size = self.tick_size.as_decimal();
+
+        if price.scale() > tick_size.scale() {
+            return Err(crate::error::Error::validation(format!(
+                "Price {price} has more decimal places than the tick size {tick_size}"
+            )));
+        }
+
+        if price < tick_size || price > Decimal::ONE - tick_size {
+            return Err(crate::error::Error::validation(format!(
+                "Price {price} is too small or too large for the minimum tick size {tick_size}"
+            )));
+        }
+
+        if size.scale() > LOT_SIZE_SCALE {
+            return Err(crate::error::Error::validation(format!(
+                "Size {size} has more than {LOT_SIZE_SCALE} decimal places"
+            )));
+        }
+
+        if size < self.min_order_size {
+            return Err(crate::error::Error::validation(format!(
+                "Size {size} is below the minimum order size {}",
+                self.min_order_size
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl MarketResponse {
+    /// Returns this market's maker and taker fee rates.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if either rate is not a whole, non-negative number of basis
+    /// points.
+    pub fn fee_rates(&self) -> Result<FeeRates> {
+        Ok(FeeRates {
+            maker_bps: fee_rate_bps("maker", self.maker_base_fee)?,
+            taker_bps: fee_rate_bps("taker", self.taker_base_fee)?,
+        })
+    }
+}
+
+fn fee_rate_bps(kind: &str, rate: Decimal) -> Result<u32> {
+    rate.normalize()
+        .is_integer()
+        .then(|| rate.to_u32())
+        .flatten()
+        .ok_or_else(|| {
+            crate::error::Error::validation(format!(
+                "Invalid {kind} fee rate {rate}: expected a whole number of basis points"
+            ))
+        })
+}
+
+/// A market's fee rates, as returned by [`Client::fee_rates`](crate::clob::Client::fee_rates).
+///
+/// Both are zero for markets without fees. Pass [`Self::taker_bps`] to
+/// [`fees::net_proceeds`](crate::clob::fees::net_proceeds) to price a taker order.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, Builder, PartialEq, Eq)]
+pub struct FeeRates {
+    /// Fee rate charged to makers, in basis points.
+    pub maker_bps: u32,
+    /// Fee rate charged to takers, in basis points.
+    pub taker_bps: u32,
+}
+
+/// The user's rewards for a single market within a [`RewardsSummary`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder, PartialEq)]
+pub struct MarketRewardsSummary {
+    /// The market condition ID (unique market identifier).
+    pub condition_id: B256,
+    /// Rewards accrued in this market on the summary's date.
+    pub accrued: Decimal,
+    /// The user's current share of this market's rewards, as a percentage.
+    pub percentage: Decimal,
+    /// The total rewards paid out by this market per day. Zero if the market has no active reward
+    /// program.
+    pub rate_per_day: Decimal,
+    /// Projected rewards for a full day at the user's current share (`percentage` of
+    /// `rate_per_day`).
+    pub projected_daily: Decimal,
+}
+
 #[non_exhaustive]
 #[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
@@ -638,8 +1105,10 @@ pub struct MarketRewardResponse {
 pub struct BuilderApiKeyResponse {
     pub key: ApiKey,
     #[serde(default)]
+    #[serde_as(as = "FlexibleTimestamp")]
     pub created_at: Option<DateTime<Utc>>,
     #[serde(default)]
+    #[serde_as(as = "FlexibleTimestamp")]
     pub revoked_at: Option<DateTime<Utc>>,
 }
 
@@ -657,6 +1126,7 @@ pub struct BuilderTradeResponse {
     pub builder: Address,
     /// The market condition ID.
     pub market: B256,
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     pub side: Side,
     pub size: Decimal,
@@ -670,14 +1140,18 @@ pub struct BuilderTradeResponse {
     pub maker: Address,
     /// On-chain transaction hash.
     pub transaction_hash: B256,
-    #[serde_as(as = "TimestampSeconds<String>")]
+    #[serde_as(as = "TimestampSeconds<String, Flexible>")]
     pub match_time: DateTime<Utc>,
     pub bucket_index: u32,
     pub fee: Decimal,
     pub fee_usdc: Decimal,
     #[serde(alias = "err_msg")]
     pub err_msg: Option<String>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
 }
 
@@ -750,6 +1224,7 @@ pub struct ApproveRfqOrderResponse {
 /// An RFQ request in the system.
 #[cfg(feature = "rfq")]
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[builder(on(String, into))]
@@ -763,8 +1238,10 @@ pub struct RfqRequest {
     /// Market condition ID.
     pub condition: B256,
     /// Token ID for the outcome token.
+    #[serde_as(as = "FlexibleU256")]
     pub token: U256,
     /// Complement token ID.
+    #[serde_as(as = "FlexibleU256")]
     pub complement: U256,
     /// Order side (BUY or SELL).
     pub side: Side,
@@ -781,6 +1258,7 @@ pub struct RfqRequest {
 /// An RFQ quote in the system.
 #[cfg(feature = "rfq")]
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[builder(on(String, into))]
@@ -796,8 +1274,10 @@ pub struct RfqQuote {
     /// Market condition ID.
     pub condition: B256,
     /// Token ID for the outcome token.
+    #[serde_as(as = "FlexibleU256")]
     pub token: U256,
     /// Complement token ID.
+    #[serde_as(as = "FlexibleU256")]
     pub complement: U256,
     /// Order side (BUY or SELL).
     pub side: Side,
@@ -1,13 +1,12 @@
 use std::sync::Arc;
 
-use async_stream::try_stream;
-use dashmap::mapref::one::{Ref, RefMut};
+use dashmap::mapref::one::Ref;
 use dashmap::{DashMap, Entry};
 use futures::Stream;
 use futures::StreamExt as _;
 
 use super::interest::InterestTracker;
-use super::subscription::{ChannelType, SubscriptionManager};
+use super::subscription::{ChannelType, MarketStream, SubscriptionManager};
 use super::types::response::{
     BestBidAsk, BookUpdate, LastTradePrice, MarketResolved, MidpointUpdate, NewMarket,
     OrderMessage, PriceChange, TickSizeChange, TradeMessage, WsMessage,
@@ -18,8 +17,9 @@ use crate::auth::{Credentials, Kind as AuthKind, Normal};
 use crate::error::Error;
 use crate::types::{Address, B256, Decimal, U256};
 use crate::ws::ConnectionManager;
+use crate::ws::StreamEvent;
 use crate::ws::config::Config;
-use crate::ws::connection::ConnectionState;
+use crate::ws::connection::{self, ConnectionState};
 
 /// WebSocket client for real-time market data and user updates.
 ///
@@ -274,22 +274,22 @@ impl<S: State> Client<S> {
     ) -> Result<impl Stream<Item = Result<MidpointUpdate>>> {
         let stream = self.subscribe_orderbook(asset_ids)?;
 
-        Ok(try_stream! {
-            for await book_result in stream {
-                let book = book_result?;
-
-                // Calculate midpoint from best bid/ask
-                if let (Some(bid), Some(ask)) = (book.bids.first(), book.asks.first()) {
-                    let midpoint = (bid.price + ask.price) / Decimal::TWO;
-                    yield MidpointUpdate {
-                        asset_id: book.asset_id,
-                        market: book.market,
-                        midpoint,
-                        timestamp: book.timestamp,
-                    };
-                }
-            }
-        })
+        Ok(stream.filter_map(|book_result| async move {
+            let book = match book_result {
+                Ok(book) => book,
+                Err(e) => return Some(Err(e)),
+            };
+
+            // Calculate midpoint from best bid/ask
+            let (bid, ask) = (book.bids.first()?, book.asks.first()?);
+            let midpoint = (bid.price + ask.price) / Decimal::TWO;
+            Some(Ok(MidpointUpdate {
+                asset_id: book.asset_id,
+                market: book.market,
+                midpoint,
+                timestamp: book.timestamp,
+            }))
+        }))
     }
 
     /// Subscribe to best bid/ask updates with custom features enabled.
@@ -358,6 +358,51 @@ impl<S: State> Client<S> {
         }))
     }
 
+    /// Subscribes to all market messages for the specified assets with a [`MarketStream`],
+    /// whose assets can be added or removed while it is open via
+    /// [`MarketStream::subscribe_tokens`] and [`MarketStream::unsubscribe_tokens`].
+    ///
+    /// This suits a changing watchlist: the connection stays up, only the subscription deltas are
+    /// sent, and the current set of assets is restored after a reconnect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `asset_ids` is empty or the subscription cannot be created.
+    pub fn subscribe_market_stream(&self, asset_ids: Vec<U256>) -> Result<MarketStream> {
+        self.inner
+            .get_or_create_channel(ChannelType::Market)?
+            .subscriptions
+            .subscribe_market_stream(asset_ids, false)
+    }
+
+    /// Wraps a stream created by this client for `channel_type` so that it also yields a
+    /// [`StreamEvent::Reconnected`] each time that channel's connection is re-established.
+    ///
+    /// Subscriptions are restored automatically after a reconnect, but messages sent while
+    /// disconnected are lost. Use this to know when to resync local state, such as an order book
+    /// built from `price_change` deltas. How often to PING and how to back off between
+    /// reconnection attempts is set through the [`Config`] the client was created with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the channel's connection cannot be created.
+    pub fn with_reconnects<T, St>(
+        &self,
+        channel_type: ChannelType,
+        stream: St,
+    ) -> Result<impl Stream<Item = Result<StreamEvent<T>>> + use<T, St, S>>
+    where
+        St: Stream<Item = Result<T>>,
+    {
+        let state_rx = self
+            .inner
+            .get_or_create_channel(channel_type)?
+            .connection
+            .state_receiver();
+
+        Ok(connection::with_reconnects(state_rx, stream))
+    }
+
     /// Get the current connection state for a specific channel.
     ///
     /// Returns [`ConnectionState::Disconnected`] if the channel has not been
@@ -384,10 +429,35 @@ impl<S: State> Client<S> {
         self.inner
             .channels
             .iter()
+            .filter(|entry| !entry.value().connection.is_closed())
             .map(|entry| entry.value().subscriptions.subscription_count())
             .sum()
     }
 
+    /// Close the connection of every channel and stop reconnecting.
+    ///
+    /// Each connection sends a close frame and waits up to
+    /// [`StreamConfig::close_timeout`](crate::ws::config::StreamConfig::close_timeout) for the
+    /// server to acknowledge it. Streams from this client end, and later subscriptions open a new
+    /// connection.
+    ///
+    /// A channel's connection is also closed, in the background, once the client and every stream
+    /// on that channel are dropped.
+    pub async fn close(&self) {
+        let channel_types: Vec<ChannelType> = self
+            .inner
+            .channels
+            .iter()
+            .map(|entry| *entry.key())
+            .collect();
+
+        for channel_type in channel_types {
+            if let Some((_, channel)) = self.inner.channels.remove(&channel_type) {
+                channel.connection.close().await;
+            }
+        }
+    }
+
     /// Unsubscribe from orderbook updates for specific assets.
     ///
     /// This decrements the reference count for each asset. The server unsubscribe
@@ -578,17 +648,29 @@ impl<S: State> ClientInner<S> {
         &self,
         channel_type: ChannelType,
     ) -> Result<Ref<'_, ChannelType, ChannelResources>> {
-        self.channels
+        let new_channel = || {
+            let endpoint = channel_endpoint(&self.base_endpoint, channel_type);
+            ChannelResources::new(endpoint, self.config.clone())
+        };
+
+        let mut channel = self
+            .channels
             .entry(channel_type)
-            .or_try_insert_with(|| {
-                let endpoint = channel_endpoint(&self.base_endpoint, channel_type);
-                ChannelResources::new(endpoint, self.config.clone())
-            })
-            .map(RefMut::downgrade)
+            .or_try_insert_with(new_channel)?;
+
+        // A connection closed through a stream stays in the map until it is replaced here
+        if channel.connection.is_closed() {
+            *channel = new_channel()?;
+        }
+
+        Ok(channel.downgrade())
     }
 
+    /// The open channel of `channel_type`, if any.
     fn channel(&self, channel_type: ChannelType) -> Option<Ref<'_, ChannelType, ChannelResources>> {
-        self.channels.get(&channel_type)
+        self.channels
+            .get(&channel_type)
+            .filter(|channel| !channel.connection.is_closed())
     }
 
     /// Helper to unsubscribe and remove connection if there are no more subscriptions on this channel
@@ -10,7 +10,7 @@ pub mod types;
 
 // Re-export commonly used types
 pub use client::Client;
-pub use subscription::{ChannelType, SubscriptionInfo, SubscriptionTarget};
+pub use subscription::{ChannelType, MarketStream, SubscriptionInfo, SubscriptionTarget};
 pub use types::request::SubscriptionRequest;
 pub use types::response::{
     BestBidAsk, BookUpdate, EventMessage, LastTradePrice, MakerOrder, MarketResolved,
@@ -18,4 +18,4 @@ pub use types::response::{
     TickSizeChange, TradeMessage, WsMessage,
 };
 
-pub use crate::ws::WsError;
+pub use crate::ws::{StreamEvent, WsError};
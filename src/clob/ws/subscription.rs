@@ -4,11 +4,13 @@
 )]
 
 use std::collections::{HashMap, HashSet};
+use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, PoisonError, RwLock};
+use std::task::{Context, Poll};
 use std::time::Instant;
 
-use async_stream::try_stream;
+use async_stream::stream;
 use dashmap::{DashMap, Entry};
 use futures::Stream;
 use tokio::sync::broadcast::error::RecvError;
@@ -21,6 +23,7 @@ use crate::auth::Credentials;
 use crate::types::{B256, U256};
 use crate::ws::ConnectionManager;
 use crate::ws::WsError;
+use crate::ws::config::BackpressurePolicy;
 use crate::ws::connection::ConnectionState;
 
 /// What a subscription is targeting.
@@ -105,11 +108,14 @@ impl SubscriptionManager {
     }
 
     /// Start the reconnection handler that re-subscribes on connection recovery.
+    ///
+    /// The handler only holds a weak reference to the manager, so it does not keep the
+    /// connection open once the client and its streams are dropped.
     pub fn start_reconnection_handler(self: &Arc<Self>) {
-        let this = Arc::clone(self);
+        let this = Arc::downgrade(self);
+        let mut state_rx = self.connection.state_receiver();
 
         tokio::spawn(async move {
-            let mut state_rx = this.connection.state_receiver();
             let mut was_connected = state_rx.borrow().is_connected();
 
             loop {
@@ -124,6 +130,9 @@ impl SubscriptionManager {
                 match state {
                     ConnectionState::Connected { .. } => {
                         if was_connected {
+                            let Some(this) = this.upgrade() else {
+                                break;
+                            };
                             // Reconnect to subscriptions
                             #[cfg(feature = "tracing")]
                             tracing::debug!("WebSocket reconnected, re-establishing subscriptions");
@@ -214,6 +223,40 @@ impl SubscriptionManager {
         asset_ids: Vec<U256>,
         custom_features: bool,
     ) -> Result<impl Stream<Item = Result<WsMessage>> + use<>> {
+        self.add_assets(&asset_ids, custom_features)?;
+        self.register_assets(market_subscription_id(&asset_ids), asset_ids.clone());
+
+        Ok(self.asset_stream(Arc::new(RwLock::new(asset_ids.into_iter().collect()))))
+    }
+
+    /// Subscribe to public market data with a [`MarketStream`], whose assets can be changed while
+    /// it is open.
+    ///
+    /// This will fail if `asset_ids` is empty.
+    pub fn subscribe_market_stream(
+        self: &Arc<Self>,
+        asset_ids: Vec<U256>,
+        custom_features: bool,
+    ) -> Result<MarketStream> {
+        self.add_assets(&asset_ids, custom_features)?;
+
+        let subscription_id = market_subscription_id(&asset_ids);
+        self.register_assets(subscription_id.clone(), asset_ids.clone());
+
+        let assets = Arc::new(RwLock::new(asset_ids.into_iter().collect()));
+
+        Ok(MarketStream {
+            subscriptions: Arc::clone(self),
+            subscription_id,
+            custom_features,
+            stream: Box::pin(self.asset_stream(Arc::clone(&assets))),
+            assets,
+        })
+    }
+
+    /// Increments the reference count of each asset and sends a subscription request for the ones
+    /// that are new to this connection.
+    fn add_assets(&self, asset_ids: &[U256], custom_features: bool) -> Result<()> {
         if asset_ids.is_empty() {
             return Err(WsError::SubscriptionFailed(
                 "asset_ids cannot be empty: at least one asset ID must be provided for subscription"
@@ -263,67 +306,79 @@ impl SubscriptionManager {
             self.connection.send(&request)?;
         }
 
-        // Register subscription
-        let sub_id = format!(
-            "market:{}",
-            asset_ids
-                .iter()
-                .map(ToString::to_string)
-                .collect::<Vec<_>>()
-                .join(",")
-        );
+        Ok(())
+    }
+
+    fn register_assets(&self, subscription_id: String, asset_ids: Vec<U256>) {
         self.active_subs.insert(
-            sub_id,
+            subscription_id,
             SubscriptionInfo {
-                target: SubscriptionTarget::Assets(asset_ids.clone()),
+                target: SubscriptionTarget::Assets(asset_ids),
                 created_at: Instant::now(),
             },
         );
+    }
 
-        // Create filtered stream with its own receiver
-        let mut rx = self.connection.subscribe();
-        let asset_ids_set: HashSet<U256> = asset_ids.into_iter().collect();
+    /// Creates a stream with its own receiver, yielding the market messages for `asset_ids`.
+    fn asset_stream(
+        &self,
+        asset_ids: Arc<RwLock<HashSet<U256>>>,
+    ) -> impl Stream<Item = Result<WsMessage>> + use<> {
+        let connection = self.connection.clone();
+        let mut rx = connection.subscribe();
+        let backpressure = connection.backpressure();
 
-        Ok(try_stream! {
+        stream! {
             loop {
                 match rx.recv().await {
                     Ok(msg) => {
-                        // Filter messages by asset_id
-                        let should_yield = match &msg {
-                            WsMessage::Book(book) => asset_ids_set.contains(&book.asset_id),
-                            WsMessage::PriceChange(price) => {
-                                price
-                                    .price_changes
-                                    .iter()
-                                    .any(|pc| asset_ids_set.contains(&pc.asset_id))
-                            },
-                            WsMessage::LastTradePrice(ltp) => asset_ids_set.contains(&ltp.asset_id),
-                            WsMessage::TickSizeChange(tsc) => asset_ids_set.contains(&tsc.asset_id),
-                            WsMessage::BestBidAsk(bba) => asset_ids_set.contains(&bba.asset_id),
-                            WsMessage::NewMarket(nm) => {
-                                nm.asset_ids.iter().any(|id| asset_ids_set.contains(id))
-                            },
-                            WsMessage::MarketResolved(mr) => {
-                                mr.asset_ids.iter().any(|id| asset_ids_set.contains(id))
-                            },
-                            _ => false,
+                        // Filter messages by asset_id. We can recover from a poisoned lock
+                        // because the set has no inconsistent intermediate state.
+                        let should_yield = {
+                            let asset_ids_set =
+                                asset_ids.read().unwrap_or_else(PoisonError::into_inner);
+                            match &msg {
+                                WsMessage::Book(book) => asset_ids_set.contains(&book.asset_id),
+                                WsMessage::PriceChange(price) => {
+                                    price
+                                        .price_changes
+                                        .iter()
+                                        .any(|pc| asset_ids_set.contains(&pc.asset_id))
+                                },
+                                WsMessage::LastTradePrice(ltp) => asset_ids_set.contains(&ltp.asset_id),
+                                WsMessage::TickSizeChange(tsc) => asset_ids_set.contains(&tsc.asset_id),
+                                WsMessage::BestBidAsk(bba) => asset_ids_set.contains(&bba.asset_id),
+                                WsMessage::NewMarket(nm) => {
+                                    nm.asset_ids.iter().any(|id| asset_ids_set.contains(id))
+                                },
+                                WsMessage::MarketResolved(mr) => {
+                                    mr.asset_ids.iter().any(|id| asset_ids_set.contains(id))
+                                },
+                                _ => false,
+                            }
                         };
 
                         if should_yield {
-                            yield msg
+                            yield Ok(msg)
                         }
                     }
                     Err(RecvError::Lagged(n)) => {
                         #[cfg(feature = "tracing")]
                         tracing::warn!("Subscription lagged, missed {n} messages");
-                        Err(WsError::Lagged { count: n })?;
+                        yield Err(WsError::Lagged { count: n }.into());
+                        if backpressure != BackpressurePolicy::DropOldest {
+                            break;
+                        }
                     }
                     Err(RecvError::Closed) => {
                         break;
                     }
                 }
             }
-        })
+
+            // Holding the connection until here keeps it open for as long as this stream is alive
+            drop(connection);
+        }
     }
 
     /// Subscribe to authenticated user channel.
@@ -389,26 +444,34 @@ impl SubscriptionManager {
         );
 
         // Create stream for user messages
-        let mut rx = self.connection.subscribe();
+        let connection = self.connection.clone();
+        let mut rx = connection.subscribe();
+        let backpressure = connection.backpressure();
 
-        Ok(try_stream! {
+        Ok(stream! {
             loop {
                 match rx.recv().await {
                     Ok(msg) => {
                         if msg.is_user() {
-                            yield msg;
+                            yield Ok(msg);
                         }
                     }
                     Err(RecvError::Lagged(n)) => {
                         #[cfg(feature = "tracing")]
                         tracing::warn!("Subscription lagged, missed {n} messages");
-                        Err(WsError::Lagged { count: n })?;
+                        yield Err(WsError::Lagged { count: n }.into());
+                        if backpressure != BackpressurePolicy::DropOldest {
+                            break;
+                        }
                     }
                     Err(RecvError::Closed) => {
                         break;
                     }
                 }
             }
+
+            // Holding the connection until here keeps it open for as long as this stream is alive
+            drop(connection);
         })
     }
 
@@ -561,3 +624,130 @@ impl SubscriptionManager {
         Ok(())
     }
 }
+
+fn market_subscription_id(asset_ids: &[U256]) -> String {
+    format!(
+        "market:{}",
+        asset_ids
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    )
+}
+
+/// A stream of market messages whose set of assets can be changed while it is open, without
+/// tearing down the WebSocket connection.
+///
+/// Created by [`Client::subscribe_market_stream`](super::Client::subscribe_market_stream). Changes
+/// are sent to the server as subscription deltas and are kept across reconnects.
+pub struct MarketStream {
+    subscriptions: Arc<SubscriptionManager>,
+    subscription_id: String,
+    custom_features: bool,
+    assets: Arc<RwLock<HashSet<U256>>>,
+    stream: Pin<Box<dyn Stream<Item = Result<WsMessage>> + Send>>,
+}
+
+impl MarketStream {
+    /// Adds `asset_ids` to this stream.
+    ///
+    /// Assets this stream already follows are ignored, so adding an asset twice does not require
+    /// removing it twice. A subscription request is only sent for assets that no other stream on
+    /// the connection follows yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription request cannot be sent.
+    pub fn subscribe_tokens(&self, asset_ids: &[U256]) -> Result<()> {
+        let added: Vec<U256> = {
+            let current = self.assets.read().unwrap_or_else(PoisonError::into_inner);
+            let mut seen = HashSet::new();
+            asset_ids
+                .iter()
+                .filter(|id| !current.contains(id) && seen.insert(**id))
+                .copied()
+                .collect()
+        };
+
+        if added.is_empty() {
+            return Ok(());
+        }
+
+        self.subscriptions
+            .add_assets(&added, self.custom_features)?;
+        self.assets
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend(added);
+        self.update_registration();
+
+        Ok(())
+    }
+
+    /// Removes `asset_ids` from this stream.
+    ///
+    /// Assets this stream does not follow are ignored. An unsubscribe request is only sent for
+    /// assets that no other stream on the connection follows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the unsubscribe request cannot be sent.
+    pub fn unsubscribe_tokens(&self, asset_ids: &[U256]) -> Result<()> {
+        let removed: Vec<U256> = {
+            let mut current = self.assets.write().unwrap_or_else(PoisonError::into_inner);
+            asset_ids
+                .iter()
+                .filter(|id| current.remove(id))
+                .copied()
+                .collect()
+        };
+
+        if removed.is_empty() {
+            return Ok(());
+        }
+
+        self.subscriptions.unsubscribe_market(&removed)?;
+        self.update_registration();
+
+        Ok(())
+    }
+
+    /// Returns the assets this stream currently follows.
+    #[must_use]
+    pub fn tokens(&self) -> Vec<U256> {
+        self.assets
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    /// Closes the connection behind this stream and stops reconnecting.
+    ///
+    /// The connection is shared by every market stream of the client, so they all end. Later
+    /// subscriptions on the client open a new connection. See
+    /// [`Client::close`](super::Client::close).
+    pub async fn close(&self) {
+        self.subscriptions.connection.close().await;
+    }
+
+    fn update_registration(&self) {
+        let tokens = self.tokens();
+        if tokens.is_empty() {
+            self.subscriptions.active_subs.remove(&self.subscription_id);
+        } else {
+            self.subscriptions
+                .register_assets(self.subscription_id.clone(), tokens);
+        }
+    }
+}
+
+impl Stream for MarketStream {
+    type Item = Result<WsMessage>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
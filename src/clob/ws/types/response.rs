@@ -7,6 +7,7 @@ use crate::auth::ApiKey;
 use crate::clob::types::{Side, TraderSide};
 use crate::clob::ws::interest::MessageInterest;
 use crate::error::Kind;
+use crate::serde_helpers::FlexibleU256;
 use crate::types::{B256, Decimal, U256};
 
 /// Top-level WebSocket message wrapper.
@@ -68,6 +69,7 @@ impl WsMessage {
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct BookUpdate {
     /// Asset/token identifier
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     /// Market condition ID
     pub market: B256,
@@ -110,9 +112,11 @@ pub struct PriceChange {
 }
 
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct PriceChangeBatchEntry {
     /// Asset/token identifier
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     /// New price
     pub price: Decimal,
@@ -138,6 +142,7 @@ pub struct PriceChangeBatchEntry {
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct TickSizeChange {
     /// Asset/token identifier
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     /// Market condition ID
     pub market: B256,
@@ -156,6 +161,7 @@ pub struct TickSizeChange {
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct LastTradePrice {
     /// Asset/token identifier
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     /// Market condition ID
     pub market: B256,
@@ -182,6 +188,7 @@ pub struct BestBidAsk {
     /// Market condition ID
     pub market: B256,
     /// Asset/token identifier
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     /// Current best bid price
     pub best_bid: Decimal,
@@ -213,6 +220,7 @@ pub struct NewMarket {
     pub description: String,
     /// List of asset IDs
     #[serde(rename = "assets_ids")]
+    #[serde_as(as = "Vec<FlexibleU256>")]
     pub asset_ids: Vec<U256>,
     /// List of outcomes (e.g., `["Yes", "No"]`)
     pub outcomes: Vec<String>,
@@ -243,10 +251,12 @@ pub struct MarketResolved {
     pub description: String,
     /// List of asset IDs
     #[serde(rename = "assets_ids")]
+    #[serde_as(as = "Vec<FlexibleU256>")]
     pub asset_ids: Vec<U256>,
     /// List of outcomes (e.g., `["Yes", "No"]`)
     pub outcomes: Vec<String>,
     /// Winning asset ID
+    #[serde_as(as = "FlexibleU256")]
     pub winning_asset_id: U256,
     /// Winning outcome (e.g., "Yes" or "No")
     pub winning_outcome: String,
@@ -276,9 +286,11 @@ pub struct EventMessage {
 
 /// Maker order details within a trade message.
 #[non_exhaustive]
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct MakerOrder {
     /// Asset/token identifier of the maker order
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     /// Amount of maker order matched in trade
     pub matched_amount: Decimal,
@@ -322,6 +334,7 @@ pub struct TradeMessage {
     /// Market condition ID
     pub market: B256,
     /// Asset/token identifier
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     /// Side of the trade
     pub side: Side,
@@ -396,6 +409,7 @@ pub struct OrderMessage {
     /// Market condition ID
     pub market: B256,
     /// Asset/token identifier
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     /// Side of the order (BUY or SELL)
     pub side: Side,
@@ -458,6 +472,7 @@ pub enum OrderStatus {
 #[derive(Debug, Clone, Deserialize, Builder)]
 pub struct MidpointUpdate {
     /// Asset/token identifier
+    #[serde_as(as = "FlexibleU256")]
     pub asset_id: U256,
     /// Market condition ID
     pub market: B256,
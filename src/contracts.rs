@@ -0,0 +1,93 @@
+//! Deployed Polymarket contract addresses.
+//!
+//! Thin accessors over [`contract_config`] for callers that only need a single address, e.g. to
+//! build on-chain split and merge transactions. Every function returns `None` for chains other
+//! than [`POLYGON`](crate::POLYGON) and [`AMOY`](crate::AMOY).
+
+use crate::contract_config;
+use crate::types::{Address, ChainId};
+
+/// Returns the CTF exchange address that orders for regular markets are signed against.
+#[must_use]
+pub fn exchange_address(chain_id: ChainId) -> Option<Address> {
+    contract_config(chain_id, false).map(|config| config.exchange)
+}
+
+/// Returns the CTF exchange address that orders for neg-risk markets are signed against.
+#[must_use]
+pub fn neg_risk_exchange_address(chain_id: ChainId) -> Option<Address> {
+    contract_config(chain_id, true).map(|config| config.exchange)
+}
+
+/// Returns the `NegRiskAdapter` address, which wraps collateral and converts positions for
+/// neg-risk markets.
+#[must_use]
+pub fn neg_risk_adapter_address(chain_id: ChainId) -> Option<Address> {
+    contract_config(chain_id, true)?.neg_risk_adapter
+}
+
+/// Returns the collateral (USDC) token address.
+#[must_use]
+pub fn collateral_address(chain_id: ChainId) -> Option<Address> {
+    contract_config(chain_id, false).map(|config| config.collateral)
+}
+
+/// Returns the Gnosis `ConditionalTokens` address, which holds every outcome token.
+#[must_use]
+pub fn conditional_tokens_address(chain_id: ChainId) -> Option<Address> {
+    contract_config(chain_id, false).map(|config| config.conditional_tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::address;
+    use crate::{AMOY, POLYGON};
+
+    #[test]
+    fn polygon_addresses() {
+        assert_eq!(
+            exchange_address(POLYGON),
+            Some(address!("0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"))
+        );
+        assert_eq!(
+            neg_risk_exchange_address(POLYGON),
+            Some(address!("0xC5d563A36AE78145C45a50134d48A1215220f80a"))
+        );
+        assert_eq!(
+            neg_risk_adapter_address(POLYGON),
+            Some(address!("0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296"))
+        );
+        assert_eq!(
+            collateral_address(POLYGON),
+            Some(address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174"))
+        );
+        assert_eq!(
+            conditional_tokens_address(POLYGON),
+            Some(address!("0x4D97DCd97eC945f40cF65F87097ACe5EA0476045"))
+        );
+    }
+
+    #[test]
+    fn amoy_addresses() {
+        assert_eq!(
+            exchange_address(AMOY),
+            Some(address!("0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40"))
+        );
+        assert_eq!(
+            collateral_address(AMOY),
+            Some(address!("0x9c4e1703476e875070ee25b56a58b008cfb8fa78"))
+        );
+        assert_eq!(
+            conditional_tokens_address(AMOY),
+            Some(address!("0x69308FB512518e39F9b16112fA8d994F4e2Bf8bB"))
+        );
+    }
+
+    #[test]
+    fn unknown_chain_should_have_no_addresses() {
+        assert_eq!(exchange_address(1), None);
+        assert_eq!(neg_risk_adapter_address(1), None);
+        assert_eq!(conditional_tokens_address(1), None);
+    }
+}
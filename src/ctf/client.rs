@@ -8,6 +8,7 @@
 //! - **Split**: Convert USDC collateral into outcome token pairs (YES/NO)
 //! - **Merge**: Combine outcome token pairs back into USDC
 //! - **Redeem**: Redeem winning outcome tokens after market resolution
+//! - **Convert**: Convert NO positions of a neg-risk market into YES positions and USDC
 //!
 //! # Example
 //!
@@ -38,10 +39,12 @@ use alloy::sol;
 use super::error::CtfError;
 use super::types::{
     CollectionIdRequest, CollectionIdResponse, ConditionIdRequest, ConditionIdResponse,
-    MergePositionsRequest, MergePositionsResponse, PositionIdRequest, PositionIdResponse,
-    RedeemNegRiskRequest, RedeemNegRiskResponse, RedeemPositionsRequest, RedeemPositionsResponse,
-    SplitPositionRequest, SplitPositionResponse,
+    ConvertPositionsRequest, ConvertPositionsResponse, MergePositionsRequest,
+    MergePositionsResponse, PositionIdRequest, PositionIdResponse, RedeemNegRiskRequest,
+    RedeemNegRiskResponse, RedeemPositionsRequest, RedeemPositionsResponse, SplitPositionRequest,
+    SplitPositionResponse,
 };
+use crate::error::Error;
 use crate::{Result, contract_config};
 
 // CTF (Conditional Token Framework) contract interface
@@ -121,6 +124,13 @@ sol! {
             bytes32 conditionId,
             uint256[] calldata amounts
         ) external;
+
+        /// Converts NO positions of a set of questions into YES positions of the others plus collateral.
+        function convertPositions(
+            bytes32 marketId,
+            uint256 indexSet,
+            uint256 amount
+        ) external;
     }
 }
 
@@ -492,6 +502,67 @@ impl<P: Provider + Clone> Client<P> {
         })
     }
 
+    /// Converts NO positions of a neg-risk market into YES positions of its other questions plus
+    /// collateral, using the `NegRisk` adapter. See [`ConvertPositionsRequest`] for what is burned
+    /// and minted.
+    ///
+    /// The wallet must have approved the `NegRisk` adapter for its outcome tokens with
+    /// `ConditionalTokens.setApprovalForAll`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The client was not created with `with_neg_risk()` (adapter not available)
+    /// - The index set selects no questions
+    /// - The transaction fails to send
+    /// - The transaction fails to be mined
+    /// - The wallet doesn't hold `amount` NO tokens of every selected question
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), fields(
+            market_id = %request.market_id,
+            index_set = %request.index_set,
+            amount = %request.amount
+        ))
+    )]
+    pub async fn convert_positions(
+        &self,
+        request: &ConvertPositionsRequest,
+    ) -> Result<ConvertPositionsResponse> {
+        let adapter = self.neg_risk_adapter.as_ref().ok_or_else(|| {
+            CtfError::ContractCall(
+                "NegRisk adapter not available. Use Client::with_neg_risk() to enable NegRisk support".to_owned()
+            )
+        })?;
+
+        if request.index_set.is_zero() {
+            return Err(Error::validation(
+                "Unable to convert positions: index set selects no questions",
+            ));
+        }
+
+        let pending_tx = adapter
+            .convertPositions(request.market_id, request.index_set, request.amount)
+            .send()
+            .await
+            .map_err(|e| {
+                CtfError::ContractCall(format!("Failed to send NegRisk convert transaction: {e}"))
+            })?;
+
+        let transaction_hash = *pending_tx.tx_hash();
+
+        let receipt = pending_tx.get_receipt().await.map_err(|e| {
+            CtfError::ContractCall(format!("Failed to get NegRisk convert receipt: {e}"))
+        })?;
+
+        Ok(ConvertPositionsResponse {
+            transaction_hash,
+            block_number: receipt.block_number.ok_or_else(|| {
+                CtfError::ContractCall("Block number not available in receipt".to_owned())
+            })?,
+        })
+    }
+
     /// Returns a reference to the underlying provider.
     #[must_use]
     pub const fn provider(&self) -> &P {
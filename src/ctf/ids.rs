@@ -0,0 +1,244 @@
+//! Offline calculation of CTF collection and position (ERC1155 token) IDs.
+//!
+//! These functions mirror `CTHelpers` from the Gnosis conditional tokens contracts, which encode
+//! collection IDs as compressed points on the `alt_bn128` curve so that they can be combined
+//! with elliptic curve addition. Computing them locally avoids a round trip to the contract (or
+//! an API) when mapping a market's condition ID to its CLOB token IDs.
+
+use alloy::primitives::{B256, U256, keccak256, uint};
+
+use super::types::BINARY_PARTITION;
+use crate::Result;
+use crate::error::Error;
+use crate::types::Address;
+
+/// Modulus of the `alt_bn128` base field.
+const P: U256 = uint!(0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47_U256);
+
+/// Constant term of the `alt_bn128` curve equation `y^2 = x^3 + 3`.
+const B: U256 = uint!(3_U256);
+
+/// Calculates a collection ID the same way `ConditionalTokens.getCollectionId` does.
+///
+/// Use [`B256::ZERO`] as the `parent_collection_id` for top-level positions, which is what every
+/// Polymarket market uses.
+///
+/// # Errors
+///
+/// Returns an error if `parent_collection_id` is not a valid collection ID.
+pub fn collection_id(
+    parent_collection_id: B256,
+    condition_id: B256,
+    index_set: U256,
+) -> Result<B256> {
+    let mut point = base_point(condition_id, index_set);
+
+    if !parent_collection_id.is_zero() {
+        let parent = decompress(parent_collection_id).ok_or_else(|| {
+            Error::validation(format!(
+                "Invalid parent collection ID: {parent_collection_id}"
+            ))
+        })?;
+        point = add(point, parent);
+    }
+
+    Ok(compress(point))
+}
+
+/// Calculates a position ID (ERC1155 token ID) the same way `ConditionalTokens.getPositionId`
+/// does, starting from the condition ID and index set of the position.
+///
+/// # Errors
+///
+/// Returns an error if `parent_collection_id` is not a valid collection ID.
+pub fn position_id(
+    collateral_token: Address,
+    parent_collection_id: B256,
+    condition_id: B256,
+    index_set: U256,
+) -> Result<U256> {
+    let collection_id = collection_id(parent_collection_id, condition_id, index_set)?;
+
+    Ok(position_id_for_collection(collateral_token, collection_id))
+}
+
+/// Returns the token IDs of both outcomes of a binary market, in outcome index order (typically
+/// `[YES, NO]`).
+///
+/// `collateral_token` is the token backing the positions: USDC
+/// ([`ContractConfig::collateral`](crate::ContractConfig::collateral)) for regular markets, and the
+/// `NegRiskAdapter`'s wrapped collateral for neg-risk markets.
+#[must_use]
+pub fn outcome_token_ids(collateral_token: Address, condition_id: B256) -> [U256; 2] {
+    BINARY_PARTITION.map(|index_set| {
+        let collection_id = compress(base_point(condition_id, U256::from(index_set)));
+        position_id_for_collection(collateral_token, collection_id)
+    })
+}
+
+fn position_id_for_collection(collateral_token: Address, collection_id: B256) -> U256 {
+    let mut preimage = [0_u8; 52];
+    preimage[..20].copy_from_slice(collateral_token.as_slice());
+    preimage[20..].copy_from_slice(collection_id.as_slice());
+
+    U256::from_be_bytes(keccak256(preimage).0)
+}
+
+/// Hashes the condition ID and index set onto the curve, keeping the parity of the hash's most
+/// significant bit as the parity of `y`.
+fn base_point(condition_id: B256, index_set: U256) -> (U256, U256) {
+    let mut preimage = [0_u8; 64];
+    preimage[..32].copy_from_slice(condition_id.as_slice());
+    preimage[32..].copy_from_slice(&index_set.to_be_bytes::<32>());
+
+    let mut x = U256::from_be_bytes(keccak256(preimage).0);
+    let odd = x.bit(255);
+
+    let y = loop {
+        x = x.add_mod(U256::from(1), P);
+        let yy = curve(x);
+        let y = sqrt(yy);
+        if y.mul_mod(y, P) == yy {
+            break y;
+        }
+    };
+
+    (x, with_parity(y, odd))
+}
+
+/// Recovers the curve point encoded in a collection ID, or `None` if it is not on the curve.
+fn decompress(collection_id: B256) -> Option<(U256, U256)> {
+    let raw = U256::from_be_bytes(collection_id.0);
+    let odd = raw.bit(254);
+    let x = (raw << 2) >> 2;
+    if x >= P {
+        return None;
+    }
+
+    let yy = curve(x);
+    let y = with_parity(sqrt(yy), odd);
+
+    (y.mul_mod(y, P) == yy).then_some((x, y))
+}
+
+/// Encodes a curve point as its `x` coordinate, with the parity of `y` stored in bit 254.
+fn compress((mut x, y): (U256, U256)) -> B256 {
+    if y.bit(0) {
+        x ^= U256::from(1) << 254;
+    }
+
+    B256::from(x)
+}
+
+/// Affine point addition, matching the `ecAdd` precompile (which represents the point at
+/// infinity as `(0, 0)`).
+fn add((x1, y1): (U256, U256), (x2, y2): (U256, U256)) -> (U256, U256) {
+    if (x1, y1) == (U256::ZERO, U256::ZERO) {
+        return (x2, y2);
+    }
+    if (x2, y2) == (U256::ZERO, U256::ZERO) {
+        return (x1, y1);
+    }
+
+    let slope = if x1 == x2 {
+        if y1 != y2 || y1.is_zero() {
+            return (U256::ZERO, U256::ZERO);
+        }
+        x1.mul_mod(x1, P)
+            .mul_mod(B, P)
+            .mul_mod(inverse(y1.add_mod(y1, P)), P)
+    } else {
+        sub(y2, y1).mul_mod(inverse(sub(x2, x1)), P)
+    };
+
+    let x3 = sub(sub(slope.mul_mod(slope, P), x1), x2);
+    let y3 = sub(slope.mul_mod(sub(x1, x3), P), y1);
+
+    (x3, y3)
+}
+
+/// `x^3 + 3 (mod P)`
+fn curve(x: U256) -> U256 {
+    x.mul_mod(x, P).mul_mod(x, P).add_mod(B, P)
+}
+
+/// Square root modulo `P`, which is valid when the input is a quadratic residue since
+/// `P = 3 (mod 4)`.
+fn sqrt(value: U256) -> U256 {
+    value.pow_mod((P + U256::from(1)) >> 2, P)
+}
+
+/// Modular inverse via Fermat's little theorem, since `P` is prime.
+fn inverse(value: U256) -> U256 {
+    value.pow_mod(P - U256::from(2), P)
+}
+
+fn sub(lhs: U256, rhs: U256) -> U256 {
+    lhs.add_mod(P - rhs, P)
+}
+
+fn with_parity(y: U256, odd: bool) -> U256 {
+    if y.bit(0) == odd { y } else { P - y }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{address, b256};
+
+    use super::*;
+
+    /// `NegRiskAdapter` wrapped collateral, which backs the outcome tokens of neg-risk markets.
+    const WRAPPED_COLLATERAL: Address = address!("0x3A3BD7bb9528E159577F7C2e685CC81A765002E2");
+    /// "Will Donald Trump win the 2024 US Presidential Election?"
+    const CONDITION_ID: B256 =
+        b256!("0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917");
+
+    #[test]
+    fn outcome_token_ids_should_match_onchain_token_ids() {
+        let [yes, no] = outcome_token_ids(WRAPPED_COLLATERAL, CONDITION_ID);
+
+        assert_eq!(
+            yes,
+            uint!(
+                21742633143463906290569050155826241533067272736897614950488156847949938836455_U256
+            )
+        );
+        assert_eq!(
+            no,
+            uint!(
+                48331043336612883890938759509493159234755048973500640148014422747788308965732_U256
+            )
+        );
+    }
+
+    #[test]
+    fn position_id_should_match_outcome_token_ids() {
+        let [yes, no] = outcome_token_ids(WRAPPED_COLLATERAL, CONDITION_ID);
+
+        assert_eq!(
+            position_id(WRAPPED_COLLATERAL, B256::ZERO, CONDITION_ID, U256::from(1)).unwrap(),
+            yes
+        );
+        assert_eq!(
+            position_id(WRAPPED_COLLATERAL, B256::ZERO, CONDITION_ID, U256::from(2)).unwrap(),
+            no
+        );
+    }
+
+    #[test]
+    fn nested_collection_id_should_decompress() {
+        let parent = collection_id(B256::ZERO, CONDITION_ID, U256::from(1)).unwrap();
+        let nested = collection_id(parent, CONDITION_ID, U256::from(2)).unwrap();
+
+        assert!(decompress(parent).is_some());
+        assert!(decompress(nested).is_some());
+        assert_ne!(parent, nested);
+    }
+
+    #[test]
+    fn invalid_parent_collection_id_should_fail() {
+        let result = collection_id(B256::repeat_byte(0xff), CONDITION_ID, U256::from(1));
+
+        result.unwrap_err();
+    }
+}
@@ -12,6 +12,7 @@
 //! - **Splitting**: Convert USDC collateral into outcome token pairs (YES/NO)
 //! - **Merging**: Combine outcome token pairs back into USDC
 //! - **Redemption**: Redeem winning outcome tokens after market resolution
+//! - **Transaction building**: Build unsigned split/merge/redeem/convert transactions with [`tx`]
 //!
 //! # Example
 //!
@@ -65,6 +66,9 @@
 
 pub mod client;
 mod error;
+mod ids;
+pub mod tx;
 pub mod types;
 
 pub use client::Client;
+pub use ids::{collection_id, outcome_token_ids, position_id};
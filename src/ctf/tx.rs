@@ -0,0 +1,277 @@
+//! Unsigned transaction builders for CTF operations.
+//!
+//! Each function ABI-encodes the call and addresses it to the contract deployed on `chain_id`,
+//! returning a [`TransactionRequest`] that can be signed and sent with any alloy provider. Nothing
+//! is broadcast, so no provider or wallet is needed to build them. Use [`Client`](super::Client)
+//! instead to send the transaction and wait for its receipt in one step.
+//!
+//! Splitting requires the `ConditionalTokens` contract to be approved to spend the collateral,
+//! and neg-risk redemption and conversion require the `NegRiskAdapter` to be approved for the
+//! outcome tokens (`ConditionalTokens.setApprovalForAll`).
+
+use alloy::primitives::Address;
+use alloy::rpc::types::TransactionRequest;
+use alloy::sol_types::SolCall as _;
+
+use super::client::{IConditionalTokens, INegRiskAdapter};
+use super::types::{
+    ConvertPositionsRequest, MergePositionsRequest, RedeemNegRiskRequest, RedeemPositionsRequest,
+    SplitPositionRequest,
+};
+use crate::Result;
+use crate::contracts::{conditional_tokens_address, neg_risk_adapter_address};
+use crate::error::Error;
+use crate::types::ChainId;
+
+/// Builds a `ConditionalTokens.splitPosition` transaction, converting collateral into a full
+/// set of outcome tokens.
+///
+/// # Errors
+///
+/// Returns an error if the `ConditionalTokens` contract is not known for `chain_id`.
+pub fn split_position(
+    chain_id: ChainId,
+    request: &SplitPositionRequest,
+) -> Result<TransactionRequest> {
+    let call = IConditionalTokens::splitPositionCall {
+        collateralToken: request.collateral_token,
+        parentCollectionId: request.parent_collection_id,
+        conditionId: request.condition_id,
+        partition: request.partition.clone(),
+        amount: request.amount,
+    };
+
+    Ok(transaction(
+        conditional_tokens(chain_id)?,
+        call.abi_encode(),
+    ))
+}
+
+/// Builds a `ConditionalTokens.mergePositions` transaction, converting a full set of outcome
+/// tokens back into collateral.
+///
+/// # Errors
+///
+/// Returns an error if the `ConditionalTokens` contract is not known for `chain_id`.
+pub fn merge_positions(
+    chain_id: ChainId,
+    request: &MergePositionsRequest,
+) -> Result<TransactionRequest> {
+    let call = IConditionalTokens::mergePositionsCall {
+        collateralToken: request.collateral_token,
+        parentCollectionId: request.parent_collection_id,
+        conditionId: request.condition_id,
+        partition: request.partition.clone(),
+        amount: request.amount,
+    };
+
+    Ok(transaction(
+        conditional_tokens(chain_id)?,
+        call.abi_encode(),
+    ))
+}
+
+/// Builds a `ConditionalTokens.redeemPositions` transaction, burning winning outcome tokens of a
+/// resolved condition for collateral.
+///
+/// # Errors
+///
+/// Returns an error if the `ConditionalTokens` contract is not known for `chain_id`.
+pub fn redeem_positions(
+    chain_id: ChainId,
+    request: &RedeemPositionsRequest,
+) -> Result<TransactionRequest> {
+    let call = IConditionalTokens::redeemPositionsCall {
+        collateralToken: request.collateral_token,
+        parentCollectionId: request.parent_collection_id,
+        conditionId: request.condition_id,
+        indexSets: request.index_sets.clone(),
+    };
+
+    Ok(transaction(
+        conditional_tokens(chain_id)?,
+        call.abi_encode(),
+    ))
+}
+
+/// Builds a `NegRiskAdapter.redeemPositions` transaction for a resolved neg-risk market.
+///
+/// # Errors
+///
+/// Returns an error if the `NegRiskAdapter` contract is not known for `chain_id`.
+pub fn redeem_neg_risk(
+    chain_id: ChainId,
+    request: &RedeemNegRiskRequest,
+) -> Result<TransactionRequest> {
+    let call = INegRiskAdapter::redeemPositionsCall {
+        conditionId: request.condition_id,
+        amounts: request.amounts.clone(),
+    };
+
+    Ok(transaction(neg_risk_adapter(chain_id)?, call.abi_encode()))
+}
+
+/// Builds a `NegRiskAdapter.convertPositions` transaction for a neg-risk market.
+///
+/// The sender must hold `amount` NO tokens of every question in the request's index set, and
+/// must have approved the `NegRiskAdapter` for its outcome tokens with
+/// `ConditionalTokens.setApprovalForAll`. Markets with a conversion fee keep part of the
+/// collateral and YES tokens that are paid out.
+///
+/// # Errors
+///
+/// Returns an error if the `NegRiskAdapter` contract is not known for `chain_id`, or if the index
+/// set is empty.
+pub fn convert_positions(
+    chain_id: ChainId,
+    request: &ConvertPositionsRequest,
+) -> Result<TransactionRequest> {
+    if request.index_set.is_zero() {
+        return Err(Error::validation(
+            "Unable to convert positions: index set selects no questions",
+        ));
+    }
+
+    let call = INegRiskAdapter::convertPositionsCall {
+        marketId: request.market_id,
+        indexSet: request.index_set,
+        amount: request.amount,
+    };
+
+    Ok(transaction(neg_risk_adapter(chain_id)?, call.abi_encode()))
+}
+
+fn conditional_tokens(chain_id: ChainId) -> Result<Address> {
+    conditional_tokens_address(chain_id).ok_or_else(|| {
+        Error::validation(format!(
+            "ConditionalTokens contract not found for chain ID {chain_id}"
+        ))
+    })
+}
+
+fn neg_risk_adapter(chain_id: ChainId) -> Result<Address> {
+    neg_risk_adapter_address(chain_id).ok_or_else(|| {
+        Error::validation(format!(
+            "NegRiskAdapter contract not found for chain ID {chain_id}"
+        ))
+    })
+}
+
+fn transaction(to: Address, input: Vec<u8>) -> TransactionRequest {
+    TransactionRequest::default().to(to).input(input.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{B256, U256, b256};
+
+    use super::*;
+    use crate::contracts::collateral_address;
+    use crate::{AMOY, POLYGON};
+
+    const CONDITION_ID: B256 =
+        b256!("0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917");
+
+    fn calldata(tx: &TransactionRequest) -> &[u8] {
+        tx.input.input().expect("transaction has calldata")
+    }
+
+    #[test]
+    fn split_position_should_encode_binary_split() {
+        let collateral = collateral_address(POLYGON).unwrap();
+        let request = SplitPositionRequest::for_binary_market(
+            collateral,
+            CONDITION_ID,
+            U256::from(1_000_000),
+        );
+
+        let tx = split_position(POLYGON, &request).unwrap();
+
+        assert_eq!(
+            tx.to,
+            Some(conditional_tokens_address(POLYGON).unwrap().into())
+        );
+        let call = IConditionalTokens::splitPositionCall::abi_decode(calldata(&tx)).unwrap();
+        assert_eq!(call.collateralToken, collateral);
+        assert_eq!(call.parentCollectionId, B256::ZERO);
+        assert_eq!(call.conditionId, CONDITION_ID);
+        assert_eq!(call.partition, [U256::from(1), U256::from(2)]);
+        assert_eq!(call.amount, U256::from(1_000_000));
+    }
+
+    #[test]
+    fn merge_positions_should_encode_binary_merge() {
+        let collateral = collateral_address(AMOY).unwrap();
+        let request =
+            MergePositionsRequest::for_binary_market(collateral, CONDITION_ID, U256::from(5));
+
+        let tx = merge_positions(AMOY, &request).unwrap();
+
+        assert_eq!(
+            tx.to,
+            Some(conditional_tokens_address(AMOY).unwrap().into())
+        );
+        let call = IConditionalTokens::mergePositionsCall::abi_decode(calldata(&tx)).unwrap();
+        assert_eq!(call.conditionId, CONDITION_ID);
+        assert_eq!(call.amount, U256::from(5));
+    }
+
+    #[test]
+    fn redeem_positions_should_encode_index_sets() {
+        let collateral = collateral_address(POLYGON).unwrap();
+        let request = RedeemPositionsRequest::for_binary_market(collateral, CONDITION_ID);
+
+        let tx = redeem_positions(POLYGON, &request).unwrap();
+
+        let call = IConditionalTokens::redeemPositionsCall::abi_decode(calldata(&tx)).unwrap();
+        assert_eq!(call.indexSets, [U256::from(1), U256::from(2)]);
+    }
+
+    #[test]
+    fn redeem_neg_risk_should_target_adapter() {
+        let request = RedeemNegRiskRequest::builder()
+            .condition_id(CONDITION_ID)
+            .amounts(vec![U256::from(3), U256::ZERO])
+            .build();
+
+        let tx = redeem_neg_risk(POLYGON, &request).unwrap();
+
+        assert_eq!(
+            tx.to,
+            Some(neg_risk_adapter_address(POLYGON).unwrap().into())
+        );
+        let call = INegRiskAdapter::redeemPositionsCall::abi_decode(calldata(&tx)).unwrap();
+        assert_eq!(call.amounts, [U256::from(3), U256::ZERO]);
+    }
+
+    #[test]
+    fn convert_positions_should_encode_index_set() {
+        let market_id = b256!("0xe3b1bc389210504ebcb9cffe4b0ed06ccac50561e0f24abb6379984cec030f00");
+        let request = ConvertPositionsRequest::for_questions(market_id, &[0, 3], U256::from(7));
+
+        let tx = convert_positions(POLYGON, &request).unwrap();
+
+        assert_eq!(
+            tx.to,
+            Some(neg_risk_adapter_address(POLYGON).unwrap().into())
+        );
+        let call = INegRiskAdapter::convertPositionsCall::abi_decode(calldata(&tx)).unwrap();
+        assert_eq!(call.marketId, market_id);
+        assert_eq!(call.indexSet, U256::from(0b1001));
+        assert_eq!(call.amount, U256::from(7));
+    }
+
+    #[test]
+    fn convert_positions_with_empty_index_set_should_fail() {
+        let request = ConvertPositionsRequest::for_questions(B256::ZERO, &[], U256::from(1));
+
+        convert_positions(POLYGON, &request).unwrap_err();
+    }
+
+    #[test]
+    fn unknown_chain_should_fail() {
+        let request = RedeemPositionsRequest::for_binary_market(Address::ZERO, CONDITION_ID);
+
+        redeem_positions(1, &request).unwrap_err();
+    }
+}
@@ -4,10 +4,11 @@ mod request;
 mod response;
 
 pub use request::{
-    BINARY_PARTITION, CollectionIdRequest, ConditionIdRequest, MergePositionsRequest,
-    PositionIdRequest, RedeemNegRiskRequest, RedeemPositionsRequest, SplitPositionRequest,
+    BINARY_PARTITION, CollectionIdRequest, ConditionIdRequest, ConvertPositionsRequest,
+    MergePositionsRequest, PositionIdRequest, RedeemNegRiskRequest, RedeemPositionsRequest,
+    SplitPositionRequest,
 };
 pub use response::{
-    CollectionIdResponse, ConditionIdResponse, MergePositionsResponse, PositionIdResponse,
-    RedeemNegRiskResponse, RedeemPositionsResponse, SplitPositionResponse,
+    CollectionIdResponse, ConditionIdResponse, ConvertPositionsResponse, MergePositionsResponse,
+    PositionIdResponse, RedeemNegRiskResponse, RedeemPositionsResponse, SplitPositionResponse,
 };
@@ -121,6 +121,23 @@ pub struct RedeemNegRiskRequest {
     pub amounts: Vec<U256>,
 }
 
+/// Request to convert NO positions of a neg-risk market using the `NegRisk` adapter.
+///
+/// Converting burns `amount` NO tokens of every question in `index_set`, and in return mints
+/// `amount` YES tokens of every other question in the market plus `amount * (n - 1)` collateral,
+/// where `n` is the number of questions converted.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct ConvertPositionsRequest {
+    /// The neg-risk market ID (`negRiskMarketID` in Gamma and CLOB market responses)
+    pub market_id: B256,
+    /// Bitmask of the questions whose NO positions are converted, where bit `i` selects the
+    /// question with index `i` (the last byte of its question ID)
+    pub index_set: U256,
+    /// Amount of NO tokens to convert for each selected question
+    pub amount: U256,
+}
+
 // Convenience methods for binary markets
 impl SplitPositionRequest {
     /// Creates a split request for a binary market (YES/NO).
@@ -206,3 +223,30 @@ impl RedeemPositionsRequest {
         }
     }
 }
+
+impl ConvertPositionsRequest {
+    /// Creates a convert request from the indices of the questions to convert, building
+    /// [`Self::index_set`] from them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_client_sdk::ctf::types::ConvertPositionsRequest;
+    /// # use alloy::primitives::{B256, U256};
+    /// // Convert 1 NO token of questions 0 and 2
+    /// let request = ConvertPositionsRequest::for_questions(B256::default(), &[0, 2], U256::from(1_000_000));
+    /// assert_eq!(request.index_set, U256::from(0b101));
+    /// ```
+    #[must_use]
+    pub fn for_questions(market_id: B256, question_indices: &[u8], amount: U256) -> Self {
+        let index_set = question_indices
+            .iter()
+            .fold(U256::ZERO, |set, &index| set | (U256::from(1) << index));
+
+        Self {
+            market_id,
+            index_set,
+            amount,
+        }
+    }
+}
@@ -66,3 +66,13 @@ pub struct RedeemNegRiskResponse {
     /// Block number where the transaction was mined
     pub block_number: u64,
 }
+
+/// Response from a `NegRisk` convert transaction.
+#[non_exhaustive]
+#[derive(Debug, Clone, Builder)]
+pub struct ConvertPositionsResponse {
+    /// Transaction hash
+    pub transaction_hash: B256,
+    /// Block number where the transaction was mined
+    pub block_number: u64,
+}
@@ -24,14 +24,17 @@
 //! # }
 //! ```
 
-use reqwest::{
-    Client as ReqwestClient, Method,
-    header::{HeaderMap, HeaderValue},
-};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use async_stream::{stream, try_stream};
+use futures::Stream;
+use reqwest::{Client as ReqwestClient, Method};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use url::Url;
 
+use super::types::ApiVersion;
 use super::types::request::{
     ActivityRequest, BuilderLeaderboardRequest, BuilderVolumeRequest, ClosedPositionsRequest,
     HoldersRequest, LiveVolumeRequest, OpenInterestRequest, PositionsRequest, TradedRequest,
@@ -41,7 +44,22 @@ use super::types::response::{
     Activity, BuilderLeaderboardEntry, BuilderVolumeEntry, ClosedPosition, Health, LiveVolume,
     MetaHolder, OpenInterest, Position, Trade, Traded, TraderLeaderboardEntry, Value,
 };
-use crate::{Result, ToQueryParams as _};
+use crate::error::Error;
+use crate::response::Response;
+use crate::types::{Address, B256, Decimal};
+use crate::{DEFAULT_USER_AGENT, Result, ToQueryParams as _};
+
+/// Largest `offset` the `/trades` endpoint accepts.
+const MAX_TRADES_OFFSET: i32 = 10_000;
+
+/// Page size used by [`Client::trades_all`] when the request doesn't set a `limit`.
+const DEFAULT_TRADES_PAGE_SIZE: i32 = 500;
+
+/// Largest `offset` the `/positions` endpoint accepts.
+const MAX_POSITIONS_OFFSET: i32 = 10_000;
+
+/// Page size used by [`Client::portfolio_value_by_market`], the most `/positions` returns at once.
+const POSITIONS_PAGE_SIZE: i32 = 500;
 
 /// HTTP client for the Polymarket Data API.
 ///
@@ -64,9 +82,15 @@ use crate::{Result, ToQueryParams as _};
 /// let client = Client::new("https://custom-api.example.com").unwrap();
 /// ```
 #[derive(Clone, Debug)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "`client` is the underlying reqwest client"
+)]
 pub struct Client {
     host: Url,
     client: ReqwestClient,
+    api_version: ApiVersion,
+    allow_non_ok_health: bool,
 }
 
 impl Default for Client {
@@ -87,37 +111,104 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
-        let mut headers = HeaderMap::new();
-
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
-        headers.insert("Accept", HeaderValue::from_static("*/*"));
-        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
-        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
-
         Ok(Self {
             host: Url::parse(host)?,
-            client,
+            client: crate::http_client(DEFAULT_USER_AGENT)?,
+            api_version: ApiVersion::default(),
+            allow_non_ok_health: false,
         })
     }
 
+    /// Sets the `User-Agent` header sent with every request, replacing the default
+    /// [`DEFAULT_USER_AGENT`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `user_agent` is not a valid header value or the HTTP client cannot be
+    /// created.
+    pub fn with_user_agent(mut self, user_agent: &str) -> Result<Self> {
+        self.client = crate::http_client(user_agent)?;
+        Ok(self)
+    }
+
+    /// Sets the [`ApiVersion`] prefixed to the paths of versioned endpoints, such as
+    /// [`Self::leaderboard`], replacing the default [`ApiVersion::V1`].
+    #[must_use]
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Sets whether [`Self::health`] returns whatever status the health check reports. By default
+    /// any status other than "OK", such as a maintenance notice served with `200`, is reported as
+    /// an error of kind [`ServiceUnavailable`](crate::error::Kind::ServiceUnavailable).
+    #[must_use]
+    pub fn with_allow_non_ok_health(mut self, allow: bool) -> Self {
+        self.allow_non_ok_health = allow;
+        self
+    }
+
     /// Returns the base URL of the API.
     #[must_use]
     pub fn host(&self) -> &Url {
         &self.host
     }
 
-    async fn get<Req: Serialize, Res: DeserializeOwned>(
+    /// Returns the underlying HTTP client, for sending other requests through the same connection
+    /// pool and TLS configuration.
+    ///
+    /// Cloning a [`reqwest::Client`] is cheap and shares the pool.
+    #[must_use]
+    pub fn http_client(&self) -> &ReqwestClient {
+        &self.client
+    }
+
+    /// Like [`Self::get_raw`], but returns the body together with the HTTP status and rate limit
+    /// headers. See [raw requests](crate::response#raw-requests).
+    pub async fn get_with_meta<Req: Serialize, Res: DeserializeOwned>(
         &self,
         path: &str,
         req: &Req,
-    ) -> Result<Res> {
+    ) -> Result<Response<Res>> {
+        let path = path.trim_start_matches('/');
         let query = req.query_params(None);
         let request = self
             .client
             .request(Method::GET, format!("{}{path}{query}", self.host))
             .build()?;
-        crate::request(&self.client, request, None).await
+        crate::request_with_meta(&self.client, request, None).await
+    }
+
+    /// Sends a GET request to an endpoint this client does not wrap yet and deserializes the body
+    /// into `Res`.
+    ///
+    /// See [raw requests](crate::response#raw-requests) for how the request is sent and the
+    /// stability caveats.
+    pub async fn get_raw<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &Req,
+    ) -> Result<Res> {
+        self.get_with_meta(path, query)
+            .await
+            .map(Response::into_data)
+    }
+
+    async fn get<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        req: &Req,
+    ) -> Result<Res> {
+        self.get_with_meta(path, req).await.map(Response::into_data)
+    }
+
+    /// Like [`Self::get`] for endpoints whose `path` lives under the configured [`ApiVersion`].
+    async fn get_versioned<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        req: &Req,
+    ) -> Result<Res> {
+        self.get(&format!("{}/{path}", self.api_version), req).await
     }
 
     /// Performs a health check on the API.
@@ -126,9 +217,17 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails or the API returns an error response.
+    /// Returns an error if the request fails or the API returns an error response. Unless
+    /// [`Self::with_allow_non_ok_health`] is set, a successful response whose status is anything
+    /// other than "OK" is reported as an error of kind
+    /// [`ServiceUnavailable`](crate::error::Kind::ServiceUnavailable).
     pub async fn health(&self) -> Result<Health> {
-        self.get("", &()).await
+        let health: Health = self.get("", &()).await?;
+        if self.allow_non_ok_health {
+            return Ok(health);
+        }
+
+        crate::ensure_ok(health.data).map(|data| Health { data })
     }
 
     /// Fetches current (open) positions for a user.
@@ -153,6 +252,46 @@ impl Client {
         self.get("trades", req).await
     }
 
+    /// Streams every trade matching `req`, paging through `/trades` with `offset`.
+    ///
+    /// The request's `limit` is used as the page size (500 if unset) and its `offset` is ignored.
+    /// Paging stops at the first short page, or once the API's maximum offset of 10,000 is
+    /// reached.
+    ///
+    /// # Errors
+    ///
+    /// Yields an error if any page request fails, after which the stream ends.
+    pub fn trades_all(&self, req: &TradesRequest) -> impl Stream<Item = Result<Trade>> + '_ {
+        let mut req = req.clone();
+        let limit = req.limit.unwrap_or(DEFAULT_TRADES_PAGE_SIZE);
+
+        try_stream! {
+            let mut offset = 0;
+
+            loop {
+                req.limit = Some(limit);
+                req.offset = Some(offset);
+                let trades: Vec<Trade> = self.get("trades", &req).await?;
+
+                #[expect(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_possible_wrap,
+                    reason = "A page never holds more than 10,000 trades"
+                )]
+                let count = trades.len() as i32;
+
+                for trade in trades {
+                    yield trade;
+                }
+
+                offset += limit;
+                if count < limit || limit == 0 || offset > MAX_TRADES_OFFSET {
+                    break;
+                }
+            }
+        }
+    }
+
     /// Fetches on-chain activity for a user.
     ///
     /// Returns various on-chain operations including trades, splits, merges,
@@ -160,8 +299,17 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails or the API returns an error response.
+    /// Returns an error if `end` is before `start`, or if the request fails or the API returns an
+    /// error response.
     pub async fn activity(&self, req: &ActivityRequest) -> Result<Vec<Activity>> {
+        if let (Some(start), Some(end)) = (req.start, req.end)
+            && start > end
+        {
+            return Err(Error::validation(format!(
+                "Unable to fetch activity: start {start} is after end {end}"
+            )));
+        }
+
         self.get("activity", req).await
     }
 
@@ -187,6 +335,61 @@ impl Client {
         self.get("value", req).await
     }
 
+    /// Fetches the total USD value of all of `user`'s positions, or zero if they hold none.
+    ///
+    /// This sums the entries [`Self::value`] returns without a market filter. Use
+    /// [`Self::portfolio_value_by_market`] for a per-market breakdown.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the API returns an error response.
+    pub async fn portfolio_value(&self, user: Address) -> Result<Decimal> {
+        let request = ValueRequest::builder().user(user).build();
+        let values = self.value(&request).await?;
+
+        Ok(values.iter().map(|value| value.value).sum())
+    }
+
+    /// Fetches the current USD value of `user`'s positions in each market, keyed by condition
+    /// ID. Markets where the user holds nothing are absent, so an empty portfolio yields an
+    /// empty map.
+    ///
+    /// The values are summed from [`Self::positions`], paging through every position including
+    /// those below the default size threshold. Paging stops once the API's maximum offset of
+    /// 10,000 is reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any page request fails or the API returns an error response.
+    pub async fn portfolio_value_by_market(&self, user: Address) -> Result<HashMap<B256, Decimal>> {
+        let mut request = PositionsRequest::builder()
+            .user(user)
+            .size_threshold(Decimal::ZERO)
+            .build();
+        request.limit = Some(POSITIONS_PAGE_SIZE);
+
+        let mut by_market = HashMap::new();
+        let mut offset = 0;
+
+        loop {
+            request.offset = Some(offset);
+            let positions = self.positions(&request).await?;
+
+            for position in &positions {
+                *by_market
+                    .entry(position.condition_id)
+                    .or_insert(Decimal::ZERO) += position.current_value;
+            }
+
+            offset += POSITIONS_PAGE_SIZE;
+            if positions.len() < POSITIONS_PAGE_SIZE as usize || offset > MAX_POSITIONS_OFFSET {
+                break;
+            }
+        }
+
+        Ok(by_market)
+    }
+
     /// Fetches closed (historical) positions for a user.
     ///
     /// These are positions that have been fully sold or redeemed.
@@ -212,7 +415,7 @@ impl Client {
         &self,
         req: &TraderLeaderboardRequest,
     ) -> Result<Vec<TraderLeaderboardEntry>> {
-        self.get("v1/leaderboard", req).await
+        self.get_versioned("leaderboard", req).await
     }
 
     /// Fetches the total count of unique markets a user has traded.
@@ -246,6 +449,46 @@ impl Client {
         self.get("live-volume", req).await
     }
 
+    /// Streams live trading volume for an event by polling `/live-volume` every `interval`.
+    ///
+    /// The Data API has no push channel for volume, so this polls rather than subscribing. The
+    /// first snapshot is fetched immediately, and later snapshots are only yielded when they
+    /// differ from the last one yielded. If a poll falls behind, the next one is delayed instead
+    /// of firing a burst of catch-up requests.
+    ///
+    /// The stream never ends on its own; drop it to stop polling.
+    ///
+    /// # Errors
+    ///
+    /// Yields an error for each failed poll and keeps polling, so transient failures don't end
+    /// the stream.
+    pub fn live_volume_poll(
+        &self,
+        req: &LiveVolumeRequest,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<Vec<LiveVolume>>> + '_ {
+        let req = req.clone();
+
+        stream! {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut last = None;
+
+            loop {
+                ticker.tick().await;
+
+                match self.live_volume(&req).await {
+                    Ok(volume) if last.as_ref() == Some(&volume) => {}
+                    Ok(volume) => {
+                        last = Some(volume.clone());
+                        yield Ok(volume);
+                    }
+                    Err(e) => yield Err(e),
+                }
+            }
+        }
+    }
+
     /// Fetches aggregated builder leaderboard rankings.
     ///
     /// Builders are third-party applications that integrate with Polymarket.
@@ -258,7 +501,7 @@ impl Client {
         &self,
         req: &BuilderLeaderboardRequest,
     ) -> Result<Vec<BuilderLeaderboardEntry>> {
-        self.get("v1/builders/leaderboard", req).await
+        self.get_versioned("builders/leaderboard", req).await
     }
 
     /// Fetches daily time-series volume data for builders.
@@ -272,6 +515,6 @@ impl Client {
         &self,
         req: &BuilderVolumeRequest,
     ) -> Result<Vec<BuilderVolumeEntry>> {
-        self.get("v1/builders/volume", req).await
+        self.get_versioned("builders/volume", req).await
     }
 }
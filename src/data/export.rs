@@ -0,0 +1,371 @@
+//! Export helpers for Data API records.
+//!
+//! **Feature flag:** `csv` (required to use this module)
+//!
+//! # Trade schema
+//!
+//! Every trade export, CSV or JSON Lines, has the same columns in the same order:
+//!
+//! | Column             | Content                                      |
+//! |--------------------|----------------------------------------------|
+//! | `timestamp`        | When the trade occurred, RFC 3339 in UTC     |
+//! | `market`           | The market's condition ID                    |
+//! | `side`             | `BUY` or `SELL`                              |
+//! | `price`            | Execution price per outcome token            |
+//! | `size`             | Number of outcome tokens traded              |
+//! | `fee`              | Always empty, see below                      |
+//! | `outcome`          | The outcome traded, e.g. "Yes"               |
+//! | `transaction_hash` | The on-chain transaction hash                |
+//!
+//! The Data API does not report fees for trades, so `fee` is left empty in CSV and `null` in JSON
+//! Lines. It is kept so that the schema stays stable. Columns are only ever appended, never
+//! renamed, removed or reordered.
+
+use std::io::Write;
+
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt as _};
+use serde::Serialize;
+
+use super::types::Side;
+use super::types::response::Trade;
+use crate::Result;
+use crate::types::{B256, Decimal};
+
+/// The columns of a trade export, matching the fields of [`TradeRow`].
+const TRADE_COLUMNS: [&str; 8] = [
+    "timestamp",
+    "market",
+    "side",
+    "price",
+    "size",
+    "fee",
+    "outcome",
+    "transaction_hash",
+];
+
+/// A single row of a trade export, see the [module docs](self#trade-schema).
+#[derive(Serialize)]
+struct TradeRow<'trade> {
+    timestamp: DateTime<Utc>,
+    market: B256,
+    side: &'trade Side,
+    price: Decimal,
+    size: Decimal,
+    fee: Option<Decimal>,
+    outcome: &'trade str,
+    transaction_hash: B256,
+}
+
+impl<'trade> From<&'trade Trade> for TradeRow<'trade> {
+    fn from(trade: &'trade Trade) -> Self {
+        Self {
+            timestamp: trade.timestamp,
+            market: trade.condition_id,
+            side: &trade.side,
+            price: trade.price,
+            size: trade.size,
+            fee: None,
+            outcome: &trade.outcome,
+            transaction_hash: trade.transaction_hash,
+        }
+    }
+}
+
+/// Writes trades to `writer` as CSV, returning the number of rows written.
+///
+/// The output has a header row followed by one row per trade, with the columns described in the
+/// [module docs](self#trade-schema).
+///
+/// Rows are written as the stream yields them, so memory use stays bounded no matter how many
+/// trades are exported. Pair it with [`Client::trades_all`](super::Client::trades_all) to dump
+/// a wallet's full history.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_client_sdk::data::{Client, export::trades_to_csv, types::request::TradesRequest};
+/// use polymarket_client_sdk::types::address;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::default();
+/// let request = TradesRequest::builder()
+///     .user(address!("56687bf447db6ffa42ffe2204a05edaa20f55839"))
+///     .build();
+///
+/// let file = std::fs::File::create("trades.csv")?;
+/// let rows = trades_to_csv(client.trades_all(&request), file).await?;
+/// println!("Exported {rows} trades");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns the first error yielded by `trades`, or an error if writing to `writer` fails. Rows
+/// written before the error are flushed to `writer`.
+pub async fn trades_to_csv<S, W>(trades: S, writer: W) -> Result<usize>
+where
+    S: Stream<Item = Result<Trade>>,
+    W: Write,
+{
+    let mut writer = trade_csv_writer(writer)?;
+    let mut trades = std::pin::pin!(trades);
+    let mut rows = 0;
+
+    while let Some(trade) = trades.next().await {
+        let trade = match trade {
+            Ok(trade) => trade,
+            Err(e) => {
+                writer.flush().map_err(csv::Error::from)?;
+                return Err(e);
+            }
+        };
+
+        writer.serialize(TradeRow::from(&trade))?;
+        rows += 1;
+    }
+
+    writer.flush().map_err(csv::Error::from)?;
+
+    Ok(rows)
+}
+
+/// Writes trades to `writer` as JSON Lines, one object per trade, returning the number of lines
+/// written.
+///
+/// Each object has the keys described in the [module docs](self#trade-schema), in that order.
+/// `price` and `size` are JSON strings so that they keep their exact decimal value.
+/// Like [`trades_to_csv`], lines are written as the stream yields them.
+///
+/// # Errors
+///
+/// Returns the first error yielded by `trades`, or an error if writing to `writer` fails. Lines
+/// written before the error are flushed to `writer`.
+pub async fn trades_to_jsonl<S, W>(trades: S, writer: W) -> Result<usize>
+where
+    S: Stream<Item = Result<Trade>>,
+    W: Write,
+{
+    let mut writer = std::io::BufWriter::new(writer);
+    let mut trades = std::pin::pin!(trades);
+    let mut lines = 0;
+
+    while let Some(trade) = trades.next().await {
+        let trade = match trade {
+            Ok(trade) => trade,
+            Err(e) => {
+                writer.flush().map_err(serde_json::Error::io)?;
+                return Err(e);
+            }
+        };
+
+        write_jsonl_row(&mut writer, &trade)?;
+        lines += 1;
+    }
+
+    writer.flush().map_err(serde_json::Error::io)?;
+
+    Ok(lines)
+}
+
+/// Writes already fetched `trades` to `writer` as CSV, see [`trades_to_csv`].
+///
+/// # Errors
+///
+/// Returns an error if writing to `writer` fails.
+pub fn write_trades_csv<W: Write>(trades: &[Trade], writer: W) -> Result<()> {
+    let mut writer = trade_csv_writer(writer)?;
+
+    for trade in trades {
+        writer.serialize(TradeRow::from(trade))?;
+    }
+
+    writer.flush().map_err(csv::Error::from)?;
+
+    Ok(())
+}
+
+/// Writes already fetched `trades` to `writer` as JSON Lines, see [`trades_to_jsonl`].
+///
+/// # Errors
+///
+/// Returns an error if writing to `writer` fails.
+pub fn write_trades_jsonl<W: Write>(trades: &[Trade], writer: W) -> Result<()> {
+    let mut writer = std::io::BufWriter::new(writer);
+
+    for trade in trades {
+        write_jsonl_row(&mut writer, trade)?;
+    }
+
+    writer.flush().map_err(serde_json::Error::io)?;
+
+    Ok(())
+}
+
+/// Creates a CSV writer that has already written the header row, so that exporting no trades
+/// still produces a header.
+fn trade_csv_writer<W: Write>(writer: W) -> Result<csv::Writer<W>> {
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(writer);
+    writer.write_record(TRADE_COLUMNS)?;
+
+    Ok(writer)
+}
+
+fn write_jsonl_row<W: Write>(writer: &mut W, trade: &Trade) -> Result<()> {
+    serde_json::to_writer(&mut *writer, &TradeRow::from(trade))?;
+    writer.write_all(b"\n").map_err(serde_json::Error::io)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::error::Error;
+    use crate::types::{address, b256};
+
+    fn trade(timestamp: i64, side: Side) -> Trade {
+        let timestamp = DateTime::from_timestamp(timestamp, 0).unwrap();
+
+        Trade::builder()
+            .proxy_wallet(address!("56687bf447db6ffa42ffe2204a05edaa20f55839"))
+            .side(side)
+            .asset(crate::types::U256::from(1))
+            .condition_id(b256!(
+                "abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890"
+            ))
+            .size(dec!(50))
+            .price(dec!(0.55))
+            .timestamp(timestamp)
+            .title("Market".to_owned())
+            .slug("market".to_owned())
+            .icon(String::new())
+            .event_slug("event".to_owned())
+            .outcome("Yes".to_owned())
+            .outcome_index(0)
+            .transaction_hash(B256::repeat_byte(0x22))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn trades_to_csv_should_write_header_and_rows() {
+        let trades = stream::iter([
+            Ok(trade(1_703_980_800, Side::Buy)),
+            Ok(trade(0, Side::Sell)),
+        ]);
+        let mut output = Vec::new();
+
+        let rows = trades_to_csv(trades, &mut output).await.unwrap();
+
+        assert_eq!(rows, 2);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "timestamp,market,side,price,size,fee,outcome,transaction_hash\n\
+             2023-12-31T00:00:00Z,0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890,BUY,0.55,50,,Yes,0x2222222222222222222222222222222222222222222222222222222222222222\n\
+             1970-01-01T00:00:00Z,0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890,SELL,0.55,50,,Yes,0x2222222222222222222222222222222222222222222222222222222222222222\n"
+        );
+    }
+
+    #[test]
+    fn write_trades_csv_should_write_header_and_rows() {
+        let trades = [trade(1_703_980_800, Side::Buy), trade(0, Side::Sell)];
+        let mut output = Vec::new();
+
+        write_trades_csv(&trades, &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().count(), 3);
+        assert!(
+            output.starts_with("timestamp,market,side,price,size,fee,outcome,transaction_hash\n")
+        );
+        assert!(output.contains("\n1970-01-01T00:00:00Z,"));
+    }
+
+    #[test]
+    fn write_trades_csv_should_write_header_without_trades() {
+        let mut output = Vec::new();
+
+        write_trades_csv(&[], &mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "timestamp,market,side,price,size,fee,outcome,transaction_hash\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn trades_to_jsonl_should_write_one_object_per_line() {
+        let trades = stream::iter([Ok(trade(1_703_980_800, Side::Buy))]);
+        let mut output = Vec::new();
+
+        let lines = trades_to_jsonl(trades, &mut output).await.unwrap();
+
+        assert_eq!(lines, 1);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"timestamp\":\"2023-12-31T00:00:00Z\",\
+             \"market\":\"0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890\",\
+             \"side\":\"BUY\",\"price\":\"0.55\",\"size\":\"50\",\"fee\":null,\"outcome\":\"Yes\",\
+             \"transaction_hash\":\"0x2222222222222222222222222222222222222222222222222222222222222222\"}\n"
+        );
+    }
+
+    #[test]
+    fn write_trades_jsonl_should_write_every_trade() {
+        let trades = [trade(0, Side::Buy), trade(0, Side::Sell)];
+        let mut output = Vec::new();
+
+        write_trades_jsonl(&trades, &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let sides: Vec<_> = output
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["side"].clone())
+            .collect();
+        assert_eq!(sides, ["BUY", "SELL"]);
+    }
+
+    #[test]
+    fn write_trades_should_share_one_schema() {
+        let trades = [trade(0, Side::Buy)];
+        let mut csv = Vec::new();
+        let mut jsonl = Vec::new();
+
+        write_trades_csv(&trades, &mut csv).unwrap();
+        write_trades_jsonl(&trades, &mut jsonl).unwrap();
+
+        let csv = String::from_utf8(csv).unwrap();
+        let header: Vec<_> = csv.lines().next().unwrap().split(',').collect();
+        assert_eq!(header, TRADE_COLUMNS);
+
+        let line = String::from_utf8(jsonl).unwrap();
+        let object: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(&line).unwrap();
+        assert_eq!(object.len(), TRADE_COLUMNS.len());
+        assert_eq!(object["fee"], serde_json::Value::Null);
+        let positions: Vec<_> = TRADE_COLUMNS
+            .iter()
+            .map(|column| line.find(&format!("\"{column}\":")).unwrap())
+            .collect();
+        assert!(positions.is_sorted(), "{line}");
+    }
+
+    #[tokio::test]
+    async fn trades_to_csv_should_keep_rows_written_before_error() {
+        let trades = stream::iter([
+            Ok(trade(0, Side::Buy)),
+            Err(Error::validation("page failed")),
+        ]);
+        let mut output = Vec::new();
+
+        trades_to_csv(trades, &mut output).await.unwrap_err();
+
+        assert_eq!(String::from_utf8(output).unwrap().lines().count(), 2);
+    }
+}
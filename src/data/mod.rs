@@ -63,6 +63,8 @@
 //! The default API endpoint is `https://data-api.polymarket.com`.
 
 pub mod client;
+#[cfg(feature = "csv")]
+pub mod export;
 pub mod types;
 
 pub use client::Client;
@@ -4,11 +4,13 @@ use serde::de::StdError;
 use serde::{Deserialize, Serialize};
 use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as};
 
-use crate::types::{B256, Decimal};
+use crate::types::{B256, Decimal, MarketId};
 
 pub mod request;
 pub mod response;
 
+pub use crate::types::Period;
+
 /// The side of a trade (buy or sell).
 ///
 /// Used to indicate whether a trade was a purchase or sale of outcome tokens.
@@ -184,27 +186,23 @@ pub enum FilterType {
     Tokens,
 }
 
-/// Time period for aggregating leaderboard and volume data.
+/// Version prefix of the Data API's versioned endpoints, such as the leaderboards.
 ///
-/// Default is [`Day`](Self::Day) for most endpoints.
-#[derive(
-    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, strum_macros::Display,
-)]
-#[serde(rename_all = "UPPERCASE")]
-#[strum(serialize_all = "UPPERCASE")]
+/// Unversioned endpoints like `/positions` are unaffected. Default is [`V1`](Self::V1).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum_macros::Display)]
+#[strum(serialize_all = "lowercase")]
 #[non_exhaustive]
-pub enum TimePeriod {
-    /// Last 24 hours (default).
+pub enum ApiVersion {
+    /// `/v1/...` (default).
     #[default]
-    Day,
-    /// Last 7 days.
-    Week,
-    /// Last 30 days.
-    Month,
-    /// All time.
-    All,
+    V1,
+    /// `/v2/...`.
+    V2,
 }
 
+/// Former name of [`Period`], kept so existing code keeps compiling.
+pub type TimePeriod = Period;
+
 /// Market category for filtering trader leaderboard results.
 ///
 /// Default is [`Overall`](Self::Overall) which includes all categories.
@@ -298,6 +296,12 @@ impl MarketFilter {
     }
 }
 
+impl From<MarketId> for MarketFilter {
+    fn from(market: MarketId) -> Self {
+        Self::markets([market.condition_id])
+    }
+}
+
 /// Error type for bounded integer values that are out of range.
 #[derive(Debug)]
 #[non_exhaustive]
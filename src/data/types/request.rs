@@ -9,12 +9,15 @@
 )]
 
 use bon::Builder;
+use chrono::{DateTime, Utc};
 use serde::Serialize;
-use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as, skip_serializing_none};
+use serde_with::{
+    StringWithSeparator, TimestampSeconds, formats::CommaSeparator, serde_as, skip_serializing_none,
+};
 
 use super::{
     ActivitySortBy, ActivityType, BoundedIntError, ClosedPositionSortBy, LeaderboardCategory,
-    LeaderboardOrderBy, MarketFilter, PositionSortBy, Side, SortDirection, TimePeriod, TradeFilter,
+    LeaderboardOrderBy, MarketFilter, Period, PositionSortBy, Side, SortDirection, TradeFilter,
 };
 use crate::types::{Address, B256, Decimal};
 
@@ -178,8 +181,8 @@ pub struct TradesRequest {
 /// - `activity_types`: Filter by activity types (TRADE, SPLIT, MERGE, etc.).
 /// - `limit`: Maximum activities to return (0-500, default: 100).
 /// - `offset`: Pagination offset (0-10000, default: 0).
-/// - `start`: Start timestamp filter (Unix timestamp).
-/// - `end`: End timestamp filter (Unix timestamp).
+/// - `start`: Only return activity at or after this time.
+/// - `end`: Only return activity at or before this time.
 /// - `sort_by`: Sort criteria (default: TIMESTAMP).
 /// - `sort_direction`: Sort order (default: DESC).
 /// - `side`: Filter by trade side (only applies to TRADE activities).
@@ -206,7 +209,7 @@ pub struct ActivityRequest {
     /// Filter by markets or events. Mutually exclusive options.
     #[serde(flatten)]
     pub filter: Option<MarketFilter>,
-    /// Filter by activity types.
+    /// Filter by activity types. Empty (the default) returns activity of every type.
     #[serde_as(as = "StringWithSeparator::<CommaSeparator, ActivityType>")]
     #[builder(default)]
     #[serde(rename = "type", skip_serializing_if = "Vec::is_empty")]
@@ -217,10 +220,13 @@ pub struct ActivityRequest {
     /// Pagination offset (0-10000, default: 0).
     #[builder(with = |v: i32| -> Result<_, BoundedIntError> { validate_bound(v, 0, 10000, "offset") })]
     pub offset: Option<i32>,
-    /// Start timestamp filter (Unix timestamp, minimum: 0).
-    pub start: Option<u64>,
-    /// End timestamp filter (Unix timestamp, minimum: 0).
-    pub end: Option<u64>,
+    /// Only return activity at or after this time (sent as a Unix timestamp).
+    #[serde_as(as = "Option<TimestampSeconds<i64>>")]
+    pub start: Option<DateTime<Utc>>,
+    /// Only return activity at or before this time (sent as a Unix timestamp). Must not be before
+    /// `start`.
+    #[serde_as(as = "Option<TimestampSeconds<i64>>")]
+    pub end: Option<DateTime<Utc>>,
     /// Sort criteria (default: TIMESTAMP).
     #[serde(rename = "sortBy")]
     pub sort_by: Option<ActivitySortBy>,
@@ -321,8 +327,12 @@ pub struct ValueRequest {
 ///
 /// # Optional Parameters
 ///
-/// - `markets`: Filter by specific condition IDs. If not provided, returns
-///   open interest for all markets.
+/// - `markets`: Filter by specific condition IDs.
+/// - `event_ids`: Filter by events, returning open interest for each of their markets.
+///
+/// If neither is provided, returns open interest for all markets. Use
+/// [`total_open_interest`](super::response::total_open_interest) to aggregate the result, e.g.
+/// into an event-level total.
 #[serde_as]
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Default, Serialize)]
@@ -333,6 +343,11 @@ pub struct OpenInterestRequest {
     #[builder(default)]
     #[serde(rename = "market", skip_serializing_if = "Vec::is_empty")]
     pub markets: Vec<B256>,
+    /// Optional list of event IDs to filter by.
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
+    #[builder(default)]
+    #[serde(rename = "eventId", skip_serializing_if = "Vec::is_empty")]
+    pub event_ids: Vec<String>,
 }
 
 /// Request parameters for the `/live-volume` endpoint.
@@ -421,10 +436,10 @@ pub struct ClosedPositionsRequest {
 /// # Example
 ///
 /// ```
-/// use polymarket_client_sdk::data::{types::request::BuilderLeaderboardRequest, types::TimePeriod};
+/// use polymarket_client_sdk::data::{types::request::BuilderLeaderboardRequest, types::Period};
 ///
 /// let request = BuilderLeaderboardRequest::builder()
-///     .time_period(TimePeriod::Week)
+///     .time_period(Period::Week)
 ///     .build();
 /// ```
 #[skip_serializing_none]
@@ -433,7 +448,7 @@ pub struct ClosedPositionsRequest {
 pub struct BuilderLeaderboardRequest {
     /// Time period to aggregate results over (default: DAY).
     #[serde(rename = "timePeriod")]
-    pub time_period: Option<TimePeriod>,
+    pub time_period: Option<Period>,
     /// Maximum number of builders to return (0-50, default: 25).
     #[builder(with = |v: i32| -> Result<_, BoundedIntError> { validate_bound(v, 0, 50, "limit") })]
     pub limit: Option<i32>,
@@ -454,10 +469,10 @@ pub struct BuilderLeaderboardRequest {
 /// # Example
 ///
 /// ```
-/// use polymarket_client_sdk::data::{types::request::BuilderVolumeRequest, types::TimePeriod};
+/// use polymarket_client_sdk::data::{types::request::BuilderVolumeRequest, types::Period};
 ///
 /// let request = BuilderVolumeRequest::builder()
-///     .time_period(TimePeriod::Month)
+///     .time_period(Period::Month)
 ///     .build();
 /// ```
 #[skip_serializing_none]
@@ -466,7 +481,7 @@ pub struct BuilderLeaderboardRequest {
 pub struct BuilderVolumeRequest {
     /// Time period to fetch daily records for (default: DAY).
     #[serde(rename = "timePeriod")]
-    pub time_period: Option<TimePeriod>,
+    pub time_period: Option<Period>,
 }
 
 /// Request parameters for the `/v1/leaderboard` endpoint.
@@ -487,11 +502,11 @@ pub struct BuilderVolumeRequest {
 /// # Example
 ///
 /// ```
-/// use polymarket_client_sdk::data::{types::request::TraderLeaderboardRequest, types::{LeaderboardCategory, TimePeriod, LeaderboardOrderBy}};
+/// use polymarket_client_sdk::data::{types::request::TraderLeaderboardRequest, types::{LeaderboardCategory, Period, LeaderboardOrderBy}};
 ///
 /// let request = TraderLeaderboardRequest::builder()
 ///     .category(LeaderboardCategory::Politics)
-///     .time_period(TimePeriod::Week)
+///     .time_period(Period::Week)
 ///     .order_by(LeaderboardOrderBy::Vol)
 ///     .build();
 /// ```
@@ -503,7 +518,7 @@ pub struct TraderLeaderboardRequest {
     pub category: Option<LeaderboardCategory>,
     /// Time period for leaderboard results (default: DAY).
     #[serde(rename = "timePeriod")]
-    pub time_period: Option<TimePeriod>,
+    pub time_period: Option<Period>,
     /// Ordering criteria (default: PNL).
     #[serde(rename = "orderBy")]
     pub order_by: Option<LeaderboardOrderBy>,
@@ -2,13 +2,16 @@
 //!
 //! This module contains structs representing API responses from the Data API endpoints.
 
+use std::collections::HashMap;
+
 use bon::Builder;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Deserializer};
 use serde_with::{DefaultOnNull, DisplayFromStr, NoneAsEmptyString, serde_as};
 
 use super::{ActivityType, Side};
-use crate::types::{Address, B256, Decimal, U256};
+use crate::serde_helpers::{FlexibleDecimal, FlexibleTimestamp, FlexibleU256};
+use crate::types::{Address, B256, Decimal, MarketId, U256};
 
 /// Deserializes an optional Side, treating empty strings as None.
 fn deserialize_optional_side<'de, D>(deserializer: D) -> Result<Option<Side>, D::Error>
@@ -70,28 +73,39 @@ pub struct Position {
     /// The user's proxy wallet address.
     pub proxy_wallet: Address,
     /// The outcome token asset identifier
+    #[serde_as(as = "FlexibleU256")]
     pub asset: U256,
     /// The market condition ID (unique market identifier).
     pub condition_id: B256,
     /// Number of outcome tokens held.
+    #[serde_as(as = "FlexibleDecimal")]
     pub size: Decimal,
     /// Average entry price for the position.
+    #[serde_as(as = "FlexibleDecimal")]
     pub avg_price: Decimal,
     /// Initial value (cost basis) of the position.
+    #[serde_as(as = "FlexibleDecimal")]
     pub initial_value: Decimal,
     /// Current market value of the position.
+    #[serde_as(as = "FlexibleDecimal")]
     pub current_value: Decimal,
     /// Unrealized cash profit/loss.
+    #[serde_as(as = "FlexibleDecimal")]
     pub cash_pnl: Decimal,
     /// Unrealized percentage profit/loss.
+    #[serde_as(as = "FlexibleDecimal")]
     pub percent_pnl: Decimal,
     /// Total amount bought (cumulative).
+    #[serde_as(as = "FlexibleDecimal")]
     pub total_bought: Decimal,
     /// Realized profit/loss from closed portions.
+    #[serde_as(as = "FlexibleDecimal")]
     pub realized_pnl: Decimal,
     /// Realized percentage profit/loss.
+    #[serde_as(as = "FlexibleDecimal")]
     pub percent_realized_pnl: Decimal,
     /// Current market price of the outcome.
+    #[serde_as(as = "FlexibleDecimal")]
     pub cur_price: Decimal,
     /// Whether the position can be redeemed (market resolved).
     pub redeemable: bool,
@@ -116,6 +130,7 @@ pub struct Position {
     /// Name of the opposite outcome.
     pub opposite_outcome: String,
     /// Asset identifier of the opposite outcome.
+    #[serde_as(as = "FlexibleU256")]
     pub opposite_asset: U256,
     /// Market end/resolution date.
     pub end_date: NaiveDate,
@@ -123,10 +138,50 @@ pub struct Position {
     pub negative_risk: bool,
 }
 
+impl Position {
+    /// Returns the value of the position if each outcome token is worth `current_price`, i.e.
+    /// `size * current_price`.
+    ///
+    /// For a resolved market pass the settlement price, 1 for the winning outcome and 0 for the
+    /// losing one, which is what [`Self::cur_price`] holds once the position is
+    /// [`redeemable`](Self::redeemable).
+    #[must_use]
+    pub fn market_value(&self, current_price: Decimal) -> Decimal {
+        self.size * current_price
+    }
+
+    /// Returns the unrealized profit or loss if each outcome token is worth `current_price`,
+    /// i.e. `size * (current_price - avg_price)`.
+    ///
+    /// See [`Self::market_value`] for resolved markets.
+    #[must_use]
+    pub fn unrealized_pnl(&self, current_price: Decimal) -> Decimal {
+        self.size * (current_price - self.avg_price)
+    }
+}
+
+impl From<&Position> for MarketId {
+    /// Orders the position's asset and its opposite asset by outcome index.
+    fn from(position: &Position) -> Self {
+        let token_ids = if position.outcome_index == 0 {
+            vec![position.asset, position.opposite_asset]
+        } else {
+            vec![position.opposite_asset, position.asset]
+        };
+
+        MarketId::new(
+            position.condition_id,
+            token_ids,
+            Some(position.slug.clone()),
+        )
+    }
+}
+
 /// A user's closed (historical) position in a prediction market.
 ///
 /// Returned by the `/closed-positions` endpoint. Represents positions that
 /// have been fully sold or redeemed, with final profit/loss figures.
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -134,6 +189,7 @@ pub struct ClosedPosition {
     /// The user's proxy wallet address.
     pub proxy_wallet: Address,
     /// The outcome token asset identifier (decimal string from API).
+    #[serde_as(as = "FlexibleU256")]
     pub asset: U256,
     /// The market condition ID (unique market identifier).
     pub condition_id: B256,
@@ -162,8 +218,10 @@ pub struct ClosedPosition {
     /// Name of the opposite outcome.
     pub opposite_outcome: String,
     /// Asset identifier of the opposite outcome.
+    #[serde_as(as = "FlexibleU256")]
     pub opposite_asset: U256,
     /// Market end/resolution date.
+    #[serde_as(as = "FlexibleTimestamp")]
     pub end_date: DateTime<Utc>,
 }
 
@@ -181,15 +239,19 @@ pub struct Trade {
     /// Trade side (BUY or SELL).
     pub side: Side,
     /// The outcome token asset identifier (decimal string from API).
+    #[serde_as(as = "FlexibleU256")]
     pub asset: U256,
     /// The market condition ID (unique market identifier).
     pub condition_id: B256,
     /// Number of tokens traded.
+    #[serde_as(as = "FlexibleDecimal")]
     pub size: Decimal,
     /// Execution price per token.
+    #[serde_as(as = "FlexibleDecimal")]
     pub price: Decimal,
-    /// Unix timestamp when the trade occurred.
-    pub timestamp: i64,
+    /// When the trade occurred.
+    #[serde_as(as = "FlexibleTimestamp")]
+    pub timestamp: DateTime<Utc>,
     /// Market title/question.
     pub title: String,
     /// Market URL slug.
@@ -237,8 +299,9 @@ pub struct Trade {
 pub struct Activity {
     /// The user's proxy wallet address.
     pub proxy_wallet: Address,
-    /// Unix timestamp when the activity occurred.
-    pub timestamp: i64,
+    /// When the activity occurred.
+    #[serde_as(as = "FlexibleTimestamp")]
+    pub timestamp: DateTime<Utc>,
     /// The market condition ID (unique market identifier).
     /// Can be empty for some activity types (e.g., rewards, conversions).
     #[serde(default)]
@@ -321,6 +384,7 @@ pub struct Holder {
     #[serde_as(as = "NoneAsEmptyString")]
     pub bio: Option<String>,
     /// The outcome token asset identifier (decimal string from API).
+    #[serde_as(as = "FlexibleU256")]
     pub asset: U256,
     /// Holder's pseudonym (if set).
     #[serde(default)]
@@ -351,15 +415,78 @@ pub struct Holder {
 /// Container for holders grouped by token.
 ///
 /// Returned by the `/holders` endpoint. Groups holders by outcome token.
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, Builder)]
 #[non_exhaustive]
 pub struct MetaHolder {
     /// The outcome token identifier
+    #[serde_as(as = "FlexibleU256")]
     pub token: U256,
     /// List of holders for this token.
     pub holders: Vec<Holder>,
 }
 
+/// Convenience accessors over the `/holders` response, i.e. a slice of [`MetaHolder`]s.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_client_sdk::data::types::response::HoldersExt as _;
+/// use polymarket_client_sdk::data::{Client, types::request::HoldersRequest};
+/// use polymarket_client_sdk::types::b256;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::default();
+/// let request = HoldersRequest::builder()
+///     .markets(vec![b256!("dd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917")])
+///     .build();
+///
+/// let holders = client.holders(&request).await?;
+/// for (token, total) in holders.total_by_token() {
+///     let top = holders.top_holders(token, 1);
+///     println!("{token}: {total} held, largest holder {:?}", top.first().map(|h| h.amount));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait HoldersExt {
+    /// Returns up to `n` holders of `token_id`, largest first.
+    fn top_holders(&self, token_id: U256, n: usize) -> Vec<&Holder>;
+
+    /// Returns the total amount held by the listed holders of each token.
+    fn total_by_token(&self) -> HashMap<U256, Decimal>;
+
+    /// Iterates over every holder along with the token it holds.
+    fn iter_holders(&self) -> impl Iterator<Item = (U256, &Holder)>;
+}
+
+impl HoldersExt for [MetaHolder] {
+    fn top_holders(&self, token_id: U256, n: usize) -> Vec<&Holder> {
+        let mut holders: Vec<_> = self
+            .iter_holders()
+            .filter_map(|(token, holder)| (token == token_id).then_some(holder))
+            .collect();
+        holders.sort_by_key(|holder| std::cmp::Reverse(holder.amount));
+        holders.truncate(n);
+
+        holders
+    }
+
+    fn total_by_token(&self) -> HashMap<U256, Decimal> {
+        let mut totals = HashMap::new();
+        for (token, holder) in self.iter_holders() {
+            *totals.entry(token).or_insert(Decimal::ZERO) += holder.amount;
+        }
+
+        totals
+    }
+
+    fn iter_holders(&self) -> impl Iterator<Item = (U256, &Holder)> {
+        self.iter()
+            .flat_map(|meta| meta.holders.iter().map(move |holder| (meta.token, holder)))
+    }
+}
+
 /// Count of unique markets a user has traded.
 ///
 /// Returned by the `/traded` endpoint.
@@ -397,10 +524,28 @@ pub struct OpenInterest {
     pub value: Decimal,
 }
 
+/// Sums the open interest of the markets in `entries`, e.g. the result of querying
+/// [`OpenInterestRequest`](super::request::OpenInterestRequest) by event ID to get an event's
+/// total capital at risk.
+///
+/// The [`Market::Global`] entry is already a total across all markets, so it is skipped rather
+/// than double counted. Neg-risk events need no special handling: each outcome is its own market
+/// whose open interest is backed by its own locked collateral, and converting a position moves
+/// that collateral between the event's markets rather than creating more, so the per-market
+/// values add up to the event's total.
+#[must_use]
+pub fn total_open_interest(entries: &[OpenInterest]) -> Decimal {
+    entries
+        .iter()
+        .filter(|entry| entry.market != Market::Global)
+        .map(|entry| entry.value)
+        .sum()
+}
+
 /// Trading volume for a specific market.
 ///
 /// Used within [`LiveVolume`] to show per-market volume breakdown.
-#[derive(Debug, Clone, Deserialize, Builder)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Builder)]
 #[non_exhaustive]
 pub struct MarketVolume {
     /// The market condition ID
@@ -414,7 +559,7 @@ pub struct MarketVolume {
 /// Returned by the `/live-volume` endpoint. Includes total volume
 /// and per-market breakdown.
 #[serde_as]
-#[derive(Debug, Clone, Deserialize, Builder)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Builder)]
 #[non_exhaustive]
 pub struct LiveVolume {
     /// Total trading volume across all markets in the event.
@@ -461,6 +606,7 @@ pub struct BuilderLeaderboardEntry {
 #[non_exhaustive]
 pub struct BuilderVolumeEntry {
     /// Timestamp for this entry in ISO 8601 format (e.g., "2025-11-15T00:00:00Z").
+    #[serde_as(as = "FlexibleTimestamp")]
     pub dt: DateTime<Utc>,
     /// Builder name or identifier.
     pub builder: String,
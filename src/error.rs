@@ -1,9 +1,10 @@
 use std::backtrace::Backtrace;
 use std::error::Error as StdError;
 use std::fmt;
+use std::time::Duration;
 
-use alloy::primitives::ChainId;
 use alloy::primitives::ruint::ParseError;
+use alloy::primitives::{ChainId, U256};
 use hmac::digest::InvalidLength;
 /// HTTP method type, re-exported for use with error inspection.
 pub use reqwest::Method;
@@ -11,6 +12,9 @@ pub use reqwest::Method;
 pub use reqwest::StatusCode;
 use reqwest::header;
 
+use crate::response::RateLimitInfo;
+use crate::types::Decimal;
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Kind {
@@ -26,6 +30,12 @@ pub enum Kind {
     WebSocket,
     /// Error related to geographic restrictions blocking access
     Geoblock,
+    /// Error related to an operation not completing within its deadline
+    Timeout,
+    /// Error related to the order book not being able to fill an order within its price bound
+    InsufficientLiquidity,
+    /// Error related to a health check reporting that the API is degraded, e.g. during maintenance
+    ServiceUnavailable,
 }
 
 #[derive(Debug)]
@@ -56,9 +66,16 @@ impl Error {
         self.source.as_deref()
     }
 
+    /// Returns the first error of type `E` in the chain of sources, so that e.g. the
+    /// [`reqwest::Error`] behind an [`Http`] error can be inspected directly.
     pub fn downcast_ref<E: StdError + 'static>(&self) -> Option<&E> {
-        let e = self.source.as_deref()?;
-        e.downcast_ref::<E>()
+        let mut error: &(dyn StdError + 'static) = self.source.as_deref()?;
+        loop {
+            if let Some(e) = error.downcast_ref::<E>() {
+                return Some(e);
+            }
+            error = error.source()?;
+        }
     }
 
     pub fn validation<S: Into<String>>(message: S) -> Self {
@@ -79,10 +96,58 @@ impl Error {
             method,
             path,
             message: message.into(),
+            rate_limit: None,
         }
         .into()
     }
 
+    #[cfg(any(
+        feature = "bridge",
+        feature = "clob",
+        feature = "data",
+        feature = "gamma"
+    ))]
+    /// Builds a [`Status`] error, attaching any rate limit information from the response headers.
+    pub(crate) fn status_with_headers<S: Into<String>>(
+        status_code: StatusCode,
+        method: Method,
+        path: String,
+        message: S,
+        headers: &header::HeaderMap,
+    ) -> Self {
+        Status {
+            status_code,
+            method,
+            path,
+            message: message.into(),
+            rate_limit: RateLimitInfo::from_headers(headers),
+        }
+        .into()
+    }
+
+    #[cfg(any(
+        feature = "bridge",
+        feature = "clob",
+        feature = "data",
+        feature = "gamma"
+    ))]
+    /// Attaches the HTTP call this error occurred in by wrapping its source in an [`Http`] error.
+    /// [`Status`] errors already carry the call and are returned unchanged.
+    pub(crate) fn with_request(self, method: &Method, path: &str) -> Self {
+        match self.source {
+            Some(source) if self.kind != Kind::Status => Self {
+                kind: self.kind,
+                source: Some(Box::new(Http {
+                    method: method.clone(),
+                    path: path.to_owned(),
+                    source,
+                })),
+                backtrace: self.backtrace,
+            },
+            source => Self { source, ..self },
+        }
+    }
+
     #[must_use]
     pub fn missing_contract_config(chain_id: ChainId, neg_risk: bool) -> Self {
         MissingContractConfig { chain_id, neg_risk }.into()
@@ -113,20 +178,48 @@ pub struct Status {
     pub method: Method,
     pub path: String,
     pub message: String,
+    /// Rate limit information from the response headers, if the server sent any. On a
+    /// `429 Too Many Requests` this tells when it is safe to retry.
+    pub rate_limit: Option<RateLimitInfo>,
 }
 
 impl fmt::Display for Status {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "error({}) making {} call to {} with {}",
-            self.status_code, self.method, self.path, self.message
+            "{} {} -> {}: {}",
+            self.method, self.path, self.status_code, self.message
         )
     }
 }
 
 impl StdError for Status {}
 
+/// Error from an HTTP call that failed without a non-successful status, e.g. because the server
+/// could not be reached or the response body could not be deserialized.
+///
+/// The underlying error is available through [`StdError::source`] and [`Error::downcast_ref`].
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct Http {
+    pub method: Method,
+    /// The path of the call, including its query string
+    pub path: String,
+    source: Box<dyn StdError + Send + Sync + 'static>,
+}
+
+impl fmt::Display for Http {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} -> {}", self.method, self.path, self.source)
+    }
+}
+
+impl StdError for Http {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
 #[non_exhaustive]
 #[derive(Debug)]
 pub struct Validation {
@@ -156,6 +249,20 @@ impl fmt::Display for Synchronization {
 
 impl StdError for Synchronization {}
 
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub struct Timeout {
+    pub duration: Duration,
+}
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "operation timed out after {:?}", self.duration)
+    }
+}
+
+impl StdError for Timeout {}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
 pub struct MissingContractConfig {
@@ -181,6 +288,92 @@ impl From<MissingContractConfig> for Error {
     }
 }
 
+/// Error indicating that the resting liquidity within a market order's worst acceptable price
+/// cannot fill the full order.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub struct InsufficientLiquidity {
+    /// The token the order was for
+    pub token_id: U256,
+    /// The worst acceptable price of the order
+    pub worst_price: Decimal,
+    /// The requested amount, in the order's unit (USDC or shares)
+    pub requested: Decimal,
+    /// The amount the book can fill within `worst_price`, in the same unit as `requested`
+    pub fillable: Decimal,
+}
+
+impl fmt::Display for InsufficientLiquidity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "insufficient liquidity for {}: only {} of {} can fill within price {}",
+            self.token_id, self.fillable, self.requested, self.worst_price
+        )
+    }
+}
+
+impl StdError for InsufficientLiquidity {}
+
+impl From<InsufficientLiquidity> for Error {
+    fn from(err: InsufficientLiquidity) -> Self {
+        Error::with_source(Kind::InsufficientLiquidity, err)
+    }
+}
+
+/// Error indicating that a health check succeeded at the HTTP level but reported something other
+/// than `OK`, such as a maintenance notice.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct ServiceUnavailable {
+    /// The body the health check returned
+    pub message: String,
+}
+
+impl fmt::Display for ServiceUnavailable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "service unavailable: {}", self.message)
+    }
+}
+
+impl StdError for ServiceUnavailable {}
+
+impl From<ServiceUnavailable> for Error {
+    fn from(err: ServiceUnavailable) -> Self {
+        Error::with_source(Kind::ServiceUnavailable, err)
+    }
+}
+
+/// Error indicating that the CLOB does not know the API key a request was signed with, for
+/// example because it was already deleted.
+///
+/// Its [`Kind`] is [`Kind::Status`], and the rejected response stays available as its source, so
+/// [`Error::downcast_ref`] also finds the [`Status`].
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct ApiKeyNotFound {
+    /// The response the CLOB rejected the request with
+    pub status: Status,
+}
+
+impl fmt::Display for ApiKeyNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "API key not found: {}", self.status)
+    }
+}
+
+impl StdError for ApiKeyNotFound {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.status)
+    }
+}
+
+impl From<ApiKeyNotFound> for Error {
+    fn from(err: ApiKeyNotFound) -> Self {
+        Error::with_source(Kind::Status, err)
+    }
+}
+
 /// Error indicating that the user is blocked from accessing Polymarket due to geographic
 /// restrictions.
 ///
@@ -238,6 +431,13 @@ impl From<InvalidLength> for Error {
     }
 }
 
+#[cfg(feature = "csv")]
+impl From<csv::Error> for Error {
+    fn from(e: csv::Error) -> Self {
+        Error::with_source(Kind::Internal, e)
+    }
+}
+
 impl From<serde_json::Error> for Error {
     fn from(e: serde_json::Error) -> Self {
         Error::with_source(Kind::Internal, e)
@@ -280,6 +480,12 @@ impl From<Synchronization> for Error {
     }
 }
 
+impl From<Timeout> for Error {
+    fn from(err: Timeout) -> Self {
+        Error::with_source(Kind::Timeout, err)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -298,6 +504,52 @@ mod tests {
         );
     }
 
+    #[cfg(any(
+        feature = "bridge",
+        feature = "clob",
+        feature = "data",
+        feature = "gamma"
+    ))]
+    #[test]
+    fn with_request_should_wrap_source() {
+        let source = serde_json::from_str::<u32>("\"x\"").unwrap_err();
+        let error = Error::from(source).with_request(&Method::GET, "/events?limit=1");
+
+        assert_eq!(error.kind(), Kind::Internal);
+        let http = error.downcast_ref::<Http>().unwrap();
+        assert_eq!(http.method, Method::GET);
+        assert_eq!(http.path, "/events?limit=1");
+        assert!(
+            error
+                .to_string()
+                .starts_with("Internal: GET /events?limit=1 -> invalid type")
+        );
+        error.downcast_ref::<serde_json::Error>().unwrap();
+    }
+
+    #[cfg(any(
+        feature = "bridge",
+        feature = "clob",
+        feature = "data",
+        feature = "gamma"
+    ))]
+    #[test]
+    fn with_request_should_keep_status() {
+        let error = Error::status(
+            StatusCode::BAD_REQUEST,
+            Method::GET,
+            "/events?limit=1".to_owned(),
+            "bad",
+        )
+        .with_request(&Method::GET, "/events?limit=1");
+
+        assert!(error.downcast_ref::<Http>().is_none());
+        assert_eq!(
+            error.to_string(),
+            "Status: GET /events?limit=1 -> 400 Bad Request: bad"
+        );
+    }
+
     #[test]
     fn geoblock_into_error_should_succeed() {
         let geoblock = Geoblock {
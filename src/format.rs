@@ -0,0 +1,100 @@
+//! Display helpers for amounts, prices and percentages.
+//!
+//! All helpers round half away from zero and never print a negative zero, so values that round
+//! to zero are shown without a sign.
+
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "format_ prefix keeps the helpers readable when imported on their own"
+)]
+
+use rust_decimal::RoundingStrategy;
+
+use crate::types::Decimal;
+
+/// Formats `value` as US dollars with two decimal places and thousands separators, e.g.
+/// `-$1,234.57`.
+#[must_use]
+pub fn format_usd(value: Decimal) -> String {
+    let rounded = round(value, 2);
+    let sign = if rounded.is_sign_negative() { "-" } else { "" };
+    let digits = format!("{:.2}", rounded.abs());
+    let (whole, fraction) = digits.split_once('.').unwrap_or((&digits, "00"));
+
+    format!("{sign}${}.{fraction}", group_thousands(whole))
+}
+
+/// Formats a probability price (between 0 and 1) as cents, e.g. `0.555` as `55.5¢`.
+///
+/// Prices are shown to a tenth of a cent, which covers the smallest tick size, with trailing
+/// zeros dropped.
+#[must_use]
+pub fn format_price(price: Decimal) -> String {
+    let cents = round(price * Decimal::ONE_HUNDRED, 1).normalize();
+
+    format!("{cents}¢")
+}
+
+/// Formats a fraction as a percentage with two decimal places, e.g. `0.1234` as `12.34%`.
+#[must_use]
+pub fn format_pct(fraction: Decimal) -> String {
+    let pct = round(fraction * Decimal::ONE_HUNDRED, 2);
+
+    format!("{pct:.2}%")
+}
+
+fn round(value: Decimal, dp: u32) -> Decimal {
+    let mut rounded = value.round_dp_with_strategy(dp, RoundingStrategy::MidpointAwayFromZero);
+    if rounded.is_zero() {
+        rounded.set_sign_positive(true);
+    }
+
+    rounded
+}
+
+fn group_thousands(digits: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn format_usd_should_group_and_round() {
+        assert_eq!(format_usd(dec!(0)), "$0.00");
+        assert_eq!(format_usd(dec!(5)), "$5.00");
+        assert_eq!(format_usd(dec!(999.999)), "$1,000.00");
+        assert_eq!(format_usd(dec!(1234567.125)), "$1,234,567.13");
+        assert_eq!(format_usd(dec!(-1234.5)), "-$1,234.50");
+        assert_eq!(format_usd(dec!(-0.001)), "$0.00");
+    }
+
+    #[test]
+    fn format_price_should_show_cents() {
+        assert_eq!(format_price(dec!(0.55)), "55¢");
+        assert_eq!(format_price(dec!(0.555)), "55.5¢");
+        assert_eq!(format_price(dec!(0.0005)), "0.1¢");
+        assert_eq!(format_price(dec!(1)), "100¢");
+        assert_eq!(format_price(dec!(0)), "0¢");
+    }
+
+    #[test]
+    fn format_pct_should_use_two_decimal_places() {
+        assert_eq!(format_pct(dec!(0.1234)), "12.34%");
+        assert_eq!(format_pct(dec!(0.5)), "50.00%");
+        assert_eq!(format_pct(dec!(-0.00125)), "-0.13%");
+        assert_eq!(format_pct(dec!(-0.00001)), "0.00%");
+        assert_eq!(format_pct(dec!(1.5)), "150.00%");
+    }
+}
@@ -0,0 +1,109 @@
+//! Conditional request cache behind [`Client::with_etag_cache`](super::Client::with_etag_cache).
+
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use reqwest::header::{ETAG, HeaderValue, IF_NONE_MATCH};
+use reqwest::{Client as ReqwestClient, Request, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::Result;
+use crate::error::Error;
+use crate::response::Response;
+
+/// A response body together with the `ETag` the server sent for it.
+#[derive(Clone, Debug)]
+struct Entry {
+    etag: HeaderValue,
+    body: Arc<[u8]>,
+}
+
+/// Response bodies keyed by request URL, revalidated with `If-None-Match`.
+#[derive(Debug)]
+pub(crate) struct EtagCache {
+    entries: DashMap<String, Entry>,
+    capacity: usize,
+}
+
+impl EtagCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            entries: DashMap::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Sends `request`, revalidating a cached body for its URL if there is one.
+    ///
+    /// A `304 Not Modified` answer is served from the cached body, with the status and rate limit
+    /// headers of the `304`. A successful answer with an `ETag` replaces the cached body, and one
+    /// without drops it, so servers that do not send `ETag`s behave as if there were no cache.
+    pub(crate) async fn execute<Res: DeserializeOwned>(
+        &self,
+        client: &ReqwestClient,
+        mut request: Request,
+    ) -> Result<Response<Res>> {
+        let method = request.method().clone();
+        let path = crate::request_path(request.url());
+        let key = request.url().to_string();
+
+        let cached = self.entries.get(&key).map(|entry| entry.value().clone());
+        if let Some(entry) = &cached {
+            request
+                .headers_mut()
+                .insert(IF_NONE_MATCH, entry.etag.clone());
+        }
+
+        let response = client
+            .execute(request)
+            .await
+            .map_err(|e| Error::from(e).with_request(&method, &path))?;
+        let status_code = response.status();
+        let headers = response.headers().clone();
+
+        if status_code == StatusCode::NOT_MODIFIED
+            && let Some(entry) = cached
+        {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(method = %method, path = %path, "Serving response from ETag cache");
+
+            return crate::decode_response(&method, &path, status_code, &headers, &entry.body);
+        }
+
+        if !status_code.is_success() {
+            return Err(crate::status_error(response, method, path).await);
+        }
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| Error::from(e).with_request(&method, &path))?;
+
+        match headers.get(ETAG) {
+            Some(etag) => self.insert(
+                key,
+                Entry {
+                    etag: etag.clone(),
+                    body: Arc::from(body.as_ref()),
+                },
+            ),
+            None => {
+                self.entries.remove(&key);
+            }
+        }
+
+        crate::decode_response(&method, &path, status_code, &headers, &body)
+    }
+
+    /// Stores `entry`, evicting an arbitrary other entry if the cache is full.
+    fn insert(&self, key: String, entry: Entry) {
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            let evicted = self.entries.iter().next().map(|entry| entry.key().clone());
+            if let Some(evicted) = evicted {
+                self.entries.remove(&evicted);
+            }
+        }
+
+        self.entries.insert(key, entry);
+    }
+}
@@ -15,7 +15,7 @@
 //! let request = EventsRequest::builder()
 //!     .active(true)
 //!     .limit(10)
-//!     .build();
+//!     .build()?;
 //!
 //! let events = client.events(&request).await?;
 //! for event in events {
@@ -25,36 +25,52 @@
 //! # }
 //! ```
 
+use std::collections::HashSet;
 use std::future::Future;
+#[cfg(feature = "cache")]
+use std::sync::Arc;
 
 use async_stream::try_stream;
-use futures::Stream;
-use reqwest::{
-    Client as ReqwestClient, Method,
-    header::{HeaderMap, HeaderValue},
-};
+use futures::{Stream, TryStreamExt as _, future};
+use reqwest::{Client as ReqwestClient, Method};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 #[cfg(feature = "tracing")]
 use tracing::warn;
 use url::Url;
 
+#[cfg(feature = "cache")]
+use super::cache::EtagCache;
+use super::types::GameStatus;
 use super::types::request::{
     CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest, EventByIdRequest,
     EventBySlugRequest, EventTagsRequest, EventsRequest, MarketByIdRequest, MarketBySlugRequest,
     MarketTagsRequest, MarketsRequest, PublicProfileRequest, RelatedTagsByIdRequest,
     RelatedTagsBySlugRequest, SearchRequest, SeriesByIdRequest, SeriesListRequest, TagByIdRequest,
-    TagBySlugRequest, TagsRequest, TeamsRequest,
+    TagBySlugRequest, TagsRequest, TeamByIdRequest, TeamBySlugRequest, TeamsRequest,
 };
 use super::types::response::{
-    Comment, Event, HealthResponse, Market, PublicProfile, RelatedTag, SearchResults, Series,
-    SportsMarketTypesResponse, SportsMetadata, Tag, Team,
+    Comment, Event, GammaPage, HealthResponse, Market, PaginatedResponse, PublicProfile,
+    RelatedTag, SearchResults, Series, SportsGame, SportsMarketTypesResponse, SportsMetadata, Tag,
+    Team,
 };
 use crate::error::Error;
-use crate::{Result, ToQueryParams as _};
+use crate::response::Response;
+use crate::util::{fan_out_collect, fan_out_fail_fast};
+use crate::{DEFAULT_USER_AGENT, Result, ToQueryParams as _};
 
 const MAX_LIMIT: i32 = 500;
 
+/// Slug of the tag that sports events are filed under.
+const SPORTS_TAG_SLUG: &str = "sports";
+
+/// Default number of requests that fan-out helpers such as [`Client::events_by_ids`] keep in
+/// flight.
+const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Page size used by [`Client::comments_all`] when the request has no `limit`.
+const DEFAULT_COMMENTS_PAGE_SIZE: i32 = 100;
+
 /// HTTP client for the Polymarket Gamma API.
 ///
 /// Provides methods for querying events, markets, tags, series, comments,
@@ -76,9 +92,17 @@ const MAX_LIMIT: i32 = 500;
 /// let client = Client::new("https://custom-api.example.com").unwrap();
 /// ```
 #[derive(Clone, Debug)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "`client` is the underlying reqwest client"
+)]
 pub struct Client {
     host: Url,
     client: ReqwestClient,
+    concurrency: usize,
+    allow_non_ok_health: bool,
+    #[cfg(feature = "cache")]
+    etag_cache: Option<Arc<EtagCache>>,
 }
 
 impl Default for Client {
@@ -99,37 +123,137 @@ impl Client {
     ///
     /// Returns an error if the URL is invalid or the HTTP client cannot be created.
     pub fn new(host: &str) -> Result<Client> {
-        let mut headers = HeaderMap::new();
-
-        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
-        headers.insert("Accept", HeaderValue::from_static("*/*"));
-        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
-        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
-        let client = ReqwestClient::builder().default_headers(headers).build()?;
-
         Ok(Self {
             host: Url::parse(host)?,
-            client,
+            client: crate::http_client(DEFAULT_USER_AGENT)?,
+            concurrency: DEFAULT_CONCURRENCY,
+            allow_non_ok_health: false,
+            #[cfg(feature = "cache")]
+            etag_cache: None,
         })
     }
 
+    /// Sets how many requests fan-out helpers such as [`Self::events_by_ids`] and
+    /// [`Self::related_tags_bfs`] keep in flight at once. Defaults to 8; values below 1 are
+    /// treated as 1.
+    #[must_use]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets whether [`Self::status`] returns whatever body the health check sends. By default any
+    /// body other than "OK", such as a maintenance notice served with `200`, is reported as an
+    /// error of kind [`ServiceUnavailable`](crate::error::Kind::ServiceUnavailable).
+    #[must_use]
+    pub fn with_allow_non_ok_health(mut self, allow: bool) -> Self {
+        self.allow_non_ok_health = allow;
+        self
+    }
+
+    /// Enables conditional requests, keeping the bodies of up to `capacity` responses that came
+    /// with an `ETag`.
+    ///
+    /// Repeating a GET request for a cached URL sends its `ETag` as `If-None-Match`, and a
+    /// `304 Not Modified` answer is served from the cached body, so polling data that rarely
+    /// changes, e.g. with [`Self::markets`], transfers the body only when it changed. Responses
+    /// without an `ETag` are not cached. When the cache is full an arbitrary entry is evicted.
+    /// A `capacity` of 0 disables the cache, which is the default.
+    ///
+    /// Clones of the client share the cache.
+    ///
+    /// **Feature flag:** `cache`
+    #[cfg(feature = "cache")]
+    #[must_use]
+    pub fn with_etag_cache(mut self, capacity: usize) -> Self {
+        self.etag_cache = (capacity > 0).then(|| Arc::new(EtagCache::new(capacity)));
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request, replacing the default
+    /// [`DEFAULT_USER_AGENT`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `user_agent` is not a valid header value or the HTTP client cannot be
+    /// created.
+    pub fn with_user_agent(mut self, user_agent: &str) -> Result<Self> {
+        self.client = crate::http_client(user_agent)?;
+        Ok(self)
+    }
+
     /// Returns the base URL of the API.
     #[must_use]
     pub fn host(&self) -> &Url {
         &self.host
     }
 
-    async fn get<Req: Serialize, Res: DeserializeOwned + Serialize>(
+    /// Returns the underlying HTTP client, for sending other requests through the same connection
+    /// pool and TLS configuration.
+    ///
+    /// Cloning a [`reqwest::Client`] is cheap and shares the pool.
+    #[must_use]
+    pub fn http_client(&self) -> &ReqwestClient {
+        &self.client
+    }
+
+    /// Like [`Self::get_raw`], but returns the body together with the HTTP status and rate limit
+    /// headers. See [raw requests](crate::response#raw-requests).
+    pub async fn get_with_meta<Req: Serialize, Res: DeserializeOwned>(
         &self,
         path: &str,
         req: &Req,
-    ) -> Result<Res> {
+    ) -> Result<Response<Res>> {
+        let path = path.trim_start_matches('/');
         let query = req.query_params(None);
         let request = self
             .client
             .request(Method::GET, format!("{}{path}{query}", self.host))
             .build()?;
-        crate::request(&self.client, request, None).await
+
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.etag_cache {
+            return cache.execute(&self.client, request).await;
+        }
+
+        crate::request_with_meta(&self.client, request, None).await
+    }
+
+    /// Sends a GET request to an endpoint this client does not wrap yet and deserializes the body
+    /// into `Res`.
+    ///
+    /// See [raw requests](crate::response#raw-requests) for how the request is sent and the
+    /// stability caveats.
+    pub async fn get_raw<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &Req,
+    ) -> Result<Res> {
+        self.get_with_meta(path, query)
+            .await
+            .map(Response::into_data)
+    }
+
+    /// Joins `segments` into a path relative to [`Self::host`], percent-encoding each one so that
+    /// a slug containing `/`, `?` or `#` stays a single path segment.
+    fn segments_path(&self, segments: &[&str]) -> String {
+        let mut url = self.host.clone();
+        if let Ok(mut path) = url.path_segments_mut() {
+            path.pop_if_empty().extend(segments);
+        }
+
+        url.path()
+            .strip_prefix(self.host.path())
+            .unwrap_or_default()
+            .to_owned()
+    }
+
+    async fn get<Req: Serialize, Res: DeserializeOwned + Serialize>(
+        &self,
+        path: &str,
+        req: &Req,
+    ) -> Result<Res> {
+        self.get_with_meta(path, req).await.map(Response::into_data)
     }
 
     /// Performs a health check on the Gamma API.
@@ -139,27 +263,45 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns an error if the API is unreachable or returns a non-200 status code.
+    /// Returns an error if the API is unreachable or returns a non-200 status code. Unless
+    /// [`Self::with_allow_non_ok_health`] is set, a successful response with any body other than
+    /// "OK" is reported as an error of kind
+    /// [`ServiceUnavailable`](crate::error::Kind::ServiceUnavailable).
     pub async fn status(&self) -> Result<HealthResponse> {
         let request = self
             .client
             .request(Method::GET, format!("{}status", self.host))
             .build()?;
+        let path = crate::request_path(request.url());
 
-        let response = self.client.execute(request).await?;
+        let response = self
+            .client
+            .execute(request)
+            .await
+            .map_err(|e| Error::from(e).with_request(&Method::GET, &path))?;
         let status_code = response.status();
 
         if !status_code.is_success() {
+            let headers = response.headers().clone();
             let message = response.text().await.unwrap_or_default();
-            return Err(Error::status(
+            return Err(Error::status_with_headers(
                 status_code,
                 Method::GET,
-                "status".to_owned(),
+                path,
                 message,
+                &headers,
             ));
         }
 
-        Ok(response.text().await?)
+        let message = response
+            .text()
+            .await
+            .map_err(|e| Error::from(e).with_request(&Method::GET, &path))?;
+        if self.allow_non_ok_health {
+            Ok(message)
+        } else {
+            crate::ensure_ok(message)
+        }
     }
 
     /// Retrieves a list of sports teams with optional filtering.
@@ -174,6 +316,36 @@ impl Client {
         self.get("teams", request).await
     }
 
+    /// Retrieves a single team by its unique ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails. An unknown ID yields a [`Status`] error with
+    /// [`StatusCode::NOT_FOUND`].
+    ///
+    /// [`Status`]: crate::error::Status
+    /// [`StatusCode::NOT_FOUND`]: crate::error::StatusCode::NOT_FOUND
+    pub async fn team_by_id(&self, request: &TeamByIdRequest) -> Result<Team> {
+        self.get(&format!("teams/{}", request.id), request).await
+    }
+
+    /// Retrieves a single team by its URL-friendly slug.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails. An unknown slug yields a [`Status`] error with
+    /// [`StatusCode::NOT_FOUND`].
+    ///
+    /// [`Status`]: crate::error::Status
+    /// [`StatusCode::NOT_FOUND`]: crate::error::StatusCode::NOT_FOUND
+    pub async fn team_by_slug(&self, request: &TeamBySlugRequest) -> Result<Team> {
+        self.get(
+            &self.segments_path(&["teams", "slug", &request.slug]),
+            request,
+        )
+        .await
+    }
+
     /// Retrieves metadata for all supported sports.
     ///
     /// Returns information about sports categories available on Polymarket,
@@ -187,6 +359,43 @@ impl Client {
         self.get("sports", &()).await
     }
 
+    /// Retrieves the sports games in progress, with their score, period and markets.
+    ///
+    /// Gamma reports live game state on the events of the `sports` tag, so this pages through its
+    /// open events and keeps those that are live. Use [`SportsGame::related_markets`] to match
+    /// games against markets fetched separately.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any page request fails.
+    pub async fn sports_live(&self) -> Result<Vec<SportsGame>> {
+        let request = EventsRequest {
+            tag_slug: Some(SPORTS_TAG_SLUG.to_owned()),
+            active: Some(true),
+            closed: Some(false),
+            ..EventsRequest::default()
+        };
+
+        self.stream_data(
+            move |client, limit, offset| {
+                let request = EventsRequest {
+                    limit: Some(limit),
+                    offset: Some(offset),
+                    ..request.clone()
+                };
+                async move { client.events(&request).await }
+            },
+            MAX_LIMIT,
+        )
+        .try_filter_map(|event| {
+            let game =
+                SportsGame::from_event(&event).filter(|game| game.status == GameStatus::Live);
+            future::ready(Ok(game))
+        })
+        .try_collect()
+        .await
+    }
+
     /// Retrieves valid market types for sports.
     ///
     /// Returns the different types of sports markets available (e.g., moneyline,
@@ -232,8 +441,11 @@ impl Client {
     ///
     /// Returns an error if the slug is invalid or the request fails.
     pub async fn tag_by_slug(&self, request: &TagBySlugRequest) -> Result<Tag> {
-        self.get(&format!("tags/slug/{}", request.slug), request)
-            .await
+        self.get(
+            &self.segments_path(&["tags", "slug", &request.slug]),
+            request,
+        )
+        .await
     }
 
     /// Retrieves related tag relationships for a tag by ID.
@@ -264,8 +476,11 @@ impl Client {
         &self,
         request: &RelatedTagsBySlugRequest,
     ) -> Result<Vec<RelatedTag>> {
-        self.get(&format!("tags/slug/{}/related-tags", request.slug), request)
-            .await
+        self.get(
+            &self.segments_path(&["tags", "slug", &request.slug, "related-tags"]),
+            request,
+        )
+        .await
     }
 
     /// Retrieves tags that are related to a specified tag by ID.
@@ -284,6 +499,49 @@ impl Client {
             .await
     }
 
+    /// Walks the related-tag graph breadth-first from `start_id`, up to `depth` levels deep.
+    ///
+    /// Each level is fetched with [`Self::tags_related_to_tag_by_id`], keeping at most
+    /// [`Self::with_concurrency`] requests in flight. Every tag is returned once, paired with the level it was first
+    /// discovered at (1 for tags directly related to `start_id`), in discovery order. The start
+    /// tag itself is not included, and tags that were already visited are not expanded again, so
+    /// cycles in the graph are harmless.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the requests fail.
+    pub async fn related_tags_bfs(
+        &self,
+        start_id: &str,
+        depth: usize,
+    ) -> Result<Vec<(Tag, usize)>> {
+        let mut visited = HashSet::from([start_id.to_owned()]);
+        let mut frontier = vec![start_id.to_owned()];
+        let mut tags = Vec::new();
+
+        for level in 1..=depth {
+            if frontier.is_empty() {
+                break;
+            }
+
+            let pages = fan_out_fail_fast(frontier, self.concurrency, |id| {
+                let request = RelatedTagsByIdRequest::builder().id(id).build();
+                async move { self.tags_related_to_tag_by_id(&request).await }
+            })
+            .await?;
+
+            frontier = Vec::new();
+            for tag in pages.into_iter().flatten() {
+                if visited.insert(tag.id.clone()) {
+                    frontier.push(tag.id.clone());
+                    tags.push((tag, level));
+                }
+            }
+        }
+
+        Ok(tags)
+    }
+
     /// Retrieves tags that are related to a specified tag by slug.
     ///
     /// Same as [`Self::tags_related_to_tag_by_id`] but uses a slug identifier instead of an ID.
@@ -296,7 +554,7 @@ impl Client {
         request: &RelatedTagsBySlugRequest,
     ) -> Result<Vec<Tag>> {
         self.get(
-            &format!("tags/slug/{}/related-tags/tags", request.slug),
+            &self.segments_path(&["tags", "slug", &request.slug, "related-tags", "tags"]),
             request,
         )
         .await
@@ -307,13 +565,48 @@ impl Client {
     /// Events are collections of related markets (e.g., "2024 Presidential Election").
     /// Use filters to search by tags, active status, or other criteria.
     ///
+    /// The API does not report how many events match; use [`Self::events_page`] when the total
+    /// is needed.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the request fails.
+    /// Returns an error if the request fails, or if `request` fails the checks of
+    /// [`EventsRequestBuilder::build`](crate::gamma::types::request::EventsRequestBuilder::build),
+    /// which also apply to requests built without the builder.
     pub async fn events(&self, request: &EventsRequest) -> Result<Vec<Event>> {
+        request.validate()?;
+
         self.get("events", request).await
     }
 
+    /// Retrieves one page of events together with the total number of matching events, for
+    /// rendering paginated lists.
+    ///
+    /// Takes the same filters as [`Self::events`] but calls `/events/pagination`, which also
+    /// reports the total. The request's `limit` and `offset` are echoed back in the page.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, or if `request` fails the checks of
+    /// [`Self::events`].
+    pub async fn events_page(&self, request: &EventsRequest) -> Result<GammaPage<Event>> {
+        request.validate()?;
+
+        let response: PaginatedResponse<Event> = self
+            .get_with_meta("events/pagination", request)
+            .await?
+            .into_data();
+
+        Ok(GammaPage {
+            data: response.data,
+            total: response
+                .pagination
+                .and_then(|pagination| pagination.total_results),
+            limit: request.limit,
+            offset: request.offset,
+        })
+    }
+
     /// Retrieves a single event by its unique ID.
     ///
     /// Returns detailed information about an event including its markets,
@@ -326,6 +619,19 @@ impl Client {
         self.get(&format!("events/{}", request.id), request).await
     }
 
+    /// Fetches several events by ID concurrently, keeping at most [`Self::with_concurrency`]
+    /// requests in flight.
+    ///
+    /// Returns one result per ID, in the same order as `ids`, so a missing event does not hide
+    /// the others.
+    pub async fn events_by_ids<S: AsRef<str>>(&self, ids: &[S]) -> Vec<Result<Event>> {
+        fan_out_collect(ids, self.concurrency, |id| {
+            let request = EventByIdRequest::builder().id(id.as_ref()).build();
+            async move { self.event_by_id(&request).await }
+        })
+        .await
+    }
+
     /// Retrieves a single event by its URL-friendly slug.
     ///
     /// Returns the same information as [`Self::event_by_id`] but uses a slug
@@ -335,8 +641,30 @@ impl Client {
     ///
     /// Returns an error if the slug is invalid or the request fails.
     pub async fn event_by_slug(&self, request: &EventBySlugRequest) -> Result<Event> {
-        self.get(&format!("events/slug/{}", request.slug), request)
-            .await
+        self.get(
+            &self.segments_path(&["events", "slug", &request.slug]),
+            request,
+        )
+        .await
+    }
+
+    /// Retrieves a single event by either its ID or its slug.
+    ///
+    /// Inputs made up only of ASCII digits are treated as IDs and fetched with
+    /// [`Self::event_by_id`]; anything else is fetched with [`Self::event_by_slug`]. Use
+    /// [`Self::event_by_slug`] directly for the rare event whose slug is all digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no event matches or the request fails.
+    pub async fn event(&self, id_or_slug: &str) -> Result<Event> {
+        if is_id(id_or_slug) {
+            self.event_by_id(&EventByIdRequest::builder().id(id_or_slug).build())
+                .await
+        } else {
+            self.event_by_slug(&EventBySlugRequest::builder().slug(id_or_slug).build())
+                .await
+        }
     }
 
     /// Retrieves all tags associated with an event.
@@ -358,11 +686,52 @@ impl Client {
     /// by tags, events, active status, or CLOB token IDs. Returns market details
     /// including current prices, volume, and outcome information.
     ///
+    /// If [`MarketsRequest::tag_slug`] is set, the slug is resolved to a tag ID with
+    /// [`Client::tag_by_slug`] before the markets are fetched, costing one extra request.
+    ///
+    /// The API does not report how many markets match, so there is no paginated variant with a
+    /// total.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the request fails.
+    /// Returns an error if the request fails, if the tag slug does not exist, if both
+    /// `tag_id` and `tag_slug` are set, if `sort_by` is combined with `order`, or if `status` is
+    /// combined with `closed` or `uma_resolution_status`.
     pub async fn markets(&self, request: &MarketsRequest) -> Result<Vec<Market>> {
-        self.get("markets", request).await
+        if request.sort_by.is_some() && request.order.is_some() {
+            return Err(Error::validation(
+                "Unable to fetch markets: sort_by cannot be combined with order",
+            ));
+        }
+
+        if request.status.is_some()
+            && (request.closed.is_some() || request.uma_resolution_status.is_some())
+        {
+            return Err(Error::validation(
+                "Unable to fetch markets: status cannot be combined with closed or \
+                 uma_resolution_status",
+            ));
+        }
+
+        let Some(slug) = &request.tag_slug else {
+            return self.get("markets", request).await;
+        };
+
+        if let Some(tag_id) = &request.tag_id {
+            return Err(Error::validation(format!(
+                "Unable to fetch markets: both tag_id {tag_id} and tag_slug {slug} are set"
+            )));
+        }
+
+        let tag = self
+            .tag_by_slug(&TagBySlugRequest::builder().slug(slug).build())
+            .await?;
+        let request = MarketsRequest {
+            tag_id: Some(tag.id),
+            ..request.clone()
+        };
+
+        self.get("markets", &request).await
     }
 
     /// Retrieves a single market by its unique ID.
@@ -386,8 +755,30 @@ impl Client {
     ///
     /// Returns an error if the slug is invalid or the request fails.
     pub async fn market_by_slug(&self, request: &MarketBySlugRequest) -> Result<Market> {
-        self.get(&format!("markets/slug/{}", request.slug), request)
-            .await
+        self.get(
+            &self.segments_path(&["markets", "slug", &request.slug]),
+            request,
+        )
+        .await
+    }
+
+    /// Retrieves a single market by either its ID or its slug.
+    ///
+    /// Inputs made up only of ASCII digits are treated as IDs and fetched with
+    /// [`Self::market_by_id`]; anything else is fetched with [`Self::market_by_slug`]. Use
+    /// [`Self::market_by_slug`] directly for the rare market whose slug is all digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no market matches or the request fails.
+    pub async fn market(&self, id_or_slug: &str) -> Result<Market> {
+        if is_id(id_or_slug) {
+            self.market_by_id(&MarketByIdRequest::builder().id(id_or_slug).build())
+                .await
+        } else {
+            self.market_by_slug(&MarketBySlugRequest::builder().slug(id_or_slug).build())
+                .await
+        }
     }
 
     /// Retrieves all tags associated with a market.
@@ -435,11 +826,46 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails.
+    /// Returns an error if the request fails, or if `sort_by` is combined with `order` or
+    /// `ascending`.
     pub async fn comments(&self, request: &CommentsRequest) -> Result<Vec<Comment>> {
+        if request.sort_by.is_some() && (request.order.is_some() || request.ascending.is_some()) {
+            return Err(Error::validation(
+                "Unable to fetch comments: sort_by cannot be combined with order or ascending",
+            ));
+        }
+
         self.get("comments", request).await
     }
 
+    /// Streams every comment matching `request`, paging through `/comments` with `offset`.
+    ///
+    /// The request's `limit` is used as the page size (100 if unset) and its `offset` is
+    /// ignored. Set `sort_by` so that the order is stable across pages.
+    ///
+    /// # Errors
+    ///
+    /// Yields an error if any page request fails, after which the stream ends.
+    pub fn comments_all(
+        &self,
+        request: &CommentsRequest,
+    ) -> impl Stream<Item = Result<Comment>> + '_ {
+        let limit = request.limit.unwrap_or(DEFAULT_COMMENTS_PAGE_SIZE);
+        let request = request.clone();
+
+        self.stream_data(
+            move |client, limit, offset| {
+                let request = CommentsRequest {
+                    limit: Some(limit),
+                    offset: Some(offset),
+                    ..request.clone()
+                };
+                async move { client.comments(&request).await }
+            },
+            limit,
+        )
+    }
+
     /// Retrieves comments by their unique comment ID.
     ///
     /// Returns comments with the specified ID, including nested replies and
@@ -530,7 +956,7 @@ impl Client {
     ///             .limit(limit)
     ///             .offset(offset)
     ///             .build();
-    ///         async move { client.events(&request).await }
+    ///         async move { client.events(&request?).await }
     ///     },
     ///     100, // page size
     /// );
@@ -594,3 +1020,8 @@ impl Client {
         }
     }
 }
+
+/// Whether `id_or_slug` looks like a numeric Gamma ID rather than a slug.
+fn is_id(id_or_slug: &str) -> bool {
+    !id_or_slug.is_empty() && id_or_slug.bytes().all(|b| b.is_ascii_digit())
+}
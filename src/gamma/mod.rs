@@ -51,7 +51,7 @@
 //! let request = EventsRequest::builder()
 //!     .active(true)
 //!     .limit(10)
-//!     .build();
+//!     .build()?;
 //!
 //! // Fetch events
 //! let events = client.events(&request).await?;
@@ -67,6 +67,8 @@
 //!
 //! The default API endpoint is `https://gamma-api.polymarket.com`.
 
+#[cfg(feature = "cache")]
+mod cache;
 pub mod client;
 pub mod types;
 
@@ -18,17 +18,21 @@
 //! ```
 //! use polymarket_client_sdk::gamma::types::request::{EventsRequest, MarketsRequest};
 //!
+//! # fn main() -> polymarket_client_sdk::Result<()> {
 //! // Simple request with defaults
-//! let events = EventsRequest::builder().build();
+//! let events = EventsRequest::builder().build()?;
 //!
 //! // Request with filters
 //! let markets = MarketsRequest::builder()
 //!     .limit(10)
 //!     .closed(false)
 //!     .build();
+//! # Ok(())
+//! # }
 //! ```
 
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeStruct as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 pub mod request;
 pub mod response;
@@ -58,3 +62,215 @@ pub enum ParentEntityType {
     #[serde(untagged)]
     Unknown(String),
 }
+
+/// Sort order for [`request::CommentsRequest::sort_by`].
+///
+/// Serialized as the `order` and `ascending` query parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CommentSort {
+    /// Most recently created first.
+    Newest,
+    /// Least recently created first.
+    Oldest,
+    /// Most reactions first.
+    MostReactions,
+}
+
+impl Serialize for CommentSort {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (order, ascending) = match self {
+            Self::Newest => ("createdAt", false),
+            Self::Oldest => ("createdAt", true),
+            Self::MostReactions => ("reactionCount", false),
+        };
+
+        let mut state = serializer.serialize_struct("CommentSort", 2)?;
+        state.serialize_field("order", order)?;
+        state.serialize_field("ascending", &ascending)?;
+        state.end()
+    }
+}
+
+/// Lifecycle filter for [`request::EventsRequest`] and [`request::MarketsRequest`], set with
+/// their `status` option.
+///
+/// Serialized as a consistent combination of the `active`, `closed` and `archived` query
+/// parameters, so it cannot be combined with them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatusFilter {
+    /// Open for trading: `active=true&closed=false&archived=false`.
+    Active,
+    /// No longer trading, whether or not the outcome is settled yet: `closed=true&archived=false`.
+    Closed,
+    /// Hidden from the site: `archived=true`.
+    Archived,
+    /// Closed with the outcome settled by the UMA oracle:
+    /// `closed=true&archived=false&uma_resolution_status=resolved`. Only `/markets` reports
+    /// resolution status, so for events this matches [`Closed`](Self::Closed).
+    Resolved,
+}
+
+impl Serialize for StatusFilter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("StatusFilter", 4)?;
+        match self {
+            Self::Active => {
+                state.serialize_field("active", &true)?;
+                state.serialize_field("closed", &false)?;
+                state.serialize_field("archived", &false)?;
+            }
+            Self::Closed => {
+                state.serialize_field("closed", &true)?;
+                state.serialize_field("archived", &false)?;
+            }
+            Self::Archived => {
+                state.serialize_field("archived", &true)?;
+            }
+            Self::Resolved => {
+                state.serialize_field("closed", &true)?;
+                state.serialize_field("archived", &false)?;
+                state.serialize_field("uma_resolution_status", "resolved")?;
+            }
+        }
+        state.end()
+    }
+}
+
+/// Field to sort [`request::EventsRequest`] and [`request::MarketsRequest`] results by, set with
+/// their `sort_by` option and serialized as the `order` query parameter. Combine it with
+/// `ascending(false)` for the largest values first, e.g. the most active markets by
+/// [`Volume24hr`](Self::Volume24hr).
+///
+/// The API sorts both `/events` and `/markets` by these fields. Any other field of
+/// [`response::Event`] or [`response::Market`] can still be passed by its camelCase name through
+/// the raw `order` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::Display)]
+#[non_exhaustive]
+pub enum SortField {
+    /// Lifetime traded volume.
+    #[strum(serialize = "volume")]
+    Volume,
+    /// Volume traded over the last 24 hours.
+    #[strum(serialize = "volume24hr")]
+    Volume24hr,
+    /// Volume traded over the last week.
+    #[strum(serialize = "volume1wk")]
+    Volume1wk,
+    /// Volume traded over the last month.
+    #[strum(serialize = "volume1mo")]
+    Volume1mo,
+    /// Volume traded over the last year.
+    #[strum(serialize = "volume1yr")]
+    Volume1yr,
+    /// Liquidity currently on the book.
+    #[strum(serialize = "liquidity")]
+    Liquidity,
+    /// How competitive the market is, a score between 0 and 1.
+    #[strum(serialize = "competitive")]
+    Competitive,
+    /// When trading opens.
+    #[strum(serialize = "startDate")]
+    StartDate,
+    /// When trading is scheduled to close.
+    #[strum(serialize = "endDate")]
+    EndDate,
+    /// When the event or market was created on Polymarket.
+    #[strum(serialize = "createdAt")]
+    CreatedAt,
+}
+
+impl Serialize for SortField {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// A sports league, as reported by [`response::SportsMetadata::sport`] and
+/// [`response::Team::league`].
+///
+/// The API is inconsistent about casing (`"nba"` for sports but `"NBA"` for teams), so leagues are
+/// matched case-insensitively. They serialize as lowercase codes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, strum_macros::Display)]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum League {
+    Nfl,
+    Nba,
+    Wnba,
+    Mlb,
+    Nhl,
+    /// College football.
+    Cfb,
+    /// College basketball.
+    Ncaab,
+    Mls,
+    /// English Premier League.
+    Epl,
+    /// UEFA Champions League.
+    Ucl,
+    /// League not known to this SDK (captures the raw value).
+    #[strum(to_string = "{0}")]
+    Unknown(String),
+}
+
+impl Serialize for League {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for League {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+
+        Ok(match raw.to_ascii_lowercase().as_str() {
+            "nfl" => Self::Nfl,
+            "nba" => Self::Nba,
+            "wnba" => Self::Wnba,
+            "mlb" => Self::Mlb,
+            "nhl" => Self::Nhl,
+            "cfb" => Self::Cfb,
+            "ncaab" => Self::Ncaab,
+            "mls" => Self::Mls,
+            "epl" => Self::Epl,
+            "ucl" => Self::Ucl,
+            _ => Self::Unknown(raw),
+        })
+    }
+}
+
+/// Where a [`response::SportsGame`] is in its lifecycle.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[non_exhaustive]
+pub enum GameStatus {
+    /// The game has not started yet.
+    Scheduled,
+    /// The game is in progress.
+    Live,
+    /// The game has finished.
+    Ended,
+}
+
+/// A kind of sports market, as reported by [`response::SportsMarketTypesResponse`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, strum_macros::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[non_exhaustive]
+pub enum SportsMarketType {
+    /// Which side wins outright.
+    Moneyline,
+    /// Whether a side wins by more than a handicap.
+    Spreads,
+    /// Whether the combined score is over or under a line.
+    Totals,
+    /// Market type not known to this SDK (captures the raw value).
+    #[serde(untagged)]
+    #[strum(to_string = "{0}")]
+    Unknown(String),
+}
@@ -8,28 +8,73 @@ use chrono::{DateTime, Utc};
 use serde::Serialize;
 use serde_with::{DisplayFromStr, serde_as, skip_serializing_none};
 
-use crate::gamma::types::{ParentEntityType, RelatedTagsStatus};
+use crate::gamma::types::{
+    CommentSort, League, ParentEntityType, RelatedTagsStatus, SortField, StatusFilter,
+};
 use crate::types::{Address, B256, Decimal, U256};
 
+/// Filters for listing teams.
+///
+/// Leagues are set as codes with `league`, or added one at a time as a [`League`] with
+/// `add_league`, and match [`Team::league`](super::response::Team::league), e.g. `"NBA"`. The API
+/// does not document a fixed set of leagues, since new ones appear as sports are added, so codes
+/// are sent as given.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Default, Serialize)]
 #[non_exhaustive]
 pub struct TeamsRequest {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[builder(field)]
+    pub league: Vec<String>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
     pub order: Option<String>,
     pub ascending: Option<bool>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
-    pub league: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    #[builder(default)]
     pub name: Vec<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub abbreviation: Vec<String>,
 }
 
+impl<S: teams_request_builder::State> TeamsRequestBuilder<S> {
+    /// Only lists teams in any of the leagues with codes `league`, replacing those set before.
+    pub fn league(mut self, league: Vec<String>) -> Self {
+        self.league = league;
+        self
+    }
+
+    /// Also lists teams in `league`. Can be called repeatedly to match any of several leagues.
+    pub fn add_league(mut self, league: League) -> Self {
+        let code = match league {
+            League::Unknown(code) => code,
+            // Teams report their league in uppercase, e.g. "NBA"
+            league => league.to_string().to_ascii_uppercase(),
+        };
+        self.league.push(code);
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Builder, Serialize)]
+#[non_exhaustive]
+pub struct TeamByIdRequest {
+    #[serde(skip_serializing)]
+    #[builder(into)]
+    pub id: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Builder, Serialize)]
+#[non_exhaustive]
+pub struct TeamBySlugRequest {
+    #[serde(skip_serializing)]
+    #[builder(into)]
+    pub slug: String,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Default, Serialize)]
 #[non_exhaustive]
@@ -84,12 +129,22 @@ pub struct RelatedTagsBySlugRequest {
     pub status: Option<RelatedTagsStatus>,
 }
 
+/// Filters for listing events.
+///
+/// The `*_date_min`/`*_date_max` pairs bound an event's start and end dates, e.g. setting
+/// `end_date_min` to now and `end_date_max` to a week from now lists events closing this week.
+/// `build` rejects a pair whose minimum is after its maximum.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Default, Serialize)]
+#[builder(finish_fn(name = build_unchecked, vis = ""))]
 #[non_exhaustive]
 pub struct EventsRequest {
     pub limit: Option<i32>,
     pub offset: Option<i32>,
+    /// Field to sort by. Serialized as `order`, so it cannot be combined with it.
+    #[serde(rename = "order")]
+    pub sort_by: Option<SortField>,
+    /// Raw field names to sort by, for fields not covered by `sort_by`.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub order: Vec<String>,
@@ -105,8 +160,13 @@ pub struct EventsRequest {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub slug: Vec<String>,
+    #[builder(into)]
     pub tag_slug: Option<String>,
     pub related_tags: Option<bool>,
+    /// Lifecycle filter. Sets `active`, `closed` and `archived`, so it cannot be combined with
+    /// them.
+    #[serde(flatten)]
+    pub status: Option<StatusFilter>,
     pub active: Option<bool>,
     pub archived: Option<bool>,
     pub featured: Option<bool>,
@@ -125,6 +185,61 @@ pub struct EventsRequest {
     pub end_date_max: Option<DateTime<Utc>>,
 }
 
+impl<S: events_request_builder::IsComplete> EventsRequestBuilder<S> {
+    /// Builds the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `sort_by` is combined with `order`, `status` is combined
+    /// with `active`, `closed` or `archived`, `start_date_min` is after `start_date_max`, or
+    /// `end_date_min` is after `end_date_max`.
+    pub fn build(self) -> crate::Result<EventsRequest> {
+        let request = self.build_unchecked();
+        request.validate()?;
+
+        Ok(request)
+    }
+}
+
+impl EventsRequest {
+    /// Runs the checks of [`EventsRequestBuilder::build`], so that a request built without the
+    /// builder, e.g. with `..EventsRequest::default()`, is checked before it is sent.
+    pub(crate) fn validate(&self) -> crate::Result<()> {
+        if self.sort_by.is_some() && !self.order.is_empty() {
+            return Err(crate::error::Error::validation(
+                "Invalid EventsRequest: sort_by cannot be combined with order",
+            ));
+        }
+
+        if self.status.is_some()
+            && (self.active.is_some() || self.closed.is_some() || self.archived.is_some())
+        {
+            return Err(crate::error::Error::validation(
+                "Invalid EventsRequest: status cannot be combined with active, closed or archived",
+            ));
+        }
+
+        validate_date_range("start_date", self.start_date_min, self.start_date_max)?;
+        validate_date_range("end_date", self.end_date_min, self.end_date_max)
+    }
+}
+
+fn validate_date_range(
+    name: &str,
+    min: Option<DateTime<Utc>>,
+    max: Option<DateTime<Utc>>,
+) -> crate::Result<()> {
+    if let (Some(min), Some(max)) = (min, max)
+        && min > max
+    {
+        return Err(crate::error::Error::validation(format!(
+            "Invalid EventsRequest: {name}_min {min} is after {name}_max {max}"
+        )));
+    }
+
+    Ok(())
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
 #[non_exhaustive]
@@ -162,6 +277,10 @@ pub struct EventTagsRequest {
 pub struct MarketsRequest {
     pub limit: Option<i32>,
     pub offset: Option<i32>,
+    /// Field to sort by. Serialized as `order`, so it cannot be combined with it.
+    #[serde(rename = "order")]
+    pub sort_by: Option<SortField>,
+    /// Raw field name to sort by, for fields not covered by `sort_by`.
     pub order: Option<String>,
     pub ascending: Option<bool>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -170,12 +289,16 @@ pub struct MarketsRequest {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
     pub slug: Vec<String>,
+    /// Filters by outcome token ID, sent as one repeated `clob_token_ids` parameter per ID. An
+    /// empty list does not filter.
     #[serde_as(as = "Vec<DisplayFromStr>")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    #[builder(default)]
+    #[builder(default, into)]
     pub clob_token_ids: Vec<U256>,
+    /// Filters by condition ID, sent as one repeated `condition_ids` parameter per ID. An empty
+    /// list does not filter.
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    #[builder(default)]
+    #[builder(default, into)]
     pub condition_ids: Vec<B256>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
@@ -190,6 +313,12 @@ pub struct MarketsRequest {
     pub end_date_max: Option<DateTime<Utc>>,
     #[builder(into)]
     pub tag_id: Option<String>,
+    /// Filters by tag slug. `/markets` only filters by tag ID, so
+    /// [`Client::markets`](crate::gamma::Client::markets) resolves the slug with an extra
+    /// `/tags/slug` lookup first. Cannot be combined with `tag_id`.
+    #[serde(skip_serializing)]
+    #[builder(into)]
+    pub tag_slug: Option<String>,
     pub related_tags: Option<bool>,
     pub cyom: Option<bool>,
     pub uma_resolution_status: Option<String>,
@@ -202,6 +331,11 @@ pub struct MarketsRequest {
     #[builder(default)]
     pub question_ids: Vec<B256>,
     pub include_tag: Option<bool>,
+    /// Lifecycle filter. Sets `closed`, `active`, `archived` and, for
+    /// [`StatusFilter::Resolved`], `uma_resolution_status`, so it cannot be combined with
+    /// `closed` or `uma_resolution_status`.
+    #[serde(flatten)]
+    pub status: Option<StatusFilter>,
     pub closed: Option<bool>,
 }
 
@@ -265,6 +399,10 @@ pub struct SeriesByIdRequest {
     pub include_chat: Option<bool>,
 }
 
+/// Request parameters for the `/comments` endpoint.
+///
+/// Without `sort_by` or `order`, comments come back in the API's default order, which it does
+/// not document. Set one of them whenever the order matters, e.g. when paging.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Builder, Serialize)]
 #[non_exhaustive]
@@ -274,6 +412,10 @@ pub struct CommentsRequest {
     pub parent_entity_id: String,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
+    /// Common sort orders. Sets `order` and `ascending`, so it cannot be combined with them.
+    #[serde(flatten)]
+    pub sort_by: Option<CommentSort>,
+    /// Raw field name to sort by, for orders not covered by `sort_by`.
     pub order: Option<String>,
     pub ascending: Option<bool>,
     pub get_positions: Option<bool>,
@@ -316,6 +458,7 @@ pub struct SearchRequest {
     pub q: String,
     pub cache: Option<bool>,
     pub events_status: Option<String>,
+    /// Maximum number of results returned for each category (events, tags and profiles).
     pub limit_per_type: Option<i32>,
     pub page: Option<i32>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -324,7 +467,10 @@ pub struct SearchRequest {
     pub keep_closed_markets: Option<i32>,
     pub sort: Option<String>,
     pub ascending: Option<bool>,
+    /// Whether to include matching tags in [`SearchResults::tags`](super::response::SearchResults::tags).
     pub search_tags: Option<bool>,
+    /// Whether to include matching profiles in
+    /// [`SearchResults::profiles`](super::response::SearchResults::profiles).
     pub search_profiles: Option<bool>,
     pub recurrence: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
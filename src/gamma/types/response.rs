@@ -6,12 +6,16 @@
 use bon::Builder;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
-use serde_with::NoneAsEmptyString;
 use serde_with::json::JsonString;
-use serde_with::{DisplayFromStr, StringWithSeparator, formats::CommaSeparator, serde_as};
+use serde_with::{DefaultOnNull, NoneAsEmptyString};
+use serde_with::{
+    DisplayFromStr, StringWithSeparator, VecSkipError, formats::CommaSeparator, serde_as,
+};
 
-use crate::serde_helpers::StringFromAny;
-use crate::types::{Address, B256, Decimal, U256};
+use super::{GameStatus, League, SportsMarketType};
+use crate::error::Error;
+use crate::serde_helpers::{FlexibleDecimal, FlexibleTimestamp, FlexibleU256, StringFromAny};
+use crate::types::{Address, B256, Decimal, MarketId, U256};
 
 /// Image optimization metadata.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
@@ -40,22 +44,77 @@ pub struct Pagination {
     pub total_results: Option<i32>,
 }
 
+/// One page of a paginated list, together with the total number of matching items.
+///
+/// Only some Gamma endpoints report totals, e.g. `/events/pagination`; see
+/// [`Client::events_page`](crate::gamma::Client::events_page). The other list endpoints return
+/// a bare `Vec` because the API does not say how many items match.
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[non_exhaustive]
+pub struct GammaPage<T> {
+    /// The items on this page.
+    pub data: Vec<T>,
+    /// Total number of items matching the request across all pages, if the API reported it.
+    pub total: Option<i32>,
+    /// Page size that was requested, if any.
+    pub limit: Option<i32>,
+    /// Number of items skipped before this page, if any.
+    pub offset: Option<i32>,
+}
+
+impl<T> GammaPage<T> {
+    /// Returns the number of pages needed to show [`Self::total`] items at [`Self::limit`] items
+    /// per page, or `None` if either is unknown or the limit is not positive.
+    #[must_use]
+    pub fn page_count(&self) -> Option<i32> {
+        let total = self.total?;
+        let limit = self.limit.filter(|limit| *limit > 0)?;
+
+        // Signed `div_ceil` is unstable, and both are non-negative here
+        let pages = total.max(0).unsigned_abs().div_ceil(limit.unsigned_abs());
+
+        i32::try_from(pages).ok()
+    }
+
+    /// Returns the zero-based index of this page, or `None` if the limit is unknown or not
+    /// positive.
+    #[must_use]
+    pub fn page_index(&self) -> Option<i32> {
+        let limit = self.limit.filter(|limit| *limit > 0)?;
+
+        Some(self.offset.unwrap_or_default().max(0) / limit)
+    }
+}
+
+/// Wire format of the `/events/pagination` endpoint.
+#[derive(Debug, Deserialize)]
+pub(crate) struct PaginatedResponse<T> {
+    #[serde(default = "Vec::new")]
+    pub(crate) data: Vec<T>,
+    pub(crate) pagination: Option<Pagination>,
+}
+
 /// Health check response.
 pub type HealthResponse = String;
 
 /// A sports team.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct Team {
     pub id: i32,
     pub name: Option<String>,
-    pub league: Option<String>,
+    pub league: Option<League>,
     pub record: Option<String>,
     pub logo: Option<String>,
     pub abbreviation: Option<String>,
     pub alias: Option<String>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
     pub color: Option<String>,
     pub provider_id: Option<i32>,
@@ -68,25 +127,101 @@ pub struct Team {
 #[non_exhaustive]
 pub struct SportsMetadata {
     pub id: Option<i32>,
-    pub sport: String,
+    pub sport: League,
     pub image: String,
     pub resolution: String,
     pub ordering: String,
     #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
     pub tags: Vec<String>,
     pub series: String,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
 }
 
+/// The current state of a sports game, taken from the live fields of its [`Event`].
+///
+/// Gamma has no dedicated scores endpoint; the score, period and status are reported on the
+/// game's event and refreshed while the game is live. See
+/// [`Client::sports_live`](crate::gamma::Client::sports_live).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SportsGame {
+    /// The game's ID, shared with [`Market::game_id`].
+    pub game_id: i64,
+    /// The ID of the game's event.
+    pub event_id: String,
+    pub title: Option<String>,
+    pub home_team: Option<String>,
+    pub away_team: Option<String>,
+    /// The score as reported by the API, e.g. `"3-1"`.
+    pub score: Option<String>,
+    /// The current period, e.g. `"Q3"` or `"2H"`.
+    pub period: Option<String>,
+    /// Time elapsed in the current period, e.g. `"12:34"`.
+    pub elapsed: Option<String>,
+    pub status: GameStatus,
+    /// The markets of the game's event.
+    #[builder(default)]
+    #[serde(default)]
+    pub markets: Vec<Market>,
+}
+
+impl SportsGame {
+    /// Builds a game from its event, or returns `None` if the event is not tied to a game.
+    #[must_use]
+    pub fn from_event(event: &Event) -> Option<Self> {
+        let status = if event.ended == Some(true) {
+            GameStatus::Ended
+        } else if event.live == Some(true) {
+            GameStatus::Live
+        } else {
+            GameStatus::Scheduled
+        };
+
+        Some(Self {
+            game_id: event.game_id?,
+            event_id: event.id.clone(),
+            title: event.title.clone(),
+            home_team: event.home_team_name.clone(),
+            away_team: event.away_team_name.clone(),
+            score: event.score.clone(),
+            period: event.period.clone(),
+            elapsed: event.elapsed.clone(),
+            status,
+            markets: event.markets().to_vec(),
+        })
+    }
+
+    /// Returns the markets in `markets` that belong to this game, matched by
+    /// [`Market::game_id`].
+    pub fn related_markets<'markets>(
+        &self,
+        markets: &'markets [Market],
+    ) -> impl Iterator<Item = &'markets Market> {
+        let game_id = self.game_id;
+
+        markets.iter().filter(move |market| {
+            market
+                .game_id
+                .as_deref()
+                .and_then(|id| id.parse::<i64>().ok())
+                == Some(game_id)
+        })
+    }
+}
+
 /// Sports market types response.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct SportsMarketTypesResponse {
-    pub market_types: Vec<String>,
+    pub market_types: Vec<SportsMarketType>,
 }
 
 /// A tag for categorizing content.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -98,7 +233,11 @@ pub struct Tag {
     pub published_at: Option<String>,
     pub created_by: Option<i32>,
     pub updated_by: Option<i32>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
     pub force_hide: Option<bool>,
     pub is_carousel: Option<bool>,
@@ -123,6 +262,7 @@ pub struct RelatedTag {
 }
 
 /// A category for organizing content.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -134,11 +274,16 @@ pub struct Category {
     pub published_at: Option<String>,
     pub created_by: Option<String>,
     pub updated_by: Option<String>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
 }
 
 /// An event creator.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -148,11 +293,16 @@ pub struct EventCreator {
     pub creator_handle: Option<String>,
     pub creator_url: Option<String>,
     pub creator_image: Option<String>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
 }
 
 /// A chat/live stream associated with an event.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -162,7 +312,11 @@ pub struct Chat {
     pub channel_name: Option<String>,
     pub channel_image: Option<String>,
     pub live: Option<bool>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub start_time: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub end_time: Option<DateTime<Utc>>,
 }
 
@@ -186,6 +340,7 @@ pub struct Template {
 }
 
 /// A collection of events.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -213,7 +368,11 @@ pub struct Collection {
     pub published_at: Option<String>,
     pub created_by: Option<String>,
     pub updated_by: Option<String>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
     pub comments_enabled: Option<bool>,
     pub image_optimized: Option<ImageOptimization>,
@@ -234,8 +393,14 @@ pub struct Event {
     pub subtitle: Option<String>,
     pub description: Option<String>,
     pub resolution_source: Option<String>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub start_date: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub creation_date: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub end_date: Option<DateTime<Utc>>,
     pub image: Option<String>,
     pub icon: Option<String>,
@@ -245,9 +410,21 @@ pub struct Event {
     pub new: Option<bool>,
     pub featured: Option<bool>,
     pub restricted: Option<bool>,
-    pub liquidity: Option<Decimal>,
-    pub volume: Option<Decimal>,
-    pub open_interest: Option<Decimal>,
+    /// Liquidity resting on the event's order books, in USDC. Zero if the API omits it.
+    #[serde_as(as = "FlexibleDecimal")]
+    #[serde(default)]
+    #[builder(default)]
+    pub liquidity: Decimal,
+    /// All-time traded volume, in USDC. Zero if the API omits it.
+    #[serde_as(as = "FlexibleDecimal")]
+    #[serde(default)]
+    #[builder(default)]
+    pub volume: Decimal,
+    /// Value of outstanding positions, in USDC. Zero if the API omits it.
+    #[serde_as(as = "FlexibleDecimal")]
+    #[serde(default)]
+    #[builder(default)]
+    pub open_interest: Decimal,
     pub sort_by: Option<String>,
     pub category: Option<String>,
     pub subcategory: Option<String>,
@@ -257,11 +434,19 @@ pub struct Event {
     pub published_at: Option<String>,
     pub created_by: Option<String>,
     pub updated_by: Option<String>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
     pub comments_enabled: Option<bool>,
     pub competitive: Option<Decimal>,
-    pub volume_24hr: Option<Decimal>,
+    /// Traded volume over the last 24 hours, in USDC. Zero if the API omits it.
+    #[serde_as(as = "FlexibleDecimal")]
+    #[serde(default)]
+    #[builder(default)]
+    pub volume_24hr: Decimal,
     pub volume_1wk: Option<Decimal>,
     pub volume_1mo: Option<Decimal>,
     pub volume_1yr: Option<Decimal>,
@@ -286,12 +471,18 @@ pub struct Event {
     pub icon_optimized: Option<ImageOptimization>,
     pub featured_image_optimized: Option<ImageOptimization>,
     pub sub_events: Option<Vec<String>>,
+    /// The event's markets, fully typed. A market that fails to deserialize, e.g. because the API
+    /// returned a malformed `clobTokenIds`, is skipped rather than failing the whole event.
+    #[serde_as(as = "Option<VecSkipError<_>>")]
+    #[serde(default)]
     pub markets: Option<Vec<Market>>,
     pub series: Option<Vec<Series>>,
     pub categories: Option<Vec<Category>>,
     pub collections: Option<Vec<Collection>>,
     pub tags: Option<Vec<Tag>>,
     pub cyom: Option<bool>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub closed_time: Option<DateTime<Utc>>,
     pub show_all_outcomes: Option<bool>,
     pub show_market_images: Option<bool>,
@@ -299,6 +490,8 @@ pub struct Event {
     pub enable_neg_risk: Option<bool>,
     pub automatically_active: Option<bool>,
     pub event_date: Option<NaiveDate>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub start_time: Option<DateTime<Utc>>,
     pub event_week: Option<i32>,
     pub series_slug: Option<String>,
@@ -307,6 +500,8 @@ pub struct Event {
     pub period: Option<String>,
     pub live: Option<bool>,
     pub ended: Option<bool>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub finished_timestamp: Option<DateTime<Utc>>,
     pub gmp_chart_mode: Option<String>,
     pub event_creators: Option<Vec<EventCreator>>,
@@ -322,7 +517,11 @@ pub struct Event {
     pub carousel_map: Option<String>,
     pub pending_deployment: Option<bool>,
     pub deploying: Option<bool>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub deploying_timestamp: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub scheduled_deployment_timestamp: Option<DateTime<Utc>>,
     pub game_status: Option<String>,
     pub requires_translation: Option<bool>,
@@ -336,6 +535,25 @@ pub struct Event {
     pub home_team_name: Option<String>,
 }
 
+impl Event {
+    /// Returns the event's markets, or an empty slice if none were returned.
+    #[must_use]
+    pub fn markets(&self) -> &[Market] {
+        self.markets.as_deref().unwrap_or_default()
+    }
+
+    /// Returns a default ranking score for trending views: the last 24 hours of volume plus
+    /// the current liquidity, both in USDC.
+    ///
+    /// Recent volume rewards activity while liquidity favors events that can absorb new orders.
+    /// Sort by the fields directly for other rankings. Not to be confused with the
+    /// [`score`](field@Self::score) field, which holds the score of a sports game.
+    #[must_use]
+    pub fn score(&self) -> Decimal {
+        self.volume_24hr + self.liquidity
+    }
+}
+
 /// A prediction market.
 #[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
@@ -350,16 +568,25 @@ pub struct Market {
     pub slug: Option<String>,
     pub twitter_card_image: Option<String>,
     pub resolution_source: Option<String>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub end_date: Option<DateTime<Utc>>,
     pub category: Option<String>,
     pub amm_type: Option<String>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub liquidity: Option<Decimal>,
     pub sponsor_name: Option<String>,
     pub sponsor_image: Option<String>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub start_date: Option<DateTime<Utc>>,
     pub x_axis_value: Option<String>,
     pub y_axis_value: Option<String>,
+    #[serde_as(as = "Option<FlexibleU256>")]
     pub denomination_token: Option<U256>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub fee: Option<Decimal>,
     pub image: Option<String>,
     pub icon: Option<String>,
@@ -370,6 +597,8 @@ pub struct Market {
     pub outcomes: Option<Vec<String>>,
     #[serde_as(as = "Option<JsonString>")]
     pub outcome_prices: Option<Vec<Decimal>>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume: Option<Decimal>,
     pub active: Option<bool>,
     pub market_type: Option<String>,
@@ -382,7 +611,11 @@ pub struct Market {
     pub market_maker_address: Option<Address>,
     pub created_by: Option<i32>,
     pub updated_by: Option<i32>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
     pub closed_time: Option<String>,
     pub wide_format: Option<bool>,
@@ -400,11 +633,19 @@ pub struct Market {
     pub question_id: Option<B256>,
     pub uma_end_date: Option<String>,
     pub enable_order_book: Option<bool>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub order_price_min_tick_size: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub order_min_size: Option<Decimal>,
     pub uma_resolution_status: Option<String>,
     pub curation_order: Option<i32>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_num: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub liquidity_num: Option<Decimal>,
     pub end_date_iso: Option<NaiveDate>,
     pub start_date_iso: Option<NaiveDate>,
@@ -412,13 +653,21 @@ pub struct Market {
     pub has_reviewed_dates: Option<bool>,
     pub ready_for_cron: Option<bool>,
     pub comments_enabled: Option<bool>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_24hr: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_1wk: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_1mo: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_1yr: Option<Decimal>,
     pub game_start_time: Option<String>,
     pub seconds_delay: Option<i32>,
-    #[serde_as(as = "Option<JsonString>")]
+    #[serde_as(as = "Option<JsonString<Vec<FlexibleU256>>>")]
     pub clob_token_ids: Option<Vec<U256>>,
     pub disqus_thread: Option<String>,
     pub short_outcomes: Option<String>,
@@ -427,19 +676,45 @@ pub struct Market {
     #[serde(rename = "teamBID")]
     pub team_b_id: Option<String>,
     pub uma_bond: Option<String>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub uma_reward: Option<Decimal>,
     pub fpmm_live: Option<bool>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_24hr_amm: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_1wk_amm: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_1mo_amm: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_1yr_amm: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_24hr_clob: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_1wk_clob: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_1mo_clob: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_1yr_clob: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_amm: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub volume_clob: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub liquidity_amm: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub liquidity_clob: Option<Decimal>,
     pub maker_base_fee: Option<i32>,
     pub taker_base_fee: Option<i32>,
@@ -457,21 +732,51 @@ pub struct Market {
     pub ready: Option<bool>,
     pub funded: Option<bool>,
     pub past_slugs: Option<String>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub ready_timestamp: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub funded_timestamp: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub accepting_orders_timestamp: Option<DateTime<Utc>>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub competitive: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub rewards_min_size: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub rewards_max_spread: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub spread: Option<Decimal>,
     pub automatically_resolved: Option<bool>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub one_day_price_change: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub one_hour_price_change: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub one_week_price_change: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub one_month_price_change: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub one_year_price_change: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub last_trade_price: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub best_bid: Option<Decimal>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub best_ask: Option<Decimal>,
     pub automatically_active: Option<bool>,
     pub clear_book_on_start: Option<bool>,
@@ -484,13 +789,21 @@ pub struct Market {
     pub game_id: Option<String>,
     pub group_item_range: Option<String>,
     pub sports_market_type: Option<String>,
+    #[serde(default)]
+    #[serde_as(as = "FlexibleDecimal")]
     pub line: Option<Decimal>,
     pub uma_resolution_statuses: Option<String>,
     pub pending_deployment: Option<bool>,
     pub deploying: Option<bool>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub deploying_timestamp: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub scheduled_deployment_timestamp: Option<DateTime<Utc>>,
     pub rfq_enabled: Option<bool>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub event_start_time: Option<DateTime<Utc>>,
     #[serde(alias = "submitted_by")]
     pub submitted_by: Option<String>,
@@ -537,6 +850,7 @@ pub struct ClobReward {
 }
 
 /// A series of related events.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
@@ -563,13 +877,19 @@ pub struct Series {
     pub published_at: Option<String>,
     pub created_by: Option<String>,
     pub updated_by: Option<String>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
     pub comments_enabled: Option<bool>,
     pub competitive: Option<Decimal>,
     pub volume_24hr: Option<Decimal>,
     pub volume: Option<Decimal>,
     pub liquidity: Option<Decimal>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub start_date: Option<DateTime<Utc>>,
     #[serde(rename = "pythTokenID")]
     pub pyth_token_id: Option<String>,
@@ -585,10 +905,12 @@ pub struct Series {
 }
 
 /// A comment position.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Builder)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct CommentPosition {
+    #[serde_as(as = "Option<FlexibleU256>")]
     pub token_id: Option<U256>,
     pub position_size: Option<Decimal>,
 }
@@ -630,6 +952,8 @@ pub struct Reaction {
     #[serde_as(as = "NoneAsEmptyString")]
     #[serde(default)]
     pub user_address: Option<Address>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
     pub profile: Option<CommentProfile>,
 }
@@ -653,12 +977,73 @@ pub struct Comment {
     #[serde_as(as = "NoneAsEmptyString")]
     #[serde(default)]
     pub reply_address: Option<Address>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
     pub profile: Option<CommentProfile>,
-    pub reactions: Option<Vec<Reaction>>,
+    /// Reactions on this comment. Empty if the API omits them or returns `null`.
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    #[serde(default)]
+    #[builder(default)]
+    pub reactions: Vec<Reaction>,
     pub report_count: Option<i32>,
-    pub reaction_count: Option<i32>,
+    /// Number of reactions on this comment. `0` if the API omits it or returns `null`.
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    #[serde(default)]
+    #[builder(default)]
+    pub reaction_count: i32,
+    /// Number of replies to this comment. `0` if the API omits it or returns `null`.
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    #[serde(default)]
+    #[builder(default)]
+    pub reply_count: i32,
+}
+
+impl Comment {
+    /// Returns whether this comment is a reply to another comment.
+    #[must_use]
+    pub fn is_reply(&self) -> bool {
+        self.parent_comment_id.is_some()
+    }
+}
+
+impl TryFrom<&Market> for MarketId {
+    type Error = Error;
+
+    /// Requires the market's `condition_id` and `clob_token_ids`; the `slug` is carried over when
+    /// present.
+    fn try_from(market: &Market) -> Result<Self, Self::Error> {
+        let Some(condition_id) = market.condition_id else {
+            return Err(Error::validation(format!(
+                "Market {} has no condition ID",
+                market.id
+            )));
+        };
+        let Some(token_ids) = market.clob_token_ids.clone() else {
+            return Err(Error::validation(format!(
+                "Market {} has no CLOB token IDs",
+                market.id
+            )));
+        };
+
+        Ok(MarketId::new(condition_id, token_ids, market.slug.clone()))
+    }
+}
+
+impl TryFrom<Market> for MarketId {
+    type Error = Error;
+
+    fn try_from(market: Market) -> Result<Self, Self::Error> {
+        MarketId::try_from(&market)
+    }
+}
+
+/// Filters out replies, keeping only comments posted directly on the event, series or market.
+pub fn top_level_only(comments: &[Comment]) -> impl Iterator<Item = &Comment> {
+    comments.iter().filter(|comment| !comment.is_reply())
 }
 
 /// A user associated with a public profile.
@@ -677,6 +1062,8 @@ pub struct PublicProfileUser {
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct PublicProfile {
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
     #[serde_as(as = "NoneAsEmptyString")]
     #[serde(default)]
@@ -714,7 +1101,11 @@ pub struct Profile {
     pub referral: Option<String>,
     pub created_by: Option<i32>,
     pub updated_by: Option<i32>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
     pub utm_source: Option<String>,
     pub utm_medium: Option<String>,
@@ -732,6 +1123,8 @@ pub struct Profile {
     pub profile_image_optimized: Option<ImageOptimization>,
     pub is_close_only: Option<bool>,
     pub is_cert_req: Option<bool>,
+    #[serde_as(as = "FlexibleTimestamp")]
+    #[serde(default)]
     pub cert_req_date: Option<DateTime<Utc>>,
 }
 
@@ -744,3 +1137,36 @@ pub struct SearchResults {
     pub profiles: Option<Vec<Profile>>,
     pub pagination: Option<Pagination>,
 }
+
+impl SearchResults {
+    /// Returns the matching events, or an empty slice if none were returned.
+    #[must_use]
+    pub fn events(&self) -> &[Event] {
+        self.events.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the markets of the matching events.
+    ///
+    /// Search does not return markets on their own; they come nested in each event.
+    pub fn markets(&self) -> impl Iterator<Item = &Market> {
+        self.events().iter().flat_map(Event::markets)
+    }
+
+    /// Returns the matching tags, or an empty slice if none were returned.
+    ///
+    /// Tags are only searched when [`SearchRequest::search_tags`](super::request::SearchRequest::search_tags)
+    /// is set.
+    #[must_use]
+    pub fn tags(&self) -> &[SearchTag] {
+        self.tags.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the matching profiles, or an empty slice if none were returned.
+    ///
+    /// Profiles are only searched when
+    /// [`SearchRequest::search_profiles`](super::request::SearchRequest::search_profiles) is set.
+    #[must_use]
+    pub fn profiles(&self) -> &[Profile] {
+        self.profiles.as_deref().unwrap_or_default()
+    }
+}
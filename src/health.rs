@@ -0,0 +1,126 @@
+//! Health checks across the Gamma, Data and CLOB APIs.
+//!
+//! [`check_all`] pings the health endpoint of every service concurrently, which makes a cheap
+//! pre-flight check before a trading session.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polymarket_client_sdk::{clob, data, gamma, health};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let clob = clob::Client::new("https://clob.polymarket.com", clob::Config::default())?;
+//! let report = health::check_all(&gamma::Client::default(), &data::Client::default(), &clob).await;
+//!
+//! if !report.is_healthy() {
+//!     eprintln!("Not trading: {report:?}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "HealthReport and ServiceHealth read better than Report and Service at call sites"
+)]
+
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::Result;
+use crate::auth::state::State;
+use crate::error::Timeout;
+use crate::{clob, data, gamma};
+
+/// How long [`check_all`] waits for each service before reporting it as timed out.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The outcome of checking a single service.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct ServiceHealth {
+    /// The status the service reported, normally "OK", or why the check failed. A check that
+    /// exceeds its timeout fails with a [`Timeout`] error.
+    pub status: Result<String>,
+    /// How long the check took, up to the timeout.
+    pub latency: Duration,
+}
+
+impl ServiceHealth {
+    /// Returns whether the service reported that it is fully operational.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.status.is_ok()
+    }
+}
+
+/// The outcome of [`check_all`], with one entry per service.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct HealthReport {
+    /// The outcome of [`gamma::Client::status`].
+    pub gamma: ServiceHealth,
+    /// The outcome of [`data::Client::health`].
+    pub data: ServiceHealth,
+    /// The outcome of [`clob::Client::ok`].
+    pub clob: ServiceHealth,
+}
+
+impl HealthReport {
+    /// Returns whether every service reported that it is fully operational.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.gamma.is_healthy() && self.data.is_healthy() && self.clob.is_healthy()
+    }
+}
+
+/// Checks the health of the Gamma, Data and CLOB APIs concurrently, allowing each check
+/// [`DEFAULT_TIMEOUT`].
+///
+/// Each check uses the client's own health method ([`gamma::Client::status`],
+/// [`data::Client::health`] and [`clob::Client::ok`]), so a service that answers with anything
+/// other than "OK" is reported as unhealthy unless the client was configured to allow it.
+pub async fn check_all<S: State>(
+    gamma: &gamma::Client,
+    data: &data::Client,
+    clob: &clob::Client<S>,
+) -> HealthReport {
+    check_all_with_timeout(gamma, data, clob, DEFAULT_TIMEOUT).await
+}
+
+/// Like [`check_all`], but allows each check `timeout` instead of [`DEFAULT_TIMEOUT`].
+pub async fn check_all_with_timeout<S: State>(
+    gamma: &gamma::Client,
+    data: &data::Client,
+    clob: &clob::Client<S>,
+    timeout: Duration,
+) -> HealthReport {
+    let (gamma, data, clob) = tokio::join!(
+        timed(gamma.status(), timeout),
+        timed(
+            async { data.health().await.map(|health| health.data) },
+            timeout
+        ),
+        timed(clob.ok(), timeout),
+    );
+
+    HealthReport { gamma, data, clob }
+}
+
+/// Runs `check`, failing it with a [`Timeout`] error if it takes longer than `timeout`.
+async fn timed<F>(check: F, timeout: Duration) -> ServiceHealth
+where
+    F: Future<Output = Result<String>>,
+{
+    let started = Instant::now();
+    let status = match tokio::time::timeout(timeout, check).await {
+        Ok(status) => status,
+        Err(_) => Err(Timeout { duration: timeout }.into()),
+    };
+
+    ServiceHealth {
+        status,
+        latency: started.elapsed(),
+    }
+}
@@ -5,22 +5,28 @@ pub mod auth;
 pub mod bridge;
 #[cfg(feature = "clob")]
 pub mod clob;
+pub mod contracts;
 #[cfg(feature = "ctf")]
 pub mod ctf;
 #[cfg(feature = "data")]
 pub mod data;
 pub mod error;
+pub mod format;
 #[cfg(feature = "gamma")]
 pub mod gamma;
+#[cfg(all(feature = "clob", feature = "data", feature = "gamma"))]
+pub mod health;
+#[cfg(all(feature = "clob", feature = "data"))]
+pub mod portfolio;
+pub mod response;
 #[cfg(feature = "rtds")]
 pub mod rtds;
 pub(crate) mod serde_helpers;
 pub mod types;
+pub mod util;
 #[cfg(any(feature = "ws", feature = "rtds"))]
 pub mod ws;
 
-use std::fmt::Write as _;
-
 use alloy::primitives::ChainId;
 use alloy::primitives::{B256, b256, keccak256};
 use phf::phf_map;
@@ -30,7 +36,7 @@ use phf::phf_map;
     feature = "data",
     feature = "gamma"
 ))]
-use reqwest::{Request, StatusCode, header::HeaderMap};
+use reqwest::{Method, Request, StatusCode, header::HeaderMap};
 use serde::Serialize;
 #[cfg(any(
     feature = "bridge",
@@ -53,6 +59,9 @@ pub const AMOY: ChainId = 80002;
 
 pub const PRIVATE_KEY_VAR: &str = "POLYMARKET_PRIVATE_KEY";
 
+/// The `User-Agent` header the HTTP clients send unless configured otherwise
+pub const DEFAULT_USER_AGENT: &str = "rs_clob_client";
+
 /// Timestamp in seconds since [`std::time::UNIX_EPOCH`]
 pub(crate) type Timestamp = i64;
 
@@ -200,7 +209,24 @@ pub fn derive_safe_wallet(eoa_address: Address, chain_id: ChainId) -> Option<Add
 ///
 /// This trait is automatically implemented for all types that implement [`Serialize`].
 /// It uses [`serde_html_form`] to serialize the struct fields into a query string.
-/// Arrays are serialized as repeated keys (`key=val1&key=val2`).
+///
+/// # Array parameters
+///
+/// `Vec` fields are serialized as repeated keys (`key=val1&key=val2`), which is what the Gamma
+/// API expects for its ID and tag filters. An empty `Vec` emits no parameter at all, so it does not
+/// filter.
+///
+/// Endpoints that expect a single comma-separated value instead, such as the Data API's `market`
+/// filter and several CLOB endpoints, annotate the field with
+/// `#[serde_as(as = "StringWithSeparator::<CommaSeparator, T>")]` to get `key=val1,val2`. A
+/// server given the wrong form typically ignores the filter rather than rejecting the request,
+/// so new array fields must pick the form the endpoint documents.
+///
+/// # Encoding
+///
+/// Keys and values, including the cursor, are percent-encoded as
+/// `application/x-www-form-urlencoded`, so a value such as `trump & biden` is sent as
+/// `trump+%26+biden` and cannot break out of its parameter.
 pub trait ToQueryParams: Serialize {
     /// Converts the request to a URL query string.
     ///
@@ -221,7 +247,7 @@ pub trait ToQueryParams: Serialize {
             if !params.is_empty() {
                 params.push('&');
             }
-            let _ = write!(params, "next_cursor={cursor}");
+            params.push_str(&cursor_param(cursor));
         }
 
         if params.is_empty() {
@@ -234,6 +260,13 @@ pub trait ToQueryParams: Serialize {
 
 impl<T: Serialize> ToQueryParams for T {}
 
+/// Returns `cursor` as a percent-encoded `next_cursor` query parameter, without a leading `?`.
+pub(crate) fn cursor_param(cursor: &str) -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("next_cursor", cursor)
+        .finish()
+}
+
 #[cfg(any(
     feature = "bridge",
     feature = "clob",
@@ -252,56 +285,175 @@ impl<T: Serialize> ToQueryParams for T {}
         )
     )
 )]
-async fn request<Response: DeserializeOwned>(
+async fn request_with_meta<Response: DeserializeOwned>(
     client: &reqwest::Client,
     mut request: Request,
     headers: Option<HeaderMap>,
-) -> Result<Response> {
+) -> Result<response::Response<Response>> {
     let method = request.method().clone();
-    let path = request.url().path().to_owned();
+    let path = request_path(request.url());
 
     if let Some(h) = headers {
         *request.headers_mut() = h;
     }
 
-    let response = client.execute(request).await?;
+    let response = client
+        .execute(request)
+        .await
+        .map_err(|e| Error::from(e).with_request(&method, &path))?;
     let status_code = response.status();
+    let response_headers = response.headers().clone();
 
     #[cfg(feature = "tracing")]
     tracing::Span::current().record("status_code", status_code.as_u16());
 
+    // Any non-2xx is an error regardless of its body, so error payloads are never deserialized
+    // into the success type
     if !status_code.is_success() {
-        let message = response.text().await.unwrap_or_default();
+        return Err(status_error(response, method, path).await);
+    }
 
-        #[cfg(feature = "tracing")]
-        tracing::warn!(
-            status = %status_code,
-            method = %method,
-            path = %path,
-            message = %message,
-            "API request failed"
-        );
+    let body = response
+        .bytes()
+        .await
+        .map_err(|e| Error::from(e).with_request(&method, &path))?;
+
+    decode_response(&method, &path, status_code, &response_headers, &body)
+}
 
-        return Err(Error::status(status_code, method, path, message));
+/// Turns a non-success `response` into a [`Status`](error::Status) error carrying its body.
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+pub(crate) async fn status_error(
+    response: reqwest::Response,
+    method: Method,
+    path: String,
+) -> Error {
+    let status_code = response.status();
+    let headers = response.headers().clone();
+    let message = response.text().await.unwrap_or_default();
+
+    #[cfg(feature = "tracing")]
+    tracing::warn!(
+        status = %status_code,
+        method = %method,
+        path = %path,
+        message = %message,
+        "API request failed"
+    );
+
+    Error::status_with_headers(status_code, method, path, message, &headers)
+}
+
+/// Deserializes the `body` of a successful response to `method` `path`.
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+pub(crate) fn decode_response<Response: DeserializeOwned>(
+    method: &Method,
+    path: &str,
+    status_code: StatusCode,
+    response_headers: &HeaderMap,
+    body: &[u8],
+) -> Result<response::Response<Response>> {
+    let with_meta = |data| response::Response::new(data, status_code, response_headers);
+
+    // Some endpoints (e.g. cancels and deletes) reply with an empty body. That is a success for
+    // responses that can be built from nothing, such as `()`, and a clear error for anything else.
+    if body.trim_ascii().is_empty() {
+        return serde_json::from_value::<Response>(serde_json::Value::Null)
+            .map(with_meta)
+            .map_err(|e| {
+                Error::validation(format!(
+                    "Expected a JSON response body from {method} {path}, but it was empty: {e}"
+                ))
+            });
+    }
+
+    let json_value = serde_json::from_slice::<serde_json::Value>(body)
+        .map_err(|e| Error::from(e).with_request(method, path))?;
+    if json_value.is_null()
+        && let Ok(response) = serde_json::from_value::<Response>(serde_json::Value::Null)
+    {
+        return Ok(with_meta(response));
     }
 
-    let json_value = response.json::<serde_json::Value>().await?;
-    let response_data: Option<Response> = serde_helpers::deserialize_with_warnings(json_value)?;
+    let response_data: Option<Response> = serde_helpers::deserialize_with_warnings(json_value)
+        .map_err(|e| e.with_request(method, path))?;
 
     if let Some(response) = response_data {
-        Ok(response)
+        Ok(with_meta(response))
     } else {
         #[cfg(feature = "tracing")]
         tracing::warn!(method = %method, path = %path, "API resource not found");
         Err(Error::status(
             StatusCode::NOT_FOUND,
-            method,
-            path,
+            method.clone(),
+            path.to_owned(),
             "Unable to find requested resource",
         ))
     }
 }
 
+/// The path of `url` including its query string, as reported in errors.
+#[cfg(any(
+    feature = "bridge",
+    feature = "clob",
+    feature = "data",
+    feature = "gamma"
+))]
+pub(crate) fn request_path(url: &url::Url) -> String {
+    url[url::Position::BeforePath..url::Position::AfterQuery].to_owned()
+}
+
+/// Checks the body of a health check, which is `OK` when the API is fully operational. Any other
+/// body, e.g. a maintenance notice served with `200`, is reported as a
+/// [`ServiceUnavailable`](error::ServiceUnavailable) error.
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+pub(crate) fn ensure_ok(message: String) -> Result<String> {
+    if message.trim() == "OK" {
+        Ok(message)
+    } else {
+        Err(error::ServiceUnavailable { message }.into())
+    }
+}
+
+/// Builds an HTTP client that sends `user_agent` and JSON headers with every request.
+#[cfg(any(feature = "data", feature = "gamma"))]
+fn http_client(user_agent: &str) -> Result<reqwest::Client> {
+    use reqwest::header::HeaderValue;
+
+    let mut headers = HeaderMap::new();
+
+    headers.insert("User-Agent", HeaderValue::from_str(user_agent)?);
+    headers.insert("Accept", HeaderValue::from_static("*/*"));
+    headers.insert("Connection", HeaderValue::from_static("keep-alive"));
+    headers.insert("Content-Type", HeaderValue::from_static("application/json"));
+
+    Ok(reqwest::Client::builder()
+        .default_headers(headers)
+        .build()?)
+}
+
+/// Sends `request` and deserializes the body, discarding the response metadata.
+#[cfg(any(feature = "bridge", feature = "clob"))]
+async fn request<Response: DeserializeOwned>(
+    client: &reqwest::Client,
+    request: Request,
+    headers: Option<HeaderMap>,
+) -> Result<Response> {
+    request_with_meta(client, request, headers)
+        .await
+        .map(response::Response::into_data)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -394,6 +546,57 @@ mod tests {
         );
     }
 
+    #[serde_with::serde_as]
+    #[derive(Serialize)]
+    struct ArrayParams {
+        #[serde_as(as = "Vec<serde_with::DisplayFromStr>")]
+        token_ids: Vec<types::U256>,
+        #[serde_as(
+            as = "serde_with::StringWithSeparator::<serde_with::formats::CommaSeparator, String>"
+        )]
+        markets: Vec<String>,
+    }
+
+    #[test]
+    fn query_params_should_repeat_array_keys() {
+        let params = ArrayParams {
+            token_ids: vec![types::U256::from(1), types::U256::from(22)],
+            markets: vec!["0xa".to_owned(), "0xb".to_owned()],
+        };
+
+        assert_eq!(
+            params.query_params(None),
+            "?token_ids=1&token_ids=22&markets=0xa%2C0xb"
+        );
+    }
+
+    #[test]
+    fn query_params_should_omit_empty_arrays() {
+        let params = ArrayParams {
+            token_ids: Vec::new(),
+            markets: vec!["0xa".to_owned()],
+        };
+
+        assert_eq!(params.query_params(None), "?markets=0xa");
+    }
+
+    #[test]
+    fn query_params_should_encode_reserved_characters() {
+        #[derive(Serialize)]
+        struct Search<'query> {
+            q: &'query str,
+        }
+
+        let search = Search {
+            q: "trump & biden=50/50?",
+        };
+
+        assert_eq!(
+            search.query_params(Some("LTE=")),
+            "?q=trump+%26+biden%3D50%2F50%3F&next_cursor=LTE%3D"
+        );
+    }
+
     #[test]
     fn derive_wallet_unsupported_chain() {
         let eoa = address!("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
@@ -0,0 +1,126 @@
+//! Portfolio valuation that combines Data API positions with CLOB prices.
+//!
+//! The Data API reports positions with a cached [`Position::cur_price`]. [`enrich_positions`]
+//! values them at the live CLOB midpoint instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use polymarket_client_sdk::{clob, data, portfolio};
+//! use polymarket_client_sdk::data::types::request::PositionsRequest;
+//! use polymarket_client_sdk::types::address;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let clob = clob::Client::new("https://clob.polymarket.com", clob::Config::default())?;
+//! let request = PositionsRequest::builder()
+//!     .user(address!("56687bf447db6ffa42ffe2204a05edaa20f55839"))
+//!     .build();
+//! let positions = data::Client::default().positions(&request).await?;
+//!
+//! for position in portfolio::enrich_positions(&clob, positions).await? {
+//!     println!("{}: {}", position.position.title, position.unrealized_pnl);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::Result;
+use crate::auth::state::State;
+use crate::clob;
+use crate::clob::types::request::MidpointRequest;
+use crate::data::types::response::Position;
+use crate::types::{Decimal, U256};
+
+/// The maximum number of tokens requested from the batch `/midpoints` endpoint at once.
+const MAX_MIDPOINTS_PER_REQUEST: usize = 100;
+
+/// Where the price of an [`EnrichedPosition`] came from.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceSource {
+    /// The live CLOB midpoint of the position's outcome token.
+    Midpoint,
+    /// The settlement price of a resolved market, 1 for the winning outcome and 0 for the
+    /// losing one.
+    Settlement,
+    /// The Data API's [`Position::cur_price`], used when the CLOB has no orderbook for the token.
+    DataApi,
+}
+
+/// A [`Position`] valued at a current price.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct EnrichedPosition {
+    pub position: Position,
+    /// The price each outcome token was valued at.
+    pub current_price: Decimal,
+    /// Where [`Self::current_price`] came from.
+    pub price_source: PriceSource,
+    /// See [`Position::market_value`].
+    pub market_value: Decimal,
+    /// See [`Position::unrealized_pnl`].
+    pub unrealized_pnl: Decimal,
+}
+
+impl EnrichedPosition {
+    fn new(position: Position, current_price: Decimal, price_source: PriceSource) -> Self {
+        Self {
+            market_value: position.market_value(current_price),
+            unrealized_pnl: position.unrealized_pnl(current_price),
+            position,
+            current_price,
+            price_source,
+        }
+    }
+}
+
+/// Values `positions` at the current CLOB midpoint of their outcome tokens, preserving their
+/// order.
+///
+/// Resolved markets have no orderbook, so [`redeemable`](Position::redeemable) positions are
+/// valued at their settlement price of 0 or 1 without a request. Midpoints of the remaining
+/// tokens are fetched from the batch `/midpoints` endpoint in chunks of up to 100. A token the
+/// CLOB returns no midpoint for falls back to the Data API's [`Position::cur_price`].
+///
+/// # Errors
+///
+/// Returns an error if a `/midpoints` request fails.
+pub async fn enrich_positions<S: State>(
+    client: &clob::Client<S>,
+    positions: Vec<Position>,
+) -> Result<Vec<EnrichedPosition>> {
+    let mut token_ids: Vec<U256> = positions
+        .iter()
+        .filter(|position| !position.redeemable)
+        .map(|position| position.asset)
+        .collect();
+    token_ids.sort_unstable();
+    token_ids.dedup();
+
+    let mut midpoints: HashMap<U256, Decimal> = HashMap::with_capacity(token_ids.len());
+    for chunk in token_ids.chunks(MAX_MIDPOINTS_PER_REQUEST) {
+        let requests: Vec<_> = chunk
+            .iter()
+            .map(|token_id| MidpointRequest::builder().token_id(*token_id).build())
+            .collect();
+
+        midpoints.extend(client.midpoints(&requests).await?.midpoints);
+    }
+
+    Ok(positions
+        .into_iter()
+        .map(|position| {
+            if position.redeemable {
+                let price = position.cur_price;
+                EnrichedPosition::new(position, price, PriceSource::Settlement)
+            } else if let Some(&price) = midpoints.get(&position.asset) {
+                EnrichedPosition::new(position, price, PriceSource::Midpoint)
+            } else {
+                let price = position.cur_price;
+                EnrichedPosition::new(position, price, PriceSource::DataApi)
+            }
+        })
+        .collect())
+}
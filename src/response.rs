@@ -0,0 +1,198 @@
+//! HTTP response metadata for callers that need more than the deserialized body.
+//!
+//! The clients return plain `Result<T>` by default. The `*_with_meta` methods return a
+//! [`Response`] instead, carrying the HTTP status and any rate limit headers alongside the data.
+//! Failed requests carry the same [`RateLimitInfo`] on [`Status`](crate::error::Status).
+//!
+//! # Raw requests
+//!
+//! The clients expose `get_raw` for GET requests to any endpoint, the CLOB, data and Gamma
+//! clients also `get_with_meta`, and the authenticated CLOB client `post_raw` for POST requests
+//! with a JSON body. `path` is
+//! relative to the client's host and the query is serialized as the query string; pass `&()` for
+//! none. Requests go through the same HTTP client, authentication and error handling as the
+//! typed methods. The typed methods are usually more convenient; use `get_with_meta` when the
+//! response metadata matters, for example to pace requests against the reported rate limit.
+//!
+//! `get_raw` and `post_raw` are escape hatches for endpoints that are not wrapped yet. They are
+//! not covered by the crate's semver guarantees and may change or be removed in any release;
+//! prefer the typed methods where they exist.
+//!
+//! All of them return an error if the request fails, the API returns a non-success status, or the
+//! body cannot be deserialized into `Res`, and `post_raw` also if its body cannot be serialized.
+
+use chrono::{DateTime, TimeDelta, Utc};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+
+/// Header carrying the number of requests allowed in the current window.
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+/// Header carrying the number of requests left in the current window.
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+/// Header carrying when the current window resets.
+pub const RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+
+/// Values of the reset header at or above this are Unix timestamps, anything smaller is a number
+/// of seconds from now.
+const RESET_TIMESTAMP_THRESHOLD: i64 = 1_000_000_000;
+
+/// A deserialized response body together with its HTTP metadata.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response<T> {
+    /// The deserialized response body.
+    pub data: T,
+    /// The HTTP status code of the response.
+    pub status: StatusCode,
+    /// Rate limit information, if the server sent any rate limit headers.
+    pub rate_limit: Option<RateLimitInfo>,
+}
+
+impl<T> Response<T> {
+    #[cfg(any(
+        feature = "bridge",
+        feature = "clob",
+        feature = "data",
+        feature = "gamma"
+    ))]
+    pub(crate) fn new(data: T, status: StatusCode, headers: &HeaderMap) -> Self {
+        Self {
+            data,
+            status,
+            rate_limit: RateLimitInfo::from_headers(headers),
+        }
+    }
+
+    /// Discards the metadata and returns the response body.
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    /// Maps the response body, keeping the metadata.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Response<U> {
+        Response {
+            data: f(self.data),
+            status: self.status,
+            rate_limit: self.rate_limit,
+        }
+    }
+}
+
+/// Rate limit state reported by the `X-RateLimit-*` response headers.
+///
+/// Each field is `None` when its header is missing or cannot be parsed.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Requests allowed in the current window, from `X-RateLimit-Limit`.
+    pub limit: Option<u64>,
+    /// Requests left in the current window, from `X-RateLimit-Remaining`.
+    pub remaining: Option<u64>,
+    /// When the current window resets, from `X-RateLimit-Reset`. The header may hold either a
+    /// Unix timestamp or a number of seconds from now; both are converted to an absolute time.
+    pub reset_at: Option<DateTime<Utc>>,
+}
+
+impl RateLimitInfo {
+    /// Parses the rate limit headers, returning `None` if none of them are present.
+    #[must_use]
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+        };
+
+        let limit = header(RATE_LIMIT_LIMIT_HEADER);
+        let remaining = header(RATE_LIMIT_REMAINING_HEADER);
+        let reset = header(RATE_LIMIT_RESET_HEADER);
+
+        if limit.is_none() && remaining.is_none() && reset.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            limit: limit.and_then(|value| value.parse().ok()),
+            remaining: remaining.and_then(|value| value.parse().ok()),
+            reset_at: reset.and_then(parse_reset),
+        })
+    }
+}
+
+fn parse_reset(value: &str) -> Option<DateTime<Utc>> {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let mut seconds = whole.parse::<i64>().ok()?;
+    // Fractional values are rounded up so the reset is never reported early
+    if !fraction.is_empty() {
+        if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        if fraction.bytes().any(|b| b != b'0') {
+            seconds = seconds.checked_add(1)?;
+        }
+    }
+
+    if seconds >= RESET_TIMESTAMP_THRESHOLD {
+        DateTime::from_timestamp(seconds, 0)
+    } else {
+        Utc::now().checked_add_signed(TimeDelta::try_seconds(seconds)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| {
+                (
+                    reqwest::header::HeaderName::from_static(name),
+                    HeaderValue::from_static(value),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn missing_headers_should_be_none() {
+        assert_eq!(RateLimitInfo::from_headers(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn timestamp_reset_should_parse() {
+        let info = RateLimitInfo::from_headers(&headers(&[
+            (RATE_LIMIT_LIMIT_HEADER, "100"),
+            (RATE_LIMIT_REMAINING_HEADER, "42"),
+            (RATE_LIMIT_RESET_HEADER, "1700000000"),
+        ]))
+        .unwrap();
+
+        assert_eq!(info.limit, Some(100));
+        assert_eq!(info.remaining, Some(42));
+        assert_eq!(info.reset_at, DateTime::from_timestamp(1_700_000_000, 0));
+    }
+
+    #[test]
+    fn relative_reset_should_be_in_the_future() {
+        let before = Utc::now();
+        let info =
+            RateLimitInfo::from_headers(&headers(&[(RATE_LIMIT_RESET_HEADER, "1.5")])).unwrap();
+
+        assert_eq!(info.limit, None);
+        assert_eq!(info.remaining, None);
+        assert!(info.reset_at.unwrap() >= before + TimeDelta::seconds(2));
+    }
+
+    #[test]
+    fn unparseable_values_should_be_none() {
+        let info = RateLimitInfo::from_headers(&headers(&[(RATE_LIMIT_REMAINING_HEADER, "many")]))
+            .unwrap();
+
+        assert_eq!(info, RateLimitInfo::default());
+    }
+}
@@ -233,6 +233,18 @@ impl<S: State> Client<S> {
         self.inner.subscriptions.subscription_count()
     }
 
+    /// Close the WebSocket connection and stop reconnecting.
+    ///
+    /// Sends a close frame and waits up to
+    /// [`StreamConfig::close_timeout`](crate::ws::config::StreamConfig::close_timeout) for the
+    /// server to acknowledge it. Streams from this client end, and later subscriptions fail.
+    ///
+    /// The connection is also closed, in the background, once the client and every stream from
+    /// it are dropped.
+    pub async fn close(&self) {
+        self.inner.connection.close().await;
+    }
+
     /// Unsubscribe from Binance crypto price updates.
     ///
     /// This decrements the reference count for the `crypto_prices` topic. Only sends
@@ -6,7 +6,7 @@
 use std::sync::{Arc, PoisonError, RwLock};
 use std::time::Instant;
 
-use async_stream::try_stream;
+use async_stream::stream;
 use dashmap::{DashMap, Entry};
 use futures::Stream;
 use tokio::sync::broadcast::error::RecvError;
@@ -17,6 +17,7 @@ use super::types::response::{RtdsMessage, parse_messages};
 use crate::Result;
 use crate::auth::Credentials;
 use crate::ws::ConnectionManager;
+use crate::ws::config::BackpressurePolicy;
 use crate::ws::connection::ConnectionState;
 
 #[non_exhaustive]
@@ -83,11 +84,14 @@ impl SubscriptionManager {
     }
 
     /// Start the reconnection handler that re-subscribes on connection recovery.
+    ///
+    /// The handler only holds a weak reference to the manager, so it does not keep the
+    /// connection open once the client and its streams are dropped.
     pub fn start_reconnection_handler(self: &Arc<Self>) {
-        let this = Arc::clone(self);
+        let this = Arc::downgrade(self);
+        let mut state_rx = self.connection.state_receiver();
 
         tokio::spawn(async move {
-            let mut state_rx = this.connection.state_receiver();
             let mut was_connected = state_rx.borrow().is_connected();
 
             loop {
@@ -102,6 +106,9 @@ impl SubscriptionManager {
                 match state {
                     ConnectionState::Connected { .. } => {
                         if was_connected {
+                            let Some(this) = this.upgrade() else {
+                                break;
+                            };
                             // Reconnect to subscriptions
                             #[cfg(feature = "tracing")]
                             tracing::debug!("RTDS reconnected, re-establishing subscriptions");
@@ -231,11 +238,13 @@ impl SubscriptionManager {
         );
 
         // Create filtered stream with its own receiver
-        let mut rx = self.connection.subscribe();
+        let connection = self.connection.clone();
+        let mut rx = connection.subscribe();
+        let backpressure = connection.backpressure();
         let target_topic = topic_type.topic;
         let target_type = topic_type.msg_type;
 
-        Ok(try_stream! {
+        Ok(stream! {
             loop {
                 match rx.recv().await {
                     Ok(msg) => {
@@ -244,19 +253,25 @@ impl SubscriptionManager {
                         let matches_type = target_type == "*" || msg.msg_type == target_type;
 
                         if matches_topic && matches_type {
-                            yield msg;
+                            yield Ok(msg);
                         }
                     }
                     Err(RecvError::Lagged(n)) => {
                         #[cfg(feature = "tracing")]
                         tracing::warn!("RTDS subscription lagged, missed {n} messages");
-                        Err(RtdsError::Lagged { count: n })?;
+                        yield Err(RtdsError::Lagged { count: n }.into());
+                        if backpressure != BackpressurePolicy::DropOldest {
+                            break;
+                        }
                     }
                     Err(RecvError::Closed) => {
                         break;
                     }
                 }
             }
+
+            // Holding the connection until here keeps it open for as long as this stream is alive
+            drop(connection);
         })
     }
 
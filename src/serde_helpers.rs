@@ -80,6 +80,491 @@ impl serde_with::SerializeAs<String> for StringFromAny {
     }
 }
 
+/// Integer timestamps at or above this magnitude are treated as milliseconds rather than seconds.
+///
+/// `10^10` seconds is in the year 2286, while `10^10` milliseconds is in April 1970, so real
+/// timestamps from either unit fall clearly on one side.
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+const MILLISECONDS_THRESHOLD: i64 = 10_000_000_000;
+
+/// A `serde_as` type that deserializes Unix timestamps or date strings as `DateTime<Utc>`.
+///
+/// Accepts:
+/// - integers (or integer strings) in seconds or milliseconds, told apart by magnitude
+/// - fractional seconds as numbers or strings (`1704164645.5`)
+/// - RFC 3339 / ISO 8601 strings, including the `2024-01-01 12:00:00+00` form and strings
+///   without an offset, which are taken to be UTC
+/// - bare dates (`2024-01-01`), taken as midnight UTC
+///
+/// Values serialize back as RFC 3339 strings. The parsed value keeps full millisecond precision,
+/// so the raw timestamp is still available via `DateTime::timestamp` or
+/// `DateTime::timestamp_millis`.
+///
+/// Use with `#[serde_as(as = "FlexibleTimestamp")]` for `DateTime<Utc>` fields. For
+/// `Option<DateTime<Utc>>` fields, use the same attribute together with `#[serde(default)]`, so
+/// that `null`, `""` and missing fields are all `None`.
+///
+/// Fields whose unit is fixed by the API, such as the order book's millisecond `timestamp`, are
+/// better served by `TimestampSeconds<String, Flexible>` or
+/// `TimestampMilliSeconds<String, Flexible>`, which accept numbers and strings without guessing
+/// the unit.
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+pub struct FlexibleTimestamp;
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl FlexibleTimestamp {
+    fn from_integer(value: i64) -> Option<chrono::DateTime<chrono::Utc>> {
+        if value.abs() >= MILLISECONDS_THRESHOLD {
+            chrono::DateTime::from_timestamp_millis(value)
+        } else {
+            chrono::DateTime::from_timestamp(value, 0)
+        }
+    }
+
+    fn from_decimal(value: rust_decimal::Decimal) -> Option<chrono::DateTime<chrono::Utc>> {
+        use rust_decimal::Decimal;
+        use rust_decimal::prelude::ToPrimitive as _;
+
+        let millis = if value.abs() >= Decimal::from(MILLISECONDS_THRESHOLD) {
+            value
+        } else {
+            value.checked_mul(Decimal::ONE_THOUSAND)?
+        };
+        chrono::DateTime::from_timestamp_millis(millis.round().to_i64()?)
+    }
+
+    fn from_str(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+        use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+
+        let value = value.trim();
+
+        if let Ok(integer) = value.parse::<i64>() {
+            return Self::from_integer(integer);
+        }
+
+        if value.contains('.')
+            && let Ok(decimal) = value.parse::<rust_decimal::Decimal>()
+        {
+            return Self::from_decimal(decimal);
+        }
+
+        if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+            return Some(datetime.to_utc());
+        }
+
+        for format in ["%Y-%m-%d %H:%M:%S%.f%#z", "%Y-%m-%dT%H:%M:%S%.f%#z"] {
+            if let Ok(datetime) = DateTime::parse_from_str(value, format) {
+                return Some(datetime.to_utc());
+            }
+        }
+
+        for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+            if let Ok(datetime) = NaiveDateTime::parse_from_str(value, format) {
+                return Some(datetime.and_utc());
+            }
+        }
+
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .map(|date| date.and_time(NaiveTime::MIN).and_utc())
+    }
+
+    fn deserialize_option<'de, D>(
+        deserializer: D,
+    ) -> std::result::Result<Option<chrono::DateTime<chrono::Utc>>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use std::fmt;
+
+        use chrono::{DateTime, Utc};
+        use serde::de::{self, Visitor};
+
+        struct TimestampVisitor;
+
+        impl<'de> Visitor<'de> for TimestampVisitor {
+            type Value = Option<DateTime<Utc>>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a Unix timestamp or an ISO 8601 date string")
+            }
+
+            fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                if v.trim().is_empty() {
+                    return Ok(None);
+                }
+
+                FlexibleTimestamp::from_str(v)
+                    .map(Some)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                FlexibleTimestamp::from_integer(v)
+                    .map(Some)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(v), &self))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                i64::try_from(v)
+                    .ok()
+                    .and_then(FlexibleTimestamp::from_integer)
+                    .map(Some)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                // Go through the shortest round-trip representation, as `FlexibleDecimal` does
+                v.to_string()
+                    .parse()
+                    .ok()
+                    .and_then(FlexibleTimestamp::from_decimal)
+                    .map(Some)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Float(v), &self))
+            }
+
+            fn visit_unit<E>(self) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(None)
+            }
+
+            fn visit_none<E>(self) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(None)
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_any(self)
+            }
+        }
+
+        deserializer.deserialize_any(TimestampVisitor)
+    }
+}
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl<'de> serde_with::DeserializeAs<'de, chrono::DateTime<chrono::Utc>> for FlexibleTimestamp {
+    fn deserialize_as<D>(
+        deserializer: D,
+    ) -> std::result::Result<chrono::DateTime<chrono::Utc>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::deserialize_option(deserializer)?
+            .ok_or_else(|| serde::de::Error::custom("expected a timestamp, found an empty value"))
+    }
+}
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl<'de> serde_with::DeserializeAs<'de, Option<chrono::DateTime<chrono::Utc>>>
+    for FlexibleTimestamp
+{
+    fn deserialize_as<D>(
+        deserializer: D,
+    ) -> std::result::Result<Option<chrono::DateTime<chrono::Utc>>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::deserialize_option(deserializer)
+    }
+}
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl serde_with::SerializeAs<chrono::DateTime<chrono::Utc>> for FlexibleTimestamp {
+    fn serialize_as<S>(
+        source: &chrono::DateTime<chrono::Utc>,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(source, serializer)
+    }
+}
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl serde_with::SerializeAs<Option<chrono::DateTime<chrono::Utc>>> for FlexibleTimestamp {
+    fn serialize_as<S>(
+        source: &Option<chrono::DateTime<chrono::Utc>>,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(source, serializer)
+    }
+}
+
+/// A `serde_as` type that deserializes `Decimal`s from JSON numbers or numeric strings.
+///
+/// Polymarket sends the same value as `"0.523"` on some endpoints and as `0.523` on others, and
+/// occasionally as `""` or `null` when it is not set. Those empty values become `None` for
+/// `Option<Decimal>` fields and zero for `Decimal` fields. Strings are trimmed and may use
+/// scientific notation (`"1e-3"`).
+///
+/// Use with `#[serde_as(as = "FlexibleDecimal")]` for `Decimal` fields. For `Option<Decimal>`
+/// fields, use the same attribute together with `#[serde(default)]` so that missing fields are
+/// `None` as well; `Option<FlexibleDecimal>` would turn `""` into `Some(0)` instead.
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+pub struct FlexibleDecimal;
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl FlexibleDecimal {
+    fn from_str(value: &str) -> Result<Option<rust_decimal::Decimal>, rust_decimal::Error> {
+        use std::str::FromStr as _;
+
+        use rust_decimal::Decimal;
+
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(None);
+        }
+
+        Decimal::from_str(value)
+            .or_else(|_e| Decimal::from_scientific(value))
+            .map(Some)
+    }
+
+    fn deserialize_option<'de, D>(
+        deserializer: D,
+    ) -> std::result::Result<Option<rust_decimal::Decimal>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use std::fmt;
+
+        use rust_decimal::Decimal;
+        use serde::de::{self, Visitor};
+
+        struct DecimalVisitor;
+
+        impl<'de> Visitor<'de> for DecimalVisitor {
+            type Value = Option<Decimal>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a number or a numeric string")
+            }
+
+            fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                FlexibleDecimal::from_str(v)
+                    .map_err(|_e| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Some(Decimal::from(v)))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Some(Decimal::from(v)))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                // Go through the shortest round-trip representation so that `0.1` stays `0.1`
+                FlexibleDecimal::from_str(&v.to_string())
+                    .map_err(|_e| E::invalid_value(de::Unexpected::Float(v), &self))
+            }
+
+            fn visit_unit<E>(self) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(None)
+            }
+
+            fn visit_none<E>(self) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(None)
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_any(self)
+            }
+        }
+
+        deserializer.deserialize_any(DecimalVisitor)
+    }
+}
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl<'de> serde_with::DeserializeAs<'de, rust_decimal::Decimal> for FlexibleDecimal {
+    fn deserialize_as<D>(deserializer: D) -> std::result::Result<rust_decimal::Decimal, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Self::deserialize_option(deserializer)?.unwrap_or_default())
+    }
+}
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl<'de> serde_with::DeserializeAs<'de, Option<rust_decimal::Decimal>> for FlexibleDecimal {
+    fn deserialize_as<D>(
+        deserializer: D,
+    ) -> std::result::Result<Option<rust_decimal::Decimal>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::deserialize_option(deserializer)
+    }
+}
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl serde_with::SerializeAs<rust_decimal::Decimal> for FlexibleDecimal {
+    fn serialize_as<S>(
+        source: &rust_decimal::Decimal,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(source, serializer)
+    }
+}
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl serde_with::SerializeAs<Option<rust_decimal::Decimal>> for FlexibleDecimal {
+    fn serialize_as<S>(
+        source: &Option<rust_decimal::Decimal>,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(source, serializer)
+    }
+}
+
+/// A `serde_as` type that deserializes token IDs as `U256` from decimal or hex strings.
+///
+/// Accepts decimal strings (`"6581861965..."`), `0x`/`0X`-prefixed hex strings and JSON
+/// integers. Surrounding whitespace is ignored. Values that are empty, malformed or larger than
+/// 256 bits are rejected with an error naming the value.
+///
+/// Values serialize the same way as a plain `U256`.
+///
+/// Use with `#[serde_as(as = "FlexibleU256")]` for `U256` fields, and e.g.
+/// `#[serde_as(as = "Option<FlexibleU256>")]` or `#[serde_as(as = "Vec<FlexibleU256>")]` for
+/// containers.
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+pub struct FlexibleU256;
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl FlexibleU256 {
+    fn from_str(value: &str) -> std::result::Result<crate::types::U256, String> {
+        use crate::types::U256;
+
+        let value = value.trim();
+        let (digits, radix) = match value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+        {
+            Some(hex) => (hex, 16),
+            None => (value, 10),
+        };
+
+        if digits.is_empty() {
+            return Err(format!("invalid token ID {value:?}: no digits"));
+        }
+
+        U256::from_str_radix(digits, radix).map_err(|e| format!("invalid token ID {value:?}: {e}"))
+    }
+}
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl<'de> serde_with::DeserializeAs<'de, crate::types::U256> for FlexibleU256 {
+    fn deserialize_as<D>(deserializer: D) -> std::result::Result<crate::types::U256, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use std::fmt;
+
+        use serde::de::{self, Visitor};
+
+        use crate::types::U256;
+
+        struct U256Visitor;
+
+        impl Visitor<'_> for U256Visitor {
+            type Value = U256;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a decimal or hex token ID")
+            }
+
+            fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                FlexibleU256::from_str(v).map_err(E::custom)
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(U256::from(v))
+            }
+
+            fn visit_u128<E>(self, v: u128) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(U256::from(v))
+            }
+        }
+
+        deserializer.deserialize_any(U256Visitor)
+    }
+}
+
+#[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+impl serde_with::SerializeAs<crate::types::U256> for FlexibleU256 {
+    fn serialize_as<S>(
+        source: &crate::types::U256,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(source, serializer)
+    }
+}
+
 /// Deserialize JSON with unknown field warnings.
 ///
 /// This function deserializes JSON to a target type while detecting and logging
@@ -293,6 +778,120 @@ mod tests {
     #[cfg(feature = "tracing")]
     use super::{format_value, lookup_value};
 
+    #[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+    mod flexible_timestamp_tests {
+        use chrono::{DateTime, TimeZone as _, Utc};
+        use serde::Deserialize;
+        use serde_with::serde_as;
+
+        use super::super::FlexibleTimestamp;
+
+        #[serde_as]
+        #[derive(Debug, Deserialize)]
+        struct TestStruct {
+            #[serde_as(as = "FlexibleTimestamp")]
+            #[serde(default)]
+            value: Option<DateTime<Utc>>,
+        }
+
+        fn parse(value: &serde_json::Value) -> Option<DateTime<Utc>> {
+            serde_json::from_value::<TestStruct>(serde_json::json!({ "value": value }))
+                .unwrap()
+                .value
+        }
+
+        #[test]
+        fn seconds_and_milliseconds_should_parse() {
+            let expected = Utc.with_ymd_and_hms(2023, 12, 31, 0, 0, 0).unwrap();
+
+            assert_eq!(parse(&serde_json::json!(1_703_980_800)), Some(expected));
+            assert_eq!(
+                parse(&serde_json::json!(1_703_980_800_000_u64)),
+                Some(expected)
+            );
+            assert_eq!(parse(&serde_json::json!("1703980800")), Some(expected));
+            assert_eq!(
+                parse(&serde_json::json!(1_703_980_800_123_i64)).map(|d| d.timestamp_millis()),
+                Some(1_703_980_800_123)
+            );
+        }
+
+        #[test]
+        fn iso_8601_strings_should_parse() {
+            let expected = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+
+            for value in [
+                "2024-01-02T03:04:05Z",
+                "2024-01-02T03:04:05.000Z",
+                "2024-01-02T05:04:05+02:00",
+                "2024-01-02 03:04:05+00",
+                "2024-01-02T03:04:05",
+            ] {
+                assert_eq!(parse(&serde_json::json!(value)), Some(expected), "{value}");
+            }
+
+            assert_eq!(
+                parse(&serde_json::json!("2024-01-02")),
+                Some(Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap())
+            );
+        }
+
+        #[test]
+        fn numeric_strings_and_fractions_should_parse() {
+            let expected = Utc.with_ymd_and_hms(2023, 12, 31, 0, 0, 0).unwrap();
+
+            assert_eq!(parse(&serde_json::json!("1703980800000")), Some(expected));
+            assert_eq!(parse(&serde_json::json!(" 1703980800 ")), Some(expected));
+            assert_eq!(parse(&serde_json::json!(1_703_980_800.0)), Some(expected));
+            assert_eq!(
+                parse(&serde_json::json!(1_703_980_800.5)).map(|d| d.timestamp_millis()),
+                Some(1_703_980_800_500)
+            );
+            assert_eq!(
+                parse(&serde_json::json!("1703980800.25")).map(|d| d.timestamp_millis()),
+                Some(1_703_980_800_250)
+            );
+        }
+
+        #[test]
+        fn null_and_missing_should_be_none() {
+            assert_eq!(parse(&serde_json::json!("")), None);
+            assert_eq!(parse(&serde_json::Value::Null), None);
+            assert!(
+                serde_json::from_str::<TestStruct>("{}")
+                    .unwrap()
+                    .value
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn invalid_string_should_fail() {
+            serde_json::from_value::<TestStruct>(serde_json::json!({ "value": "yesterday" }))
+                .unwrap_err();
+        }
+
+        #[test]
+        fn required_timestamp_should_reject_empty_values() {
+            #[serde_as]
+            #[derive(Debug, Deserialize)]
+            struct Required {
+                #[serde_as(as = "FlexibleTimestamp")]
+                value: DateTime<Utc>,
+            }
+
+            let parsed: Required =
+                serde_json::from_value(serde_json::json!({ "value": "2024-01-02" })).unwrap();
+            assert_eq!(
+                parsed.value,
+                Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap()
+            );
+
+            serde_json::from_value::<Required>(serde_json::json!({ "value": "" })).unwrap_err();
+            serde_json::from_value::<Required>(serde_json::json!({ "value": null })).unwrap_err();
+        }
+    }
+
     // ========== deserialize_with_warnings tests ==========
     #[cfg(any(
         feature = "bridge",
@@ -593,6 +1192,130 @@ mod tests {
         }
     }
 
+    // ========== FlexibleDecimal tests ==========
+    #[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+    mod flexible_decimal_tests {
+        use rust_decimal::Decimal;
+        use rust_decimal_macros::dec;
+        use serde::Deserialize;
+
+        use super::super::FlexibleDecimal;
+
+        #[derive(Debug, Deserialize)]
+        struct Required {
+            #[serde(with = "serde_with::As::<FlexibleDecimal>")]
+            value: Decimal,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct Optional {
+            #[serde(default, with = "serde_with::As::<FlexibleDecimal>")]
+            value: Option<Decimal>,
+        }
+
+        fn required(value: &serde_json::Value) -> Decimal {
+            serde_json::from_value::<Required>(serde_json::json!({ "value": value }))
+                .expect("deserialization failed")
+                .value
+        }
+
+        fn optional(json: &serde_json::Value) -> Option<Decimal> {
+            serde_json::from_value::<Optional>(json.clone())
+                .expect("deserialization failed")
+                .value
+        }
+
+        #[test]
+        fn flexible_decimal_accepts_strings_and_numbers() {
+            assert_eq!(required(&serde_json::json!("0.523")), dec!(0.523));
+            assert_eq!(required(&serde_json::json!(0.523)), dec!(0.523));
+            assert_eq!(required(&serde_json::json!(42)), dec!(42));
+            assert_eq!(required(&serde_json::json!(-7)), dec!(-7));
+            assert_eq!(required(&serde_json::json!(" 1.5 ")), dec!(1.5));
+            assert_eq!(required(&serde_json::json!("1e-3")), dec!(0.001));
+        }
+
+        #[test]
+        fn flexible_decimal_empty_is_zero_when_required() {
+            assert_eq!(required(&serde_json::json!("")), Decimal::ZERO);
+            assert_eq!(required(&serde_json::json!(null)), Decimal::ZERO);
+        }
+
+        #[test]
+        fn flexible_decimal_empty_is_none_when_optional() {
+            assert_eq!(optional(&serde_json::json!({ "value": "" })), None);
+            assert_eq!(optional(&serde_json::json!({ "value": null })), None);
+            assert_eq!(optional(&serde_json::json!({})), None);
+            assert_eq!(
+                optional(&serde_json::json!({ "value": "0.5" })),
+                Some(dec!(0.5))
+            );
+        }
+
+        #[test]
+        fn flexible_decimal_rejects_non_numeric_strings() {
+            serde_json::from_value::<Required>(serde_json::json!({ "value": "abc" })).unwrap_err();
+        }
+    }
+
+    // ========== FlexibleU256 tests ==========
+    #[cfg(any(feature = "clob", feature = "data", feature = "gamma"))]
+    mod flexible_u256_tests {
+        use serde::Deserialize;
+
+        use super::super::FlexibleU256;
+        use crate::types::U256;
+
+        #[derive(Debug, Deserialize)]
+        struct TokenId {
+            #[serde(with = "serde_with::As::<FlexibleU256>")]
+            id: U256,
+        }
+
+        fn parse(value: &serde_json::Value) -> Result<U256, serde_json::Error> {
+            serde_json::from_value::<TokenId>(serde_json::json!({ "id": value })).map(|t| t.id)
+        }
+
+        #[test]
+        fn flexible_u256_accepts_decimal_and_hex() {
+            let expected = U256::from(255);
+
+            assert_eq!(parse(&serde_json::json!("255")).unwrap(), expected);
+            assert_eq!(parse(&serde_json::json!("0xff")).unwrap(), expected);
+            assert_eq!(parse(&serde_json::json!("0XFF")).unwrap(), expected);
+            assert_eq!(parse(&serde_json::json!(" 255 ")).unwrap(), expected);
+            assert_eq!(parse(&serde_json::json!(255)).unwrap(), expected);
+        }
+
+        #[test]
+        fn flexible_u256_accepts_full_size_token_ids() {
+            let decimal =
+                "65818619657568813474341868652308942079804919287380422192892211131408793125422";
+            let id = parse(&serde_json::json!(decimal)).unwrap();
+
+            assert_eq!(id.to_string(), decimal);
+            assert_eq!(parse(&serde_json::json!(format!("{id:#x}"))).unwrap(), id);
+        }
+
+        #[test]
+        fn flexible_u256_rejects_oversized_values() {
+            let too_big = format!("{}0", U256::MAX);
+            let err = parse(&serde_json::json!(too_big)).unwrap_err();
+            assert!(err.to_string().contains("invalid token ID"), "{err}");
+
+            let too_big_hex = format!("0x1{}", "0".repeat(64));
+            parse(&serde_json::json!(too_big_hex)).unwrap_err();
+        }
+
+        #[test]
+        fn flexible_u256_rejects_malformed_values() {
+            parse(&serde_json::json!("")).unwrap_err();
+            parse(&serde_json::json!("0x")).unwrap_err();
+            parse(&serde_json::json!("12ab")).unwrap_err();
+            parse(&serde_json::json!(-1)).unwrap_err();
+        }
+    }
+
     // ========== lookup_value tests ==========
 
     #[cfg(feature = "tracing")]
@@ -3,6 +3,8 @@
 //! These types are commonly used in this SDK and are re-exported here
 //! so users don't need to add these dependencies to their `Cargo.toml`.
 
+use std::str::FromStr;
+
 /// Ethereum address type and the [`address!`] macro for compile-time address literals.
 /// [`ChainId`] is a type alias for `u64` representing EVM chain IDs.
 /// [`Signature`] represents cryptographic signatures for signed orders.
@@ -21,3 +23,151 @@ pub use rust_decimal::Decimal;
 /// let price = dec!(0.55);
 /// ```
 pub use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+use crate::error::Error;
+
+/// The identifiers a market shares across the Gamma, Data and CLOB APIs, so a market fetched from
+/// one API can be passed into a request for another.
+///
+/// With the `gamma` feature, a Gamma `Market` converts with `TryFrom`, which requires its
+/// `condition_id` and `clob_token_ids` to be present. With the `data` feature, a Data API
+/// `Position` converts with `From`, and a [`MarketId`] converts into a Data API `MarketFilter`.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MarketId {
+    /// The market's condition ID.
+    pub condition_id: B256,
+    /// The market's outcome token IDs, in outcome order.
+    pub token_ids: Vec<U256>,
+    /// The market's URL slug, if known.
+    pub slug: Option<String>,
+}
+
+impl MarketId {
+    #[must_use]
+    pub fn new(condition_id: B256, token_ids: Vec<U256>, slug: Option<String>) -> Self {
+        Self {
+            condition_id,
+            token_ids,
+            slug,
+        }
+    }
+}
+
+/// Time interval of a price history query, e.g. the CLOB's `/prices-history` `interval`.
+///
+/// Displays and parses as the string the API expects, e.g. `"1h"`, so it can be read from
+/// configuration files.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum Interval {
+    /// 1 minute
+    #[serde(rename = "1m")]
+    #[strum(serialize = "1m")]
+    OneMinute,
+    /// 1 hour
+    #[serde(rename = "1h")]
+    #[strum(serialize = "1h")]
+    OneHour,
+    /// 6 hours
+    #[serde(rename = "6h")]
+    #[strum(serialize = "6h")]
+    SixHours,
+    /// 1 day
+    #[serde(rename = "1d")]
+    #[strum(serialize = "1d")]
+    OneDay,
+    /// 1 week
+    #[serde(rename = "1w")]
+    #[strum(serialize = "1w")]
+    OneWeek,
+    /// Maximum available history
+    #[serde(rename = "max")]
+    #[strum(serialize = "max")]
+    Max,
+}
+
+impl FromStr for Interval {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "1m" => Ok(Self::OneMinute),
+            "1h" => Ok(Self::OneHour),
+            "6h" => Ok(Self::SixHours),
+            "1d" => Ok(Self::OneDay),
+            "1w" => Ok(Self::OneWeek),
+            "max" => Ok(Self::Max),
+            _ => Err(Error::validation(format!("Unknown interval {s}"))),
+        }
+    }
+}
+
+/// Time period that leaderboard and volume data is aggregated over, e.g. the Data API's
+/// `timePeriod`.
+///
+/// Displays as the string the API expects, e.g. `"WEEK"`, and parses case-insensitively so it can
+/// be read from configuration files. Default is [`Day`](Self::Day), which most endpoints use when
+/// no period is given.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+#[non_exhaustive]
+pub enum Period {
+    /// Last 24 hours (default).
+    #[default]
+    Day,
+    /// Last 7 days.
+    Week,
+    /// Last 30 days.
+    Month,
+    /// All time.
+    All,
+}
+
+impl FromStr for Period {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "DAY" => Ok(Self::Day),
+            "WEEK" => Ok(Self::Week),
+            "MONTH" => Ok(Self::Month),
+            "ALL" => Ok(Self::All),
+            _ => Err(Error::validation(format!("Unknown period {s}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interval_should_round_trip_through_strings() {
+        for interval in [
+            Interval::OneMinute,
+            Interval::OneHour,
+            Interval::SixHours,
+            Interval::OneDay,
+            Interval::OneWeek,
+            Interval::Max,
+        ] {
+            assert_eq!(interval.to_string().parse::<Interval>().unwrap(), interval);
+        }
+        assert_eq!("1H".parse::<Interval>().unwrap(), Interval::OneHour);
+        "2h".parse::<Interval>().unwrap_err();
+    }
+
+    #[test]
+    fn period_should_round_trip_through_strings() {
+        for period in [Period::Day, Period::Week, Period::Month, Period::All] {
+            assert_eq!(period.to_string().parse::<Period>().unwrap(), period);
+        }
+        assert_eq!("week".parse::<Period>().unwrap(), Period::Week);
+        assert_eq!(Period::default(), Period::Day);
+        "year".parse::<Period>().unwrap_err();
+    }
+}
@@ -0,0 +1,158 @@
+//! General-purpose helpers shared by the API clients.
+
+use std::future::Future;
+
+use futures::{Stream, StreamExt as _, stream};
+
+use crate::Result;
+
+/// Calls `f` for every item, keeping at most `concurrency` calls in flight, and returns one result
+/// per item in the same order as `items`, successful or not. A `concurrency` of 0 is treated as 1.
+///
+/// Use [`fan_out_fail_fast`] to stop at the first error instead.
+///
+/// # Example
+///
+/// ```no_run
+/// use polymarket_client_sdk::gamma::{Client, types::request::MarketBySlugRequest};
+/// use polymarket_client_sdk::util::fan_out_collect;
+///
+/// # async fn example() {
+/// let client = Client::default();
+/// let slugs = ["market-a", "market-b", "market-c"];
+///
+/// let markets = fan_out_collect(slugs, 4, |slug| {
+///     let client = &client;
+///     async move {
+///         let request = MarketBySlugRequest::builder().slug(slug).build();
+///         client.market_by_slug(&request).await
+///     }
+/// })
+/// .await;
+/// # }
+/// ```
+pub async fn fan_out_collect<I, F, Fut, R>(items: I, concurrency: usize, f: F) -> Vec<Result<R>>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = Result<R>>,
+{
+    let mut results: Vec<_> = indexed(items, concurrency, f).collect().await;
+
+    results.sort_unstable_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Calls `f` for every item, keeping at most `concurrency` calls in flight, and returns the
+/// values in the same order as `items`. A `concurrency` of 0 is treated as 1.
+///
+/// # Errors
+///
+/// Returns the first error returned by `f`, cancelling calls still in flight and never starting
+/// the rest. Use [`fan_out_collect`] to run every call regardless.
+pub async fn fan_out_fail_fast<I, F, Fut, R>(items: I, concurrency: usize, f: F) -> Result<Vec<R>>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = Result<R>>,
+{
+    let mut calls = std::pin::pin!(indexed(items, concurrency, f));
+
+    let mut values = Vec::new();
+    while let Some((index, result)) = calls.next().await {
+        values.push((index, result?));
+    }
+
+    values.sort_unstable_by_key(|(index, _)| *index);
+    Ok(values.into_iter().map(|(_, value)| value).collect())
+}
+
+/// Runs `f` for every item with at most `concurrency` calls in flight, yielding each result with
+/// the index of its item as it completes.
+fn indexed<I, F, Fut, R>(
+    items: I,
+    concurrency: usize,
+    mut f: F,
+) -> impl Stream<Item = (usize, Result<R>)>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = Result<R>>,
+{
+    stream::iter(items.into_iter().enumerate())
+        .map(move |(index, item)| {
+            let call = f(item);
+            async move { (index, call.await) }
+        })
+        .buffer_unordered(concurrency.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use super::*;
+    use crate::error::Error;
+
+    async fn delayed(value: u64) -> Result<u64> {
+        tokio::time::sleep(Duration::from_millis(value * 10)).await;
+        if value == 0 {
+            return Err(Error::validation("zero"));
+        }
+
+        Ok(value)
+    }
+
+    #[tokio::test]
+    async fn fan_out_should_preserve_input_order() {
+        let values = fan_out_fail_fast([3, 1, 2], 3, delayed).await.unwrap();
+
+        assert_eq!(values, [3, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn fan_out_should_collect_errors() {
+        let results = fan_out_collect([2, 0, 1], 2, delayed).await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(*results[0].as_ref().unwrap(), 2);
+        results[1].as_ref().unwrap_err();
+        assert_eq!(*results[2].as_ref().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn fan_out_should_fail_fast() {
+        let started = AtomicUsize::new(0);
+
+        let result = fan_out_fail_fast([0, 5, 5, 5], 1, |value| {
+            started.fetch_add(1, Ordering::SeqCst);
+            delayed(value)
+        })
+        .await;
+
+        result.unwrap_err();
+        assert_eq!(started.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn fan_out_should_bound_concurrency() {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        fan_out_fail_fast(0..10, 3, |_| {
+            let (in_flight, peak) = (&in_flight, &peak);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+    }
+}
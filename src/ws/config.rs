@@ -7,30 +7,92 @@ use std::time::Duration;
 
 use backoff::{ExponentialBackoff, ExponentialBackoffBuilder};
 
-const DEFAULT_HEARTBEAT_INTERVAL_DURATION: Duration = Duration::from_secs(5);
-const DEFAULT_HEARTBEAT_TIMEOUT_DURATION: Duration = Duration::from_secs(15);
+const DEFAULT_PING_INTERVAL_DURATION: Duration = Duration::from_secs(10);
+const DEFAULT_PONG_TIMEOUT_DURATION: Duration = Duration::from_secs(15);
+const DEFAULT_CLOSE_TIMEOUT_DURATION: Duration = Duration::from_secs(5);
+const DEFAULT_BUFFER_CAPACITY: usize = 1024;
 const DEFAULT_INITIAL_BACKOFF_DURATION: Duration = Duration::from_secs(1);
 const DEFAULT_MAX_BACKOFF_DURATION: Duration = Duration::from_secs(60);
 const DEFAULT_BACKOFF_MULTIPLIER: f64 = 2.0;
 
 /// Configuration for WebSocket client behavior.
 #[non_exhaustive]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Config {
-    /// Interval for sending PING messages to keep connection alive
-    pub heartbeat_interval: Duration,
-    /// Maximum time to wait for PONG response before considering connection dead
-    pub heartbeat_timeout: Duration,
+    /// Keepalive (PING/PONG) configuration
+    pub stream: StreamConfig,
+    /// Buffering between the connection and slow subscribers
+    pub buffer: BufferConfig,
     /// Reconnection strategy configuration
     pub reconnect: ReconnectConfig,
 }
 
-impl Default for Config {
+/// Configuration for keeping an idle connection alive.
+///
+/// A PING is sent every `ping_interval`. If no PONG arrives within `pong_timeout`, the
+/// connection is treated as half-open: it is dropped and re-established according to
+/// [`ReconnectConfig`], and active subscriptions are restored. Wrap a stream with
+/// [`Client::with_reconnects`](crate::clob::ws::Client::with_reconnects) to be told when that
+/// happens.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct StreamConfig {
+    /// Interval for sending PING messages to keep connection alive
+    pub ping_interval: Duration,
+    /// Maximum time to wait for PONG response before considering connection dead
+    pub pong_timeout: Duration,
+    /// Maximum time to wait for the server to acknowledge a close frame when the connection is
+    /// closed
+    pub close_timeout: Duration,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            ping_interval: DEFAULT_PING_INTERVAL_DURATION,
+            pong_timeout: DEFAULT_PONG_TIMEOUT_DURATION,
+            close_timeout: DEFAULT_CLOSE_TIMEOUT_DURATION,
+        }
+    }
+}
+
+/// What happens when a subscriber falls `capacity` messages behind the connection.
+///
+/// Order-book reconstruction from `book`/`price_change` deltas must not miss messages, so use
+/// [`Block`](Self::Block) or [`Error`](Self::Error) for it. [`DropOldest`](Self::DropOldest) suits
+/// consumers that only care about the latest state, such as prices or best bid/ask.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BackpressurePolicy {
+    /// Stop reading from the socket until the slowest subscriber catches up. No messages are
+    /// lost, but a subscriber stalled for longer than [`StreamConfig::pong_timeout`] causes a
+    /// reconnect, after which the server sends fresh snapshots.
+    Block,
+    /// Overwrite the oldest buffered messages. The stream yields a
+    /// [`WsError::Lagged`](super::WsError::Lagged) with the number of dropped messages and
+    /// then keeps going.
+    DropOldest,
+    /// Overwrite the oldest buffered messages and end the stream after yielding
+    /// [`WsError::Lagged`](super::WsError::Lagged).
+    #[default]
+    Error,
+}
+
+/// Configuration for buffering messages between the connection and its subscribers.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct BufferConfig {
+    /// Maximum number of messages buffered for a subscriber. Must be greater than zero.
+    pub capacity: usize,
+    /// What to do once a subscriber falls `capacity` messages behind
+    pub policy: BackpressurePolicy,
+}
+
+impl Default for BufferConfig {
     fn default() -> Self {
         Self {
-            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL_DURATION,
-            heartbeat_timeout: DEFAULT_HEARTBEAT_TIMEOUT_DURATION,
-            reconnect: ReconnectConfig::default(),
+            capacity: DEFAULT_BUFFER_CAPACITY,
+            policy: BackpressurePolicy::default(),
         }
     }
 }
@@ -109,8 +171,8 @@ mod tests {
     }
 
     #[test]
-    fn default_heartbeat_is_five_seconds() {
+    fn default_heartbeat_is_ten_seconds() {
         let config = Config::default();
-        assert_eq!(config.heartbeat_interval, Duration::from_secs(5));
+        assert_eq!(config.stream.ping_interval, Duration::from_secs(10));
     }
 }
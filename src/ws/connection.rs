@@ -5,18 +5,21 @@
 
 use std::fmt::Debug;
 use std::marker::PhantomData;
-use std::time::Instant;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use backoff::backoff::Backoff as _;
+use futures::stream::{SplitSink, SplitStream};
 use futures::{SinkExt as _, StreamExt as _};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use tokio::net::TcpStream;
-use tokio::sync::{broadcast, mpsc, watch};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{Notify, broadcast, mpsc, watch};
 use tokio::time::{interval, sleep, timeout};
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungstenite::Message};
 
-use super::config::Config;
+use super::config::{BackpressurePolicy, Config};
 use super::error::WsError;
 use super::traits::MessageParser;
 use crate::auth::Credentials;
@@ -26,8 +29,46 @@ use crate::{Result, error::Error};
 
 type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
 
-/// Broadcast channel capacity for incoming messages.
-const BROADCAST_CAPACITY: usize = 1024;
+/// The connection loop's end of the channel that broadcasts parsed messages to subscribers.
+struct Broadcaster<M> {
+    tx: broadcast::Sender<M>,
+    /// Signalled by a [`Subscriber`] each time it receives a message or is dropped
+    received: Arc<Notify>,
+}
+
+/// Receives the messages of a [`ConnectionManager`], see [`ConnectionManager::subscribe`].
+///
+/// Each received message, and dropping the subscriber, wakes a connection that is holding off
+/// under [`BackpressurePolicy::Block`] until its subscribers catch up.
+#[derive(Debug)]
+pub struct Subscriber<M> {
+    rx: broadcast::Receiver<M>,
+    /// Declared after `rx` so that it is dropped, and notifies, once `rx` is gone
+    received: NotifyOnDrop,
+}
+
+impl<M: Clone> Subscriber<M> {
+    /// Receives the next message, as [`broadcast::Receiver::recv`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RecvError::Lagged`] if this subscriber fell behind and messages were dropped,
+    /// and [`RecvError::Closed`] once the connection is closed for good.
+    pub async fn recv(&mut self) -> std::result::Result<M, RecvError> {
+        let message = self.rx.recv().await;
+        self.received.0.notify_one();
+        message
+    }
+}
+
+#[derive(Debug)]
+struct NotifyOnDrop(Arc<Notify>);
+
+impl Drop for NotifyOnDrop {
+    fn drop(&mut self) {
+        self.0.notify_one();
+    }
+}
 
 /// Connection state tracking.
 #[non_exhaustive]
@@ -57,6 +98,69 @@ impl ConnectionState {
     }
 }
 
+/// An item of a stream that reports reconnections alongside its messages.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamEvent<T> {
+    /// A message received from the server
+    Message(T),
+    /// The connection dropped and was re-established, and subscriptions were restored. Messages
+    /// sent while disconnected are lost, so local state built from the stream should be resynced.
+    Reconnected,
+}
+
+/// Yields the items of `stream` as [`StreamEvent::Message`]s, and a [`StreamEvent::Reconnected`]
+/// each time the connection behind `state_rx` comes back up after having been connected.
+///
+/// State changes are checked before messages, so a reconnection is reported before anything
+/// received on the new connection.
+#[cfg(feature = "clob")]
+pub(crate) fn with_reconnects<T, S>(
+    mut state_rx: watch::Receiver<ConnectionState>,
+    stream: S,
+) -> impl futures::Stream<Item = Result<StreamEvent<T>>>
+where
+    S: futures::Stream<Item = Result<T>>,
+{
+    let mut was_connected = state_rx.borrow_and_update().is_connected();
+
+    async_stream::stream! {
+        let mut stream = std::pin::pin!(stream);
+        let mut watching = true;
+
+        loop {
+            let event = tokio::select! {
+                biased;
+
+                changed = state_rx.changed(), if watching => {
+                    if changed.is_err() {
+                        // The connection manager is gone, the stream ends on its own
+                        watching = false;
+                        continue;
+                    }
+
+                    if !state_rx.borrow_and_update().is_connected() {
+                        continue;
+                    }
+                    if !was_connected {
+                        was_connected = true;
+                        continue;
+                    }
+
+                    Ok(StreamEvent::Reconnected)
+                }
+
+                item = stream.next() => match item {
+                    Some(item) => item.map(StreamEvent::Message),
+                    None => break,
+                },
+            };
+
+            yield event;
+        }
+    }
+}
+
 /// Manages WebSocket connection lifecycle, reconnection, and heartbeat.
 ///
 /// This generic connection manager handles all WebSocket connection concerns:
@@ -64,6 +168,8 @@ impl ConnectionState {
 /// - Automatic reconnection with exponential backoff
 /// - Heartbeat monitoring via PING/PONG
 /// - Broadcasting messages to multiple subscribers
+/// - Closing the connection with a close handshake, either through [`Self::close`] or once every
+///   clone of the manager is dropped
 ///
 /// # Type Parameters
 ///
@@ -98,8 +204,17 @@ where
     state_rx: watch::Receiver<ConnectionState>,
     /// Sender channel for outgoing messages
     sender_tx: mpsc::UnboundedSender<String>,
-    /// Broadcast sender for incoming messages
-    broadcast_tx: broadcast::Sender<M>,
+    /// Broadcast sender for incoming messages. The connection loop owns the only strong sender, so
+    /// subscribers see the channel close once the loop stops.
+    broadcast_tx: broadcast::WeakSender<M>,
+    /// Signalled by subscribers each time they receive a message, waking a connection that is
+    /// blocked on a full buffer
+    received: Arc<Notify>,
+    /// Tells the connection loop to close the connection and stop reconnecting. Dropping every
+    /// clone of it does the same.
+    shutdown_tx: watch::Sender<bool>,
+    /// What subscribers do when they fall behind
+    backpressure: BackpressurePolicy,
     /// Phantom data for unused type parameters
     _phantom: PhantomData<P>,
 }
@@ -115,14 +230,27 @@ where
     /// The connection loop runs in a background task and automatically
     /// handles reconnection according to the config's `ReconnectConfig`.
     pub fn new(endpoint: String, config: Config, parser: P) -> Result<Self> {
+        if config.buffer.capacity == 0 {
+            return Err(Error::validation(
+                "WebSocket buffer capacity must be greater than zero",
+            ));
+        }
+
+        let backpressure = config.buffer.policy;
         let (sender_tx, sender_rx) = mpsc::unbounded_channel();
-        let (broadcast_tx, _) = broadcast::channel(BROADCAST_CAPACITY);
+        let (broadcast_tx, _) = broadcast::channel(config.buffer.capacity);
+        let received = Arc::new(Notify::new());
         let (state_tx, state_rx) = watch::channel(ConnectionState::Disconnected);
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
 
         // Spawn connection task
         let connection_config = config;
         let connection_endpoint = endpoint;
-        let broadcast_tx_clone = broadcast_tx.clone();
+        let weak_broadcast_tx = broadcast_tx.downgrade();
+        let broadcaster = Broadcaster {
+            tx: broadcast_tx,
+            received: Arc::clone(&received),
+        };
         let state_tx_clone = state_tx.clone();
 
         tokio::spawn(async move {
@@ -130,9 +258,10 @@ where
                 connection_endpoint,
                 connection_config,
                 sender_rx,
-                broadcast_tx_clone,
+                broadcaster,
                 parser,
                 state_tx_clone,
+                shutdown_rx,
             )
             .await;
         });
@@ -141,29 +270,34 @@ where
             state_tx,
             state_rx,
             sender_tx,
-            broadcast_tx,
+            broadcast_tx: weak_broadcast_tx,
+            received,
+            shutdown_tx,
+            backpressure,
             _phantom: PhantomData,
         })
     }
 
     /// Main connection loop with automatic reconnection.
+    ///
+    /// Runs until a shutdown is requested, every [`ConnectionManager`] is dropped, or the
+    /// configured number of reconnection attempts is used up.
     async fn connection_loop(
         endpoint: String,
         config: Config,
         mut sender_rx: mpsc::UnboundedReceiver<String>,
-        broadcast_tx: broadcast::Sender<M>,
+        broadcaster: Broadcaster<M>,
         parser: P,
         state_tx: watch::Sender<ConnectionState>,
+        mut shutdown_rx: watch::Receiver<bool>,
     ) {
         let mut attempt = 0_u32;
         let mut backoff: backoff::ExponentialBackoff = config.reconnect.clone().into();
 
         loop {
-            // Check if ConnectionManager was dropped (all sender_tx instances gone)
-            if sender_rx.is_closed() {
+            if Self::is_shutting_down(&shutdown_rx) {
                 #[cfg(feature = "tracing")]
-                tracing::debug!("Sender channel closed, stopping connection loop");
-                _ = state_tx.send(ConnectionState::Disconnected);
+                tracing::debug!("Shutdown requested, stopping connection loop");
                 break;
             }
 
@@ -171,8 +305,13 @@ where
 
             _ = state_tx.send(ConnectionState::Connecting);
 
-            // Attempt connection
-            match connect_async(&endpoint).await {
+            // Attempt connection, giving up if a shutdown is requested in the meantime
+            let connected = tokio::select! {
+                connected = connect_async(&endpoint) => connected,
+                () = Self::shutdown_requested(&mut shutdown_rx) => break,
+            };
+
+            match connected {
                 Ok((ws_stream, _)) => {
                     attempt = 0;
                     backoff.reset();
@@ -184,10 +323,11 @@ where
                     if let Err(e) = Self::handle_connection(
                         ws_stream,
                         &mut sender_rx,
-                        &broadcast_tx,
+                        &broadcaster,
                         state_rx,
                         config.clone(),
                         &parser,
+                        &mut shutdown_rx,
                     )
                     .await
                     {
@@ -208,10 +348,12 @@ where
             }
 
             // Check if we should stop reconnecting
+            if Self::is_shutting_down(&shutdown_rx) {
+                break;
+            }
             if let Some(max) = config.reconnect.max_attempts
                 && attempt >= max
             {
-                _ = state_tx.send(ConnectionState::Disconnected);
                 break;
             }
 
@@ -219,32 +361,92 @@ where
             _ = state_tx.send(ConnectionState::Reconnecting { attempt });
 
             if let Some(duration) = backoff.next_backoff() {
-                sleep(duration).await;
+                tokio::select! {
+                    () = sleep(duration) => {}
+                    () = Self::shutdown_requested(&mut shutdown_rx) => break,
+                }
             }
         }
+
+        _ = state_tx.send(ConnectionState::Disconnected);
+    }
+
+    /// Whether [`Self::close`] was called or every [`ConnectionManager`] was dropped.
+    fn is_shutting_down(shutdown_rx: &watch::Receiver<bool>) -> bool {
+        *shutdown_rx.borrow() || shutdown_rx.has_changed().is_err()
+    }
+
+    /// Resolves once [`Self::close`] is called or every [`ConnectionManager`] is dropped.
+    async fn shutdown_requested(shutdown_rx: &mut watch::Receiver<bool>) {
+        _ = shutdown_rx.wait_for(|&shutdown| shutdown).await;
+    }
+
+    /// Sends a close frame and waits up to `close_timeout` for the server to answer with its own.
+    ///
+    /// Messages the server sends in the meantime are discarded.
+    async fn close_gracefully(
+        write: &mut SplitSink<WsStream, Message>,
+        read: &mut SplitStream<WsStream>,
+        close_timeout: Duration,
+    ) {
+        if write.send(Message::Close(None)).await.is_err() {
+            return;
+        }
+
+        let acknowledged = timeout(close_timeout, async {
+            while let Some(Ok(message)) = read.next().await {
+                if matches!(message, Message::Close(_)) {
+                    break;
+                }
+            }
+        })
+        .await;
+
+        #[cfg(feature = "tracing")]
+        if acknowledged.is_err() {
+            tracing::debug!("Timed out waiting for the server to acknowledge the close frame");
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _: &_ = &acknowledged;
     }
 
     /// Handle an active WebSocket connection.
     async fn handle_connection(
         ws_stream: WsStream,
         sender_rx: &mut mpsc::UnboundedReceiver<String>,
-        broadcast_tx: &broadcast::Sender<M>,
+        broadcaster: &Broadcaster<M>,
         state_rx: watch::Receiver<ConnectionState>,
         config: Config,
         parser: &P,
+        shutdown_rx: &mut watch::Receiver<bool>,
     ) -> Result<()> {
         let (mut write, mut read) = ws_stream.split();
+        let buffer = config.buffer.clone();
+        let close_timeout = config.stream.close_timeout;
 
         // Channel to notify heartbeat loop when PONG is received
         let (pong_tx, pong_rx) = watch::channel(Instant::now());
         let (ping_tx, mut ping_rx) = mpsc::unbounded_channel();
 
-        let heartbeat_handle = tokio::spawn(async move {
+        let mut heartbeat_handle = tokio::spawn(async move {
             Self::heartbeat_loop(ping_tx, state_rx, &config, pong_rx).await;
         });
 
         loop {
             tokio::select! {
+                // The heartbeat loop only exits while we are still running when the connection
+                // stopped answering PINGs, so drop it and let the caller reconnect
+                _ = &mut heartbeat_handle => {
+                    return Err(Error::with_source(Kind::WebSocket, WsError::Timeout));
+                }
+
+                // Close the connection with a handshake instead of reconnecting
+                () = Self::shutdown_requested(shutdown_rx) => {
+                    heartbeat_handle.abort();
+                    Self::close_gracefully(&mut write, &mut read, close_timeout).await;
+                    return Ok(());
+                }
+
                 // Handle incoming messages
                 Some(msg) = read.next() => {
                     match msg {
@@ -261,7 +463,16 @@ where
                                     for message in messages {
                                         #[cfg(feature = "tracing")]
                                         tracing::trace!(?message, "Parsed WebSocket message");
-                                        _ = broadcast_tx.send(message);
+                                        if buffer.policy == BackpressurePolicy::Block {
+                                            // Hold off reading until the slowest subscriber has room
+                                            while broadcaster.tx.len() >= buffer.capacity {
+                                                tokio::select! {
+                                                    () = broadcaster.received.notified() => {}
+                                                    () = Self::shutdown_requested(shutdown_rx) => break,
+                                                }
+                                            }
+                                        }
+                                        _ = broadcaster.tx.send(message);
                                     }
                                 }
                                 Err(e) => {
@@ -326,7 +537,7 @@ where
         config: &Config,
         mut pong_rx: watch::Receiver<Instant>,
     ) {
-        let mut ping_interval = interval(config.heartbeat_interval);
+        let mut ping_interval = interval(config.stream.ping_interval);
 
         loop {
             ping_interval.tick().await;
@@ -348,7 +559,7 @@ where
             }
 
             // Wait for PONG within timeout
-            let pong_result = timeout(config.heartbeat_timeout, pong_rx.changed()).await;
+            let pong_result = timeout(config.stream.pong_timeout, pong_rx.changed()).await;
 
             match pong_result {
                 Ok(Ok(())) => {
@@ -370,7 +581,7 @@ where
                     #[cfg(feature = "tracing")]
                     tracing::warn!(
                         "Heartbeat timeout: no PONG received within {:?}",
-                        config.heartbeat_timeout
+                        config.stream.pong_timeout
                     );
                     break;
                 }
@@ -409,10 +620,49 @@ where
     /// Subscribe to incoming messages.
     ///
     /// Each call returns a new independent receiver. Multiple subscribers can
-    /// receive messages concurrently without blocking each other.
+    /// receive messages concurrently; how a subscriber that falls behind is
+    /// handled depends on the configured [`BackpressurePolicy`].
+    ///
+    /// Receivers are closed once the connection is closed for good.
+    #[must_use]
+    pub fn subscribe(&self) -> Subscriber<M> {
+        let rx = self
+            .broadcast_tx
+            .upgrade()
+            .map_or_else(|| broadcast::channel(1).1, |tx| tx.subscribe());
+
+        Subscriber {
+            rx,
+            received: NotifyOnDrop(Arc::clone(&self.received)),
+        }
+    }
+
+    /// Close the connection and stop reconnecting.
+    ///
+    /// Sends a close frame and waits up to [`StreamConfig::close_timeout`] for the server to
+    /// acknowledge it, then stops the background connection task. Receivers from
+    /// [`Self::subscribe`] are closed and further messages cannot be sent. Returns immediately
+    /// if the connection is already closed.
+    ///
+    /// Dropping every clone of the manager closes the connection the same way, in the
+    /// background.
+    ///
+    /// [`StreamConfig::close_timeout`]: super::config::StreamConfig::close_timeout
+    pub async fn close(&self) {
+        self.shutdown_tx.send_replace(true);
+        self.shutdown_tx.closed().await;
+    }
+
+    /// Whether [`Self::close`] was called, after which the connection never reconnects.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        *self.shutdown_tx.borrow()
+    }
+
+    /// The [`BackpressurePolicy`] streams built on [`Self::subscribe`] should apply when they lag.
     #[must_use]
-    pub fn subscribe(&self) -> broadcast::Receiver<M> {
-        self.broadcast_tx.subscribe()
+    pub const fn backpressure(&self) -> BackpressurePolicy {
+        self.backpressure
     }
 
     /// Subscribe to connection state changes.
@@ -24,7 +24,7 @@ pub mod connection;
 pub mod error;
 pub mod traits;
 
-pub use connection::ConnectionManager;
+pub use connection::{ConnectionManager, StreamEvent, Subscriber};
 #[expect(
     clippy::module_name_repetitions,
     reason = "WsError includes module name for clarity when used outside this module"
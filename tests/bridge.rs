@@ -339,3 +339,29 @@ mod client {
         result.unwrap_err();
     }
 }
+
+mod raw {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::bridge::Client;
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn get_raw_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/quote").query_param("amount", "5");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "fee": "0.1" }));
+        });
+
+        let response: serde_json::Value = client.get_raw("quote", &json!({ "amount": 5 })).await?;
+
+        assert_eq!(response, json!({ "fee": "0.1" }));
+        mock.assert();
+
+        Ok(())
+    }
+}
@@ -12,10 +12,10 @@ use std::str::FromStr as _;
 use alloy::primitives::U256;
 use chrono::{DateTime, Utc};
 use httpmock::MockServer;
-use polymarket_client_sdk::POLYGON;
 use polymarket_client_sdk::clob::types::SignatureType;
 use polymarket_client_sdk::clob::{Client, Config};
 use polymarket_client_sdk::types::{Decimal, b256};
+use polymarket_client_sdk::{DEFAULT_USER_AGENT, POLYGON};
 use reqwest::StatusCode;
 use rust_decimal_macros::dec;
 use serde_json::json;
@@ -28,22 +28,25 @@ use crate::common::{
 
 mod unauthenticated {
 
+    use std::time::Duration;
+
     use chrono::{TimeDelta, TimeZone as _};
     use futures_util::future;
     use futures_util::stream::StreamExt as _;
+    use polymarket_client_sdk::clob::HttpVersion;
     use polymarket_client_sdk::clob::types::request::{
         LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, PriceHistoryRequest,
         PriceRequest, SpreadRequest,
     };
     use polymarket_client_sdk::clob::types::response::{
-        FeeRateResponse, GeoblockResponse, LastTradePriceResponse, LastTradesPricesResponse,
-        MarketResponse, MidpointResponse, MidpointsResponse, NegRiskResponse,
-        OrderBookSummaryResponse, OrderSummary, Page, PriceHistoryResponse, PricePoint,
-        PriceResponse, PricesResponse, Rewards, SimplifiedMarketResponse, SpreadResponse,
-        SpreadsResponse, TickSizeResponse, Token,
+        FeeRateResponse, FeeRates, GeoblockResponse, LastTradePriceResponse,
+        LastTradesPricesResponse, MarketResponse, MidpointResponse, MidpointsResponse,
+        NegRiskResponse, OrderBookSummaryResponse, OrderSummary, Page, PriceHistoryResponse,
+        PricePoint, PriceResponse, PricesResponse, Rewards, SimplifiedMarketResponse,
+        SpreadResponse, SpreadsResponse, TickSizeResponse, Token,
     };
-    use polymarket_client_sdk::clob::types::{Interval, Side, TickSize, TimeRange};
-    use polymarket_client_sdk::error::Status;
+    use polymarket_client_sdk::clob::types::{Amount, Interval, Side, TickSize, TimeRange};
+    use polymarket_client_sdk::error::{Kind, Status, Validation};
     use polymarket_client_sdk::types::address;
     use reqwest::Method;
 
@@ -67,6 +70,112 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn ok_with_non_ok_body_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK).body("\"Maintenance\"");
+        });
+
+        let err = client.ok().await.unwrap_err();
+        assert_eq!(err.kind(), Kind::ServiceUnavailable);
+
+        let config = Config::builder().allow_non_ok_health(true).build();
+        let client = Client::new(&server.base_url(), config)?;
+        assert_eq!(client.ok().await?, "Maintenance");
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn ok_with_tuned_connection_pool_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .pool_idle_timeout(Duration::from_secs(30))
+            .pool_max_idle_per_host(4)
+            .tcp_keepalive(Duration::from_secs(10))
+            .http_version(HttpVersion::Http1Only)
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        assert_eq!(client.ok().await?, "OK");
+        assert_eq!(client.ok().await?, "OK");
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn keep_alive_disabled_should_send_connection_close() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().keep_alive(false).build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("connection", "close");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        assert_eq!(client.ok().await?, "OK");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn default_config_should_keep_alive() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("connection", "keep-alive");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        assert_eq!(client.ok().await?, "OK");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn http_client_should_send_requests_with_configured_headers() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder().user_agent("my-service/1.0").build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/custom")
+                .header("user-agent", "my-service/1.0");
+            then.status(StatusCode::OK).body("custom");
+        });
+
+        let response = client
+            .http_client()
+            .get(format!("{}custom", client.host()))
+            .send()
+            .await?;
+
+        assert_eq!(response.text().await?, "custom");
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn server_time_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -109,6 +218,55 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn user_agent_should_default_and_be_configurable() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let default_client = Client::new(&server.base_url(), Config::default())?;
+        let config = Config::builder().user_agent("my-bot/1.0").build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let default_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("user-agent", DEFAULT_USER_AGENT);
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/")
+                .header("user-agent", "my-bot/1.0");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        default_client.ok().await?;
+        client.ok().await?;
+
+        default_mock.assert();
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn midpoint_should_fail_on_empty_body() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/midpoint");
+            then.status(StatusCode::OK);
+        });
+
+        let request = MidpointRequest::builder().token_id(token_1()).build();
+        let err = client.midpoint(&request).await.unwrap_err();
+
+        let validation = err.downcast_ref::<Validation>().unwrap();
+        assert!(validation.reason.contains("empty"));
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn midpoints_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -170,11 +328,17 @@ mod unauthenticated {
 
         assert_eq!(
             status_err.to_string(),
-            r#"error(404 Not Found) making GET call to /price with {"message":"Request did not match any route or mock"}"#
+            format!(
+                r#"GET /price?token_id={}&side=SELL -> 404 Not Found: {{"message":"Request did not match any route or mock"}}"#,
+                token_1()
+            )
         );
         assert_eq!(status_err.status_code, StatusCode::NOT_FOUND);
         assert_eq!(status_err.method, Method::GET);
-        assert_eq!(status_err.path, "/price");
+        assert_eq!(
+            status_err.path,
+            format!("/price?token_id={}&side=SELL", token_1())
+        );
 
         Ok(())
     }
@@ -424,6 +588,96 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn tick_sizes_should_key_by_token_and_skip_missing() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let found = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/tick-size")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.01" }));
+        });
+        let missing = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/tick-size")
+                .query_param("token_id", token_2().to_string());
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "market not found" }));
+        });
+
+        let tick_sizes = client
+            .tick_sizes(&[token_1(), token_2(), token_1()])
+            .await?;
+
+        assert_eq!(tick_sizes.len(), 1);
+        assert_eq!(tick_sizes[&token_1()], TickSize::Hundredth);
+        found.assert_calls(1);
+        missing.assert_calls(1);
+
+        // Found tokens are cached
+        client.tick_sizes(&[token_1()]).await?;
+        found.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tick_sizes_should_fail_on_server_error() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/tick-size");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        let err = client.tick_sizes(&[token_1()]).await.unwrap_err();
+
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.status_code, StatusCode::INTERNAL_SERVER_ERROR);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn neg_risks_should_use_cached_values() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+        client.set_neg_risk(token_1(), true);
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/neg-risk")
+                .query_param("token_id", token_2().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "neg_risk": false }));
+        });
+
+        let neg_risks = client.neg_risks(&[token_1(), token_2()]).await?;
+
+        assert_eq!(neg_risks.len(), 2);
+        assert!(neg_risks[&token_1()]);
+        assert!(!neg_risks[&token_2()]);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn neg_risks_should_fail_on_network_error() -> anyhow::Result<()> {
+        let client = Client::new("http://127.0.0.1:1", Config::default())?;
+
+        let err = client.neg_risks(&[token_1()]).await.unwrap_err();
+
+        assert_ne!(err.kind(), polymarket_client_sdk::error::Kind::Status);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn fee_rate_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -447,6 +701,34 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn net_proceeds_should_apply_fee_rate() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/fee-rate")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "base_fee": 100 }));
+        });
+
+        let bought = client
+            .net_proceeds(token_1(), Side::Buy, dec!(0.40), dec!(100))
+            .await?;
+        let sold = client
+            .net_proceeds(token_1(), Side::Sell, dec!(0.60), dec!(100))
+            .await?;
+
+        assert_eq!(bought, dec!(99));
+        assert_eq!(sold, dec!(59.6));
+        // The fee rate is cached after the first lookup
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn set_tick_size_should_prepopulate_cache() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -537,6 +819,135 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn order_constraints_should_fetch_book_once() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "market": "0x00000000000000000000000000000000000000000000000000000000aabbcc00",
+                "asset_id": token_1(),
+                "tick_size": "0.01",
+                "min_order_size": "5",
+                "neg_risk": true,
+                "timestamp": "123456789"
+            }));
+        });
+
+        let constraints = client.order_constraints(token_1()).await?;
+        let cached = client.order_constraints(token_1()).await?;
+
+        assert_eq!(constraints, cached);
+        assert_eq!(constraints.tick_size, TickSize::Hundredth);
+        assert_eq!(constraints.min_order_size, dec!(5));
+        assert!(constraints.neg_risk);
+        // Tick size and neg risk lookups are served from the same cache
+        assert!(client.neg_risk(token_1()).await?.neg_risk);
+        mock.assert_calls(1);
+
+        constraints.validate(dec!(0.55), dec!(5))?;
+        constraints.validate(dec!(0.555), dec!(5)).unwrap_err();
+        constraints.validate(dec!(0.55), dec!(4)).unwrap_err();
+        constraints.validate(dec!(0.55), dec!(5.001)).unwrap_err();
+
+        Ok(())
+    }
+
+    fn fee_market(fees: &serde_json::Value) -> serde_json::Value {
+        let mut market = json!({
+            "enable_order_book": true,
+            "active": true,
+            "closed": false,
+            "archived": false,
+            "accepting_orders": true,
+            "accepting_order_timestamp": null,
+            "minimum_order_size": "5",
+            "minimum_tick_size": "0.01",
+            "condition_id": "0x00000000000000000000000000000000000000000000000000000000aabbcc00",
+            "question_id": "",
+            "question": "Will it rain tomorrow?",
+            "description": "",
+            "market_slug": "rain-tomorrow",
+            "end_date_iso": null,
+            "game_start_time": null,
+            "seconds_delay": 0,
+            "fpmm": "",
+            "notifications_enabled": true,
+            "neg_risk": false,
+            "icon": "",
+            "image": "",
+            "rewards": { "rates": null, "min_size": "0", "max_spread": "0" },
+            "is_50_50_outcome": false
+        });
+        market
+            .as_object_mut()
+            .unwrap()
+            .extend(fees.as_object().unwrap().clone());
+        market
+    }
+
+    #[tokio::test]
+    async fn fee_rates_should_read_market_fees() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let book_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "market": "0x00000000000000000000000000000000000000000000000000000000aabbcc00",
+                "asset_id": token_1(),
+                "tick_size": "0.01",
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "123456789"
+            }));
+        });
+        let market_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path(
+                "/markets/0x00000000000000000000000000000000000000000000000000000000aabbcc00",
+            );
+            then.status(StatusCode::OK).json_body(fee_market(&json!({
+                "maker_base_fee": 0,
+                "taker_base_fee": "1000"
+            })));
+        });
+
+        let rates = client.fee_rates(token_1()).await?;
+
+        let expected = FeeRates::builder().maker_bps(0).taker_bps(1000).build();
+
+        assert_eq!(rates, expected);
+        book_mock.assert();
+        market_mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn fee_rates_should_handle_markets_without_fees() -> anyhow::Result<()> {
+        let market: MarketResponse = serde_json::from_value(fee_market(&json!({})))?;
+        assert_eq!(market.fee_rates()?, FeeRates::default());
+
+        let market: MarketResponse = serde_json::from_value(fee_market(&json!({
+            "maker_base_fee": null,
+            "taker_base_fee": "0"
+        })))?;
+        assert_eq!(market.fee_rates()?, FeeRates::default());
+
+        let market: MarketResponse = serde_json::from_value(fee_market(&json!({
+            "taker_base_fee": 0.1
+        })))?;
+        market.fee_rates().unwrap_err();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn order_book_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -623,25 +1034,64 @@ mod unauthenticated {
     }
 
     #[tokio::test]
-    async fn order_books_should_succeed() -> anyhow::Result<()> {
+    async fn simulate_fill_should_walk_fetched_book() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = Client::new(&server.base_url(), Config::default())?;
 
         let mock = server.mock(|when, then| {
-            when.method(httpmock::Method::POST)
-                .path("/books")
-                .json_body(json!([{ "token_id": token_1().to_string() }]));
-            then.status(StatusCode::OK).json_body(json!([{
-                "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "market": "0x00000000000000000000000000000000000000000000000000000000aabbcc00",
                 "asset_id": token_1(),
                 "tick_size": TickSize::Hundredth.as_decimal(),
                 "min_order_size": "5",
                 "neg_risk": false,
-                "timestamp": "1",
-                "asks": [{
-                    "price": "2",
-                    "size": "1"
-                }]
+                "timestamp": "123456789",
+                "bids": [],
+                "asks": [
+                    { "price": "0.7", "size": "100" },
+                    { "price": "0.6", "size": "100" }
+                ]
+            }));
+        });
+
+        let simulation = client
+            .simulate_fill(token_1(), Side::Buy, Amount::usdc(dec!(150))?)
+            .await?;
+
+        // $60 buys 100 @ 0.6 and $70 buys the 100 @ 0.7, leaving $20 unfilled
+        assert_eq!(simulation.filled, dec!(130));
+        assert_eq!(simulation.remaining, dec!(20));
+        assert_eq!(simulation.shares, dec!(200));
+        assert_eq!(simulation.avg_price, Some(dec!(0.65)));
+        assert_eq!(simulation.levels_consumed, 2);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn order_books_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/books")
+                .json_body(json!([{ "token_id": token_1().to_string() }]));
+            then.status(StatusCode::OK).json_body(json!([{
+                "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "asset_id": token_1(),
+                "tick_size": TickSize::Hundredth.as_decimal(),
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "1",
+                "asks": [{
+                    "price": "2",
+                    "size": "1"
+                }]
             }]));
         });
 
@@ -675,6 +1125,99 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn books_map_should_key_by_token_and_skip_missing() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/books");
+            then.status(StatusCode::OK).json_body(json!([{
+                "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "asset_id": token_1(),
+                "tick_size": TickSize::Hundredth.as_decimal(),
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "1"
+            }]));
+        });
+
+        let books = client.books_map(&[token_1(), token_2(), token_1()]).await?;
+
+        assert_eq!(books.len(), 1);
+        assert_eq!(books[&token_1()].asset_id, token_1());
+        assert!(!books.contains_key(&token_2()));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn books_map_should_fall_back_to_single_fetches() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let batch = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/books");
+            then.status(StatusCode::BAD_REQUEST)
+                .json_body(json!({ "error": "No orderbook exists for the requested token id" }));
+        });
+        let found = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "market": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "asset_id": token_1(),
+                "tick_size": TickSize::Hundredth.as_decimal(),
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "1"
+            }));
+        });
+        let missing = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_2().to_string());
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "No orderbook exists for the requested token id" }));
+        });
+
+        let books = client.books_map(&[token_1(), token_2()]).await?;
+
+        assert_eq!(books.len(), 1);
+        assert!(books.contains_key(&token_1()));
+        batch.assert();
+        found.assert();
+        missing.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn books_map_should_propagate_other_errors() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let batch = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/books");
+            then.status(StatusCode::TOO_MANY_REQUESTS);
+        });
+        let single = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/book");
+            then.status(StatusCode::OK);
+        });
+
+        let err = client.books_map(&[token_1(), token_2()]).await.unwrap_err();
+
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.status_code, StatusCode::TOO_MANY_REQUESTS);
+        batch.assert();
+        single.assert_calls(0);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn last_trade_price_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1381,37 +1924,42 @@ mod unauthenticated {
 }
 
 mod authenticated {
-    #[cfg(feature = "heartbeats")]
     use std::time::Duration;
 
     use alloy::primitives::Signature;
     use alloy::signers::Signer as _;
-    use alloy::signers::local::LocalSigner;
-    use chrono::NaiveDate;
+    use alloy::signers::local::{LocalSigner, PrivateKeySigner};
+    use chrono::{NaiveDate, TimeDelta};
     use httpmock::Method::{DELETE, GET, POST};
+    use polymarket_client_sdk::auth::ExposeSecret as _;
+    use polymarket_client_sdk::clob::signing::{CachedDomainSigner, OrderSigner as _};
     use polymarket_client_sdk::clob::types::request::{
         BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
         OrdersRequest, TradesRequest, UserRewardsEarningRequest,
     };
     use polymarket_client_sdk::clob::types::response::{
-        ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse, CancelOrdersResponse,
-        CurrentRewardResponse, Earning, HeartbeatResponse, MakerOrder, MarketRewardResponse,
-        MarketRewardsConfig, NotificationPayload, NotificationResponse, OpenOrderResponse,
-        OrderScoringResponse, Page, PostOrderResponse, RewardsConfig, Token,
-        TotalUserEarningResponse, TradeResponse, UserEarningResponse, UserRewardsEarningResponse,
+        ApiKeyInfo, ApiKeysResponse, BalanceAllowanceResponse, BanStatusResponse,
+        CancelOrdersResponse, CurrentRewardResponse, Earning, HeartbeatResponse, MakerOrder,
+        MarketResolvedPayload, MarketRewardResponse, MarketRewardsConfig, MarketRewardsSummary,
+        NotCanceledReason, Notification, NotificationPayload, NotificationResponse,
+        OpenOrderResponse, OrderScoringResponse, Page, PostOrderResponse, RewardsConfig,
+        RewardsSummary, Token, TotalUserEarningResponse, TradeResponse, UserEarningResponse,
+        UserRewardsEarningResponse,
     };
     use polymarket_client_sdk::clob::types::{
-        AssetType, OrderStatusType, OrderType, Side, SignableOrder, SignedOrder, TickSize,
-        TraderSide,
+        AssetType, NotificationType, OrderStatusType, OrderType, Side, SignableOrder, SignedOrder,
+        TickSize, TraderSide,
     };
+    use polymarket_client_sdk::clob::{ApiKeyStrategy, ServerTimePolicy, TimeSource};
     #[cfg(feature = "heartbeats")]
     use polymarket_client_sdk::error::Synchronization;
+    use polymarket_client_sdk::error::{Kind as ErrorKind, Status, Validation};
     use polymarket_client_sdk::types::{Address, address, b256};
 
     use super::*;
     use crate::common::{
         API_KEY, PASSPHRASE, POLY_NONCE, POLY_SIGNATURE, POLY_TIMESTAMP, SECRET, SIGNATURE,
-        TIMESTAMP,
+        TIMESTAMP, TestClient,
     };
 
     #[tokio::test]
@@ -1439,6 +1987,99 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn verify_credentials_should_distinguish_invalid_from_failed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mut mock = server.mock(|when, then| {
+            when.method(GET).path("/auth/api-keys");
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+        assert!(client.verify_credentials().await?);
+        mock.delete();
+
+        let mut mock = server.mock(|when, then| {
+            when.method(GET).path("/auth/api-keys");
+            then.status(StatusCode::UNAUTHORIZED)
+                .json_body(json!({"error": "Unauthorized/Invalid api key"}));
+        });
+        assert!(!client.verify_credentials().await?);
+        mock.delete();
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/auth/api-keys");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+        let err = client.verify_credentials().await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Status>().unwrap().status_code,
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_with_meta_should_return_status_and_rate_limit() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK)
+                .header("X-RateLimit-Limit", "100")
+                .header("X-RateLimit-Remaining", "99")
+                .header("X-RateLimit-Reset", "1700000000")
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        let response = client
+            .get_with_meta::<_, ApiKeysResponse>("/auth/api-keys", &())
+            .await?;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.data.keys(), [API_KEY]);
+        let rate_limit = response.rate_limit.unwrap();
+        assert_eq!(rate_limit.limit, Some(100));
+        assert_eq!(rate_limit.remaining, Some(99));
+        assert_eq!(
+            rate_limit.reset_at,
+            chrono::DateTime::from_timestamp(1_700_000_000, 0)
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn api_key_infos_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/auth/api-keys");
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        let response = client.api_key_infos().await?;
+
+        let expected = vec![ApiKeyInfo::builder().key(API_KEY).build()];
+
+        assert_eq!(response, expected);
+        assert!(!response[0].is_builder);
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn delete_api_keys_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1460,6 +2101,31 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn delete_unknown_api_key_should_return_api_key_not_found() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE).path("/auth/api-key");
+            then.status(StatusCode::UNAUTHORIZED)
+                .json_body(json!({ "error": "Unauthorized/Invalid api key" }));
+        });
+
+        let err = client.delete_api_key().await.unwrap_err();
+
+        assert_eq!(err.kind(), polymarket_client_sdk::error::Kind::Status);
+        let not_found = err
+            .downcast_ref::<polymarket_client_sdk::error::ApiKeyNotFound>()
+            .unwrap();
+        assert_eq!(not_found.status.status_code, StatusCode::UNAUTHORIZED);
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.method, reqwest::Method::DELETE);
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn closed_only_mode_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1511,7 +2177,10 @@ mod authenticated {
         });
 
         let funder = address!("0x995c9b1f779c04e65AF8ea3360F96c43b5e62316");
-        let config = Config::builder().use_server_time(true).build();
+        let config = Config::builder()
+            .use_server_time(true)
+            .server_time_cache(std::time::Duration::ZERO)
+            .build();
         let client = Client::new(&server.base_url(), config)?
             .authentication_builder(&signer)
             .funder(funder)
@@ -1576,102 +2245,583 @@ mod authenticated {
     }
 
     #[tokio::test]
-    async fn post_order_should_succeed() -> anyhow::Result<()> {
+    async fn derive_strategy_should_not_create_api_key() -> anyhow::Result<()> {
         let server = MockServer::start();
-        let client = create_authenticated(&server).await?;
-
-        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
 
-        let mock = server.mock(|when, then| {
-            when.method(POST)
-                .path("/order")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE)
-                .json_body(json!({
-                    "order": {
-                        "expiration": "0",
-                        "feeRateBps": "0",
-                        "maker": Address::ZERO,
-                        "makerAmount": "0",
-                        "nonce": "0",
-                        "salt": 0,
-                        "side": Side::Buy,
-                        "signature": "0x0d18c04a653d89bf7375636adb7db69cffe362755960dc6ce8a0d46b04355b767958fae51c48e0e4b0908347442cb461e811d2f5a751303f7a8c1f75e17b3e701b",
-                        "signatureType": 0,
-                        "signer": Address::ZERO,
-                        "taker": Address::ZERO,
-                        "takerAmount": "0",
-                        "tokenId": "0"
-                    },
-                    "orderType": "FOK",
-                    "owner": "00000000-0000-0000-0000-000000000000"
-                }));
+        let create = server.mock(|when, then| {
+            when.method(POST).path("/auth/api-key");
+            then.status(StatusCode::OK);
+        });
+        let derive = server.mock(|when, then| {
+            when.method(GET).path("/auth/derive-api-key");
             then.status(StatusCode::OK).json_body(json!({
-                "error_msg": "",
-                "makingAmount": "",
-                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
-                "status": "live",
-                "success": true,
-                "takingAmount": ""
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
             }));
         });
 
-        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
-        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
-        let response = client.post_order(signed_order).await?;
-
-        let expected = PostOrderResponse::builder()
-            .making_amount(Decimal::ZERO)
-            .taking_amount(Decimal::ZERO)
-            .order_id("0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0")
-            .status(OrderStatusType::Live)
-            .success(true)
+        let config = Config::builder()
+            .api_key_strategy(ApiKeyStrategy::Derive)
             .build();
+        Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
 
-        assert_eq!(response, expected);
-        mock.assert();
+        create.assert_calls(0);
+        derive.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn post_order_should_accept_transactions_hashes_alias() -> anyhow::Result<()> {
+    async fn server_time_cache_should_share_offset_across_clones() -> anyhow::Result<()> {
         let server = MockServer::start();
-        let client = create_authenticated(&server).await?;
-
-        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
 
-        let mock = server.mock(|when, then| {
-            when.method(POST)
-                .path("/order")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE);
+        let server_time = Utc::now().timestamp() + 120;
+        let time = server.mock(|when, then| {
+            when.method(GET).path("/time");
+            then.status(StatusCode::OK).json_body(server_time);
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/auth/derive-api-key");
             then.status(StatusCode::OK).json_body(json!({
-                "error_msg": "",
-                "makingAmount": "100",
-                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
-                "status": "matched",
-                "success": true,
-                "takingAmount": "50",
-                "transactionsHashes": ["0x2369f69af45a559ad6e769d3d209d2379af9d412315e27b9283594a6392557b6"]
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
             }));
         });
+        let api_keys = server.mock(|when, then| {
+            when.method(GET).path("/auth/api-keys");
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
 
-        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
-        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
-        let response = client.post_order(signed_order).await?;
-
-        let expected = PostOrderResponse::builder()
-            .making_amount(Decimal::from(100))
-            .taking_amount(Decimal::from(50))
+        // The default cache is short, but long enough to cover this test
+        let config = Config::builder()
+            .use_server_time(true)
+            .api_key_strategy(ApiKeyStrategy::Derive)
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        let tasks: Vec<_> = std::iter::repeat_with(|| {
+            let client = client.clone();
+            tokio::spawn(async move {
+                client.api_keys().await?;
+                anyhow::Ok(client.server_time_offset())
+            })
+        })
+        .take(4)
+        .collect();
+
+        for task in tasks {
+            let offset = task.await??.unwrap();
+            assert_eq!(Some(offset), client.server_time_offset());
+            assert!((TimeDelta::seconds(118)..=TimeDelta::seconds(122)).contains(&offset));
+        }
+        time.assert_calls(1);
+        api_keys.assert_calls(4);
+
+        let offset = client.server_time_offset();
+        let client = client.deauthenticate().await?;
+        assert_eq!(client.server_time_offset(), offset);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn authenticate_with_nonce_should_derive_that_key() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let derive = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/derive-api-key")
+                .header(POLY_ADDRESS, signer.address().to_string().to_lowercase())
+                .header(POLY_NONCE, "3");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let config = Config::builder()
+            .api_key_strategy(ApiKeyStrategy::Derive)
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .nonce(3)
+            .authenticate()
+            .await?;
+
+        assert_eq!(client.credentials().key(), API_KEY);
+        assert_eq!(client.credentials().nonce(), Some(3));
+        derive.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn with_credentials_should_reuse_saved_credentials() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let derive = server.mock(|when, then| {
+            when.method(GET).path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let config = Config::builder()
+            .api_key_strategy(ApiKeyStrategy::Derive)
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .nonce(2)
+            .authenticate()
+            .await?;
+        let saved = serde_json::to_string(client.credentials())?;
+
+        let restored = Client::with_credentials(
+            &server.base_url(),
+            Config::default(),
+            &signer,
+            serde_json::from_str(&saved)?,
+        )
+        .await?;
+
+        assert_eq!(restored.address(), signer.address());
+        assert_eq!(restored.credentials().key(), API_KEY);
+        assert_eq!(restored.credentials().secret().expose_secret(), SECRET);
+        assert_eq!(
+            restored.credentials().passphrase().expose_secret(),
+            PASSPHRASE
+        );
+        assert_eq!(restored.credentials().nonce(), Some(2));
+        derive.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn authenticate_many_should_authenticate_each_signer() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let first = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let second = LocalSigner::random().with_chain_id(Some(POLYGON));
+        let without_chain = LocalSigner::random();
+
+        let derive = |signer: &LocalSigner<_>, key: Uuid| {
+            let address = signer.address().to_string().to_lowercase();
+            server.mock(move |when, then| {
+                when.method(GET)
+                    .path("/auth/derive-api-key")
+                    .header(POLY_ADDRESS, address)
+                    .header(POLY_NONCE, "0");
+                then.status(StatusCode::OK).json_body(json!({
+                    "apiKey": key.to_string(),
+                    "passphrase": PASSPHRASE,
+                    "secret": SECRET
+                }));
+            })
+        };
+        let first_mock = derive(&first, API_KEY);
+        let second_mock = derive(&second, Uuid::new_v4());
+
+        let config = Config::builder()
+            .api_key_strategy(ApiKeyStrategy::Derive)
+            .build();
+        let results = Client::new(&server.base_url(), config)?
+            .authenticate_many(&[first.clone(), without_chain, second.clone()])
+            .await;
+
+        let [first_client, failed, second_client] = results.try_into().unwrap();
+        let first_client = first_client?;
+        let second_client = second_client?;
+        failed.unwrap_err();
+
+        assert_eq!(first_client.address(), first.address());
+        assert_eq!(second_client.address(), second.address());
+        first_mock.assert();
+        second_mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_strategy_should_not_fall_back_to_derive() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let create = server.mock(|when, then| {
+            when.method(POST).path("/auth/api-key");
+            then.status(StatusCode::BAD_REQUEST);
+        });
+        let derive = server.mock(|when, then| {
+            when.method(GET).path("/auth/derive-api-key");
+            then.status(StatusCode::OK);
+        });
+
+        let config = Config::builder()
+            .api_key_strategy(ApiKeyStrategy::Create)
+            .build();
+        let err = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Status);
+        create.assert();
+        derive.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn server_time_failure_should_fall_back_to_local_time() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+        let mock2 = server.mock(|when, then| {
+            when.method(GET).path("/time");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        let config = Config::builder()
+            .use_server_time(true)
+            .server_time_policy(ServerTimePolicy::FallbackToLocal)
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        let response = post_live_order(&server, &client, &signer).await?;
+
+        assert_eq!(response.time_source, Some(TimeSource::Local));
+        mock.assert();
+        // Authenticating and posting the order each fail to fetch the server time
+        mock2.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn server_time_failure_should_fail_when_strict() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/auth/api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+        let mock2 = server.mock(|when, then| {
+            when.method(GET).path("/time");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        let config = Config::builder().use_server_time(true).build();
+        let err = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Status);
+        mock.assert_calls(0);
+        // Both the create and the derive attempt fail to fetch the server time
+        mock2.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn server_time_should_be_reported_as_time_source() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/auth/api-key")
+                .header(POLY_TIMESTAMP, TIMESTAMP);
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/time");
+            then.status(StatusCode::OK)
+                .json_body(TIMESTAMP.parse::<i64>().unwrap());
+        });
+
+        let config = Config::builder()
+            .use_server_time(true)
+            .server_time_policy(ServerTimePolicy::FallbackToLocal)
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        let response = post_live_order(&server, &client, &signer).await?;
+
+        assert_eq!(response.time_source, Some(TimeSource::Server));
+
+        Ok(())
+    }
+
+    /// Signs a default order and posts it to a mocked `/order` endpoint that accepts it.
+    async fn post_live_order(
+        server: &MockServer,
+        client: &TestClient,
+        signer: &PrivateKeySigner,
+    ) -> anyhow::Result<PostOrderResponse> {
+        ensure_requirements(server, token_1(), TickSize::Hundredth);
+        server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let signed_order = client.sign(signer, SignableOrder::default()).await?;
+        Ok(client.post_order(signed_order).await?)
+    }
+
+    #[tokio::test]
+    async fn post_order_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/order")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .json_body(json!({
+                    "order": {
+                        "expiration": "0",
+                        "feeRateBps": "0",
+                        "maker": Address::ZERO,
+                        "makerAmount": "0",
+                        "nonce": "0",
+                        "salt": 0,
+                        "side": Side::Buy,
+                        "signature": "0x0d18c04a653d89bf7375636adb7db69cffe362755960dc6ce8a0d46b04355b767958fae51c48e0e4b0908347442cb461e811d2f5a751303f7a8c1f75e17b3e701b",
+                        "signatureType": 0,
+                        "signer": Address::ZERO,
+                        "taker": Address::ZERO,
+                        "takerAmount": "0",
+                        "tokenId": "0"
+                    },
+                    "orderType": "FOK",
+                    "owner": "00000000-0000-0000-0000-000000000000"
+                }));
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.post_order(signed_order).await?;
+
+        let expected = PostOrderResponse::builder()
+            .making_amount(Decimal::ZERO)
+            .taking_amount(Decimal::ZERO)
+            .order_id("0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0")
+            .status(OrderStatusType::Live)
+            .success(true)
+            .time_source(TimeSource::Server)
+            .build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_with_idempotency_key_should_send_header() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+        let key = Uuid::new_v4();
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/order")
+                .header("idempotency-key", key.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "live",
+                "success": true,
+                "takingAmount": ""
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client
+            .post_order_with_idempotency_key(&signed_order, key, 3)
+            .await?;
+
+        assert!(response.success);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_with_idempotency_key_should_not_retry_status_errors() -> anyhow::Result<()>
+    {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/order");
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let err = client
+            .post_order_with_idempotency_key(&signed_order, Uuid::new_v4(), 3)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Status);
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_raw_should_send_authenticated_json() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/unwrapped")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .header_exists(POLY_SIGNATURE)
+                .json_body(json!({ "ids": ["1", "2"] }));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "accepted": 2 }));
+        });
+
+        let response: serde_json::Value = client
+            .post_raw("/unwrapped", &json!({ "ids": ["1", "2"] }))
+            .await?;
+
+        assert_eq!(response, json!({ "accepted": 2 }));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sign_with_cached_domain_signer_should_match_sign() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let cached = CachedDomainSigner::new(signer.clone())?;
+
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let cached_order = client.sign_with(&cached, SignableOrder::default()).await?;
+
+        assert_eq!(cached_order, signed_order);
+        assert_eq!(cached.address(), signer.address());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn post_order_should_accept_transactions_hashes_alias() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/order")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK).json_body(json!({
+                "error_msg": "",
+                "makingAmount": "100",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "matched",
+                "success": true,
+                "takingAmount": "50",
+                "transactionsHashes": ["0x2369f69af45a559ad6e769d3d209d2379af9d412315e27b9283594a6392557b6"]
+            }));
+        });
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let response = client.post_order(signed_order).await?;
+
+        let expected = PostOrderResponse::builder()
+            .making_amount(Decimal::from(100))
+            .taking_amount(Decimal::from(50))
             .order_id("0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0")
             .status(OrderStatusType::Matched)
             .success(true)
             .transaction_hashes(vec![b256!(
                 "2369f69af45a559ad6e769d3d209d2379af9d412315e27b9283594a6392557b6"
             )])
+            .time_source(TimeSource::Server)
             .build();
 
         assert_eq!(response, expected);
@@ -1857,6 +3007,125 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn cancel_order_should_accept_empty_body() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE).path("/order");
+            then.status(StatusCode::OK);
+        });
+
+        let response = client.cancel_order("1").await?;
+
+        assert_eq!(response, CancelOrdersResponse::default());
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_all_orders_should_accept_null_body() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE).path("/cancel-all");
+            then.status(StatusCode::OK).json_body(json!(null));
+        });
+
+        let response = client.cancel_all_orders().await?;
+
+        assert_eq!(response, CancelOrdersResponse::default());
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_balance_allowance_should_accept_empty_body() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/balance-allowance/update");
+            then.status(StatusCode::OK);
+        });
+
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(AssetType::Collateral)
+            .build()?;
+        client.update_balance_allowance(request).await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_balance_allowance_should_ignore_json_body() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/balance-allowance/update");
+            then.status(StatusCode::OK).json_body(json!({}));
+        });
+
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(AssetType::Collateral)
+            .build()?;
+        client.update_balance_allowance(request).await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_orders_should_classify_not_canceled_reasons() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE).path("/orders");
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": ["1"],
+                "not_canceled": {
+                    "2": "matched orders can't be canceled",
+                    "3": "order not found",
+                    "4": "the order is already canceled",
+                    "5": "market is closed"
+                }
+            }));
+        });
+
+        let response = client.cancel_orders(&["1", "2", "3", "4", "5"]).await?;
+
+        assert!(response.is_canceled("1"));
+        assert!(!response.is_canceled("2"));
+        assert_eq!(response.not_canceled_reason("1"), None);
+        assert_eq!(
+            response.not_canceled_reason("2"),
+            Some(NotCanceledReason::Matched)
+        );
+        assert_eq!(
+            response.not_canceled_reason("3"),
+            Some(NotCanceledReason::NotFound)
+        );
+        assert_eq!(
+            response.not_canceled_reason("4"),
+            Some(NotCanceledReason::AlreadyCanceled)
+        );
+        assert_eq!(
+            response.not_canceled_reason("5"),
+            Some(NotCanceledReason::Other("market is closed".to_owned()))
+        );
+        assert_eq!(response.not_canceled_reasons().count(), 4);
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn cancel_order_should_accept_snake_case_not_canceled() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1923,6 +3192,166 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn cancel_orders_should_split_into_batches() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(GET).path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let config = Config::builder()
+            .api_key_strategy(ApiKeyStrategy::Derive)
+            .cancel_batch_size(2)
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        let first = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/orders")
+                .json_body(json!(["1", "2"]));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["1", "2"] }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/orders")
+                .json_body(json!(["3", "4"]));
+            then.status(StatusCode::OK).json_body(json!({
+                "canceled": ["3"],
+                "not_canceled": { "4": "order not found" }
+            }));
+        });
+        let third = server.mock(|when, then| {
+            when.method(DELETE).path("/orders").json_body(json!(["5"]));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["5"] }));
+        });
+
+        let response = client.cancel_orders(&["1", "2", "3", "4", "5"]).await?;
+
+        let expected = CancelOrdersResponse::builder()
+            .canceled(vec![
+                "1".to_owned(),
+                "2".to_owned(),
+                "3".to_owned(),
+                "5".to_owned(),
+            ])
+            .not_canceled(HashMap::from([(
+                "4".to_owned(),
+                "order not found".to_owned(),
+            )]))
+            .build();
+
+        assert_eq!(response, expected);
+        first.assert();
+        second.assert();
+        third.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_orders_should_keep_successful_batches_when_one_fails() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(GET).path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let config = Config::builder()
+            .api_key_strategy(ApiKeyStrategy::Derive)
+            .cancel_batch_size(2)
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        let first = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/orders")
+                .json_body(json!(["1", "2"]));
+            then.status(StatusCode::OK)
+                .json_body(json!({ "canceled": ["1", "2"] }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/orders")
+                .json_body(json!(["3", "4"]));
+            then.status(StatusCode::INTERNAL_SERVER_ERROR)
+                .json_body(json!({ "error": "internal error" }));
+        });
+
+        let response = client.cancel_orders(&["1", "2", "3", "4"]).await?;
+
+        assert_eq!(response.canceled, ["1", "2"]);
+        assert!(response.not_canceled.is_empty());
+        assert_eq!(response.failed_batches.len(), 1);
+        let failed = &response.failed_batches[0];
+        assert_eq!(failed.order_ids, ["3", "4"]);
+        assert_eq!(failed.error.kind(), ErrorKind::Status);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cancel_orders_should_fail_when_every_batch_fails() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(GET).path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let config = Config::builder()
+            .api_key_strategy(ApiKeyStrategy::Derive)
+            .cancel_batch_size(2)
+            .build();
+        let client = Client::new(&server.base_url(), config)?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE).path("/orders");
+            then.status(StatusCode::UNAUTHORIZED)
+                .json_body(json!({ "error": "Unauthorized/Invalid api key" }));
+        });
+
+        let err = client
+            .cancel_orders(&["1", "2", "3", "4", "5"])
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Status);
+        mock.assert_calls(3);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn cancel_all_orders_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2172,9 +3601,9 @@ mod authenticated {
 
         let expected = vec![
             NotificationResponse::builder()
-                .r#type(1)
+                .r#type(NotificationType::OrderCancellation)
                 .owner(API_KEY)
-                .payload(NotificationPayload::builder()
+                .payload(Notification::OrderCancellation(NotificationPayload::builder()
                     .asset_id(U256::from_str("71321045679252212594626385532706912750332728571942532289631379312455583992563").unwrap())
                     .condition_id(b256!(
                         "5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1"
@@ -2203,79 +3632,465 @@ mod authenticated {
                     ))
                     .order_type(OrderType::Unknown(String::new()))
                     .build()
-                )
+                ))
                 .build(),
         ];
 
-        assert_eq!(response, expected);
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn notifications_should_parse_id_and_timestamp() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/notifications");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": 42,
+                    "type": 99,
+                    "owner": API_KEY,
+                    "payload": { "foo": "bar" },
+                    "timestamp": 1_705_000_000
+                }
+            ]));
+        });
+
+        let response = client.notifications().await?;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].id.as_deref(), Some("42"));
+        assert_eq!(response[0].r#type, NotificationType::Unknown(99));
+        assert_eq!(
+            response[0].timestamp,
+            DateTime::from_timestamp(1_705_000_000, 0)
+        );
+        assert_eq!(
+            response[0].payload,
+            Notification::Unknown(json!({ "foo": "bar" }))
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn notifications_should_parse_typed_payloads() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/notifications");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "type": 2,
+                    "owner": API_KEY,
+                    "payload": {
+                        "asset_id": "71321045679252212594626385532706912750332728571942532289631379312455583992563",
+                        "condition_id": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+                        "eventSlug": "will-trump-win-the-2024-iowa-caucus",
+                        "icon": "",
+                        "image": "",
+                        "market": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+                        "market_slug": "will-trump-win-the-2024-iowa-caucus",
+                        "matched_size": "20",
+                        "order_id": "0x2ae21876d2702d8b71308d0999062db9625a691ce4593c5f10230eeeff945e70",
+                        "original_size": "20",
+                        "outcome": "YES",
+                        "outcome_index": 0,
+                        "owner": API_KEY,
+                        "price": "0.12",
+                        "question": "Will Trump win the 2024 Iowa Caucus?",
+                        "remaining_size": "0",
+                        "seriesSlug": "",
+                        "side": "BUY",
+                        "trade_id": "565a5035-d70e-4493-9215-8cae52d26efe",
+                        "transaction_hash": "0x3bc57dcae83a930df64fce8fdc46a8fca9b98af92a7b83a8a2f2c657446c2a71",
+                        "type": "GTC"
+                    }
+                },
+                {
+                    "type": 4,
+                    "owner": API_KEY,
+                    "payload": {
+                        "condition_id": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+                        "market_slug": "will-trump-win-the-2024-iowa-caucus",
+                        "question": "Will Trump win the 2024 Iowa Caucus?",
+                        "outcome": "YES"
+                    }
+                },
+                {
+                    "type": 99,
+                    "owner": API_KEY,
+                    "payload": { "message": "something new" }
+                },
+                {
+                    "type": 2,
+                    "owner": API_KEY,
+                    "payload": { "unexpected": true }
+                }
+            ]));
+        });
+
+        let response = client.notifications().await?;
+
+        assert_eq!(response.len(), 4);
+
+        assert_eq!(response[0].r#type, NotificationType::OrderFill);
+        let Notification::OrderFill(fill) = &response[0].payload else {
+            panic!("expected an order fill, got {:?}", response[0].payload);
+        };
+        assert_eq!(fill.matched_size, dec!(20));
+        assert_eq!(fill.side, Side::Buy);
+        assert_eq!(
+            fill.trade_id.as_deref(),
+            Some("565a5035-d70e-4493-9215-8cae52d26efe")
+        );
+        assert_eq!(fill.order_type, OrderType::GTC);
+
+        assert_eq!(response[1].r#type, NotificationType::MarketResolved);
+        assert_eq!(
+            response[1].payload,
+            Notification::MarketResolved(
+                MarketResolvedPayload::builder()
+                    .condition_id(b256!(
+                        "5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1"
+                    ))
+                    .market_slug("will-trump-win-the-2024-iowa-caucus")
+                    .question("Will Trump win the 2024 Iowa Caucus?")
+                    .outcome("YES")
+                    .build()
+            )
+        );
+
+        assert_eq!(response[2].r#type, NotificationType::Unknown(99));
+        assert_eq!(
+            response[2].payload,
+            Notification::Unknown(json!({ "message": "something new" }))
+        );
+
+        assert_eq!(
+            response[3].payload,
+            Notification::Unknown(json!({ "unexpected": true }))
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_notifications_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/notifications")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .query_param("ids", "1,2");
+            then.status(StatusCode::OK).json_body(json!(null));
+        });
+
+        let request = DeleteNotificationsRequest::builder()
+            .notification_ids(vec!["1".to_owned(), "2".to_owned()])
+            .build();
+        client.delete_notifications(&request).await?;
+
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn drop_notifications_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/notifications")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .query_param("ids", "1,2");
+            then.status(StatusCode::OK).json_body(json!(null));
+        });
+
+        client
+            .drop_notifications(&["1".to_owned(), "2".to_owned()])
+            .await?;
+
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn drop_notifications_with_no_ids_should_not_send_request() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(DELETE).path("/notifications");
+            then.status(StatusCode::OK).json_body(json!(null));
+        });
+
+        client.drop_notifications(&[]).await?;
+
+        mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn balance_allowance_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE)
+                .query_param("asset_type", "COLLATERAL")
+                .query_param("token_id", token_1().to_string())
+                .query_param("signature_type", "0");
+            // Trying different Decimal deserialization routes
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": 0,
+                "allowances": { Address::ZERO.to_string(): "1" }
+            }));
+        });
+
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(AssetType::Collateral)
+            .token_id(token_1())
+            .build()?;
+        let response = client.balance_allowance(request).await?;
+
+        let expected = BalanceAllowanceResponse::builder()
+            .balance(Decimal::ZERO)
+            .allowances(HashMap::from_iter([(Address::ZERO, "1".to_owned())]))
+            .build();
+
+        assert_eq!(response, expected);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn collateral_balance_should_be_in_whole_units() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", "COLLATERAL")
+                .query_param_missing("token_id");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "12500000",
+                "allowances": { Address::ZERO.to_string(): "0" }
+            }));
+        });
+
+        assert_eq!(client.collateral_balance().await?, dec!(12.5));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn token_balance_should_request_conditional_token() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", "CONDITIONAL")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "3000000",
+                "allowances": {}
+            }));
+        });
+
+        assert_eq!(client.token_balance(token_1()).await?, dec!(3));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn allowance_should_be_smallest_spender_allowance() {
+        let spender = Address::repeat_byte(1);
+        let response = BalanceAllowanceResponse::builder()
+            .balance(Decimal::ZERO)
+            .allowances(HashMap::from_iter([
+                (Address::ZERO, "1000".to_owned()),
+                (spender, "250".to_owned()),
+            ]))
+            .build();
+
+        assert_eq!(response.allowance(), Some(U256::from(250)));
+        assert_eq!(
+            response.allowance_for(Address::ZERO),
+            Some(U256::from(1000))
+        );
+        assert_eq!(response.allowance_for(Address::repeat_byte(2)), None);
+
+        let empty = BalanceAllowanceResponse::builder()
+            .balance(Decimal::ZERO)
+            .build();
+        assert_eq!(empty.allowance(), None);
+    }
+
+    async fn signed_order(
+        server: &MockServer,
+        client: &crate::common::TestClient,
+        side: Side,
+    ) -> anyhow::Result<SignedOrder> {
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        ensure_requirements(server, token_1(), TickSize::Thousandth);
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.512))
+            .size(Decimal::ONE_HUNDRED)
+            .side(side)
+            .build()
+            .await?;
+
+        Ok(client.sign(&signer, order).await?)
+    }
+
+    #[tokio::test]
+    async fn check_order_feasibility_should_report_buy_shortfall() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let order = signed_order(&server, &client, Side::Buy).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", "COLLATERAL");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "50000000",
+                "allowances": { Address::ZERO.to_string(): U256::MAX.to_string() }
+            }));
+        });
+
+        let report = client.check_order_feasibility(&order).await?;
+
+        assert_eq!(report.asset_type, AssetType::Collateral);
+        assert_eq!(report.required, dec!(51.2));
+        assert_eq!(report.balance, dec!(50));
+        assert_eq!(report.min_allowance, None);
+        assert!(!report.sufficient);
+        assert_eq!(report.shortfall, dec!(1.2));
         mock.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn delete_notifications_should_succeed() -> anyhow::Result<()> {
+    async fn check_order_feasibility_should_check_sell_against_token_balance() -> anyhow::Result<()>
+    {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
+        let order = signed_order(&server, &client, Side::Sell).await?;
 
         let mock = server.mock(|when, then| {
-            when.method(DELETE)
-                .path("/notifications")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE)
-                .query_param("ids", "1,2");
-            then.status(StatusCode::OK).json_body(json!(null));
+            when.method(GET)
+                .path("/balance-allowance")
+                .query_param("asset_type", "CONDITIONAL")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": "250000000",
+                "allowances": { Address::ZERO.to_string(): "500000000" }
+            }));
         });
 
-        let request = DeleteNotificationsRequest::builder()
-            .notification_ids(vec!["1".to_owned(), "2".to_owned()])
-            .build();
-        client.delete_notifications(&request).await?;
+        let report = client.check_order_feasibility(&order).await?;
 
+        assert_eq!(report.asset_type, AssetType::Conditional);
+        assert_eq!(report.required, dec!(100));
+        assert_eq!(report.min_allowance, Some(dec!(500)));
+        assert!(report.sufficient);
+        assert_eq!(report.shortfall, Decimal::ZERO);
         mock.assert();
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn balance_allowance_should_succeed() -> anyhow::Result<()> {
+    async fn wait_for_allowance_should_return_once_allowances_are_met() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
         let mock = server.mock(|when, then| {
             when.method(GET)
                 .path("/balance-allowance")
-                .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
-                .header(POLY_API_KEY, API_KEY)
-                .header(POLY_PASSPHRASE, PASSPHRASE)
-                .query_param("asset_type", "COLLATERAL")
-                .query_param("token_id", token_1().to_string())
-                .query_param("signature_type", "0");
-            // Trying different Decimal deserialization routes
+                .query_param("asset_type", "COLLATERAL");
             then.status(StatusCode::OK).json_body(json!({
-                "balance": 0,
-                "allowances": { Address::ZERO.to_string(): "1" }
+                "balance": 10,
+                "allowances": {
+                    Address::ZERO.to_string(): "1000",
+                    Address::repeat_byte(1).to_string(): "500"
+                }
             }));
         });
 
         let request = BalanceAllowanceRequest::builder()
             .asset_type(AssetType::Collateral)
-            .token_id(token_1())
-            .build();
-        let response = client.balance_allowance(request).await?;
-
-        let expected = BalanceAllowanceResponse::builder()
-            .balance(Decimal::ZERO)
-            .allowances(HashMap::from_iter([(Address::ZERO, "1".to_owned())]))
-            .build();
+            .build()?;
+        let response = client
+            .wait_for_allowance(request, U256::from(500), Duration::from_secs(5))
+            .await?;
 
-        assert_eq!(response, expected);
+        assert_eq!(response.balance, Decimal::TEN);
         mock.assert();
 
         Ok(())
     }
 
+    #[tokio::test]
+    async fn wait_for_allowance_should_time_out_when_allowance_is_too_low() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/balance-allowance");
+            then.status(StatusCode::OK).json_body(json!({
+                "balance": 10,
+                "allowances": { Address::ZERO.to_string(): "0" }
+            }));
+        });
+
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(AssetType::Collateral)
+            .build()?;
+        let err = client
+            .wait_for_allowance(request, U256::from(1), Duration::from_millis(50))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Timeout);
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn update_balance_allowance_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2296,7 +4111,7 @@ mod authenticated {
         let request = BalanceAllowanceRequest::builder()
             .asset_type(AssetType::Collateral)
             .token_id(token_1())
-            .build();
+            .build()?;
         client.update_balance_allowance(request).await?;
 
         mock.assert();
@@ -2454,6 +4269,77 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn earnings_range_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let start = NaiveDate::from_ymd_opt(2025, 12, 30).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let mocks: Vec<_> = start
+            .iter_days()
+            .take(3)
+            .map(|date| {
+                server.mock(|when, then| {
+                    when.method(GET)
+                        .path("/rewards/user/total")
+                        .query_param("date", date.to_string());
+                    then.status(StatusCode::OK).json_body(json!([{
+                        "date": date.to_string(),
+                        "asset_address": "0x0000000000000000000000000000000000000001",
+                        "maker_address": "0x0000000000000000000000000000000000000002",
+                        "earnings": 1,
+                        "asset_rate": "0.1"
+                    }]));
+                })
+            })
+            .collect();
+
+        let response = client.earnings_range(start, end).await?;
+
+        let dates: Vec<_> = response.iter().map(|(date, _)| *date).collect();
+        assert_eq!(
+            dates,
+            vec![start, NaiveDate::from_ymd_opt(2025, 12, 31).unwrap(), end]
+        );
+        for (date, earnings) in &response {
+            assert_eq!(earnings.len(), 1);
+            assert_eq!(earnings[0].date, *date);
+        }
+        for mock in mocks {
+            mock.assert();
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn earnings_range_should_fail_on_invalid_range() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let start = NaiveDate::from_ymd_opt(2025, 12, 8).unwrap();
+
+        let err = client
+            .earnings_range(start, start.pred_opt().unwrap())
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(
+            msg,
+            "Unable to fetch earnings: start date 2025-12-08 is after end date 2025-12-07"
+        );
+
+        let err = client
+            .earnings_range(start, start + TimeDelta::days(90))
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+        assert_eq!(msg, "Unable to fetch earnings for 91 days, must be <= 90");
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn user_earnings_and_markets_config_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2707,6 +4593,122 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn my_rewards_summary_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let rewards_mock = server.mock(|when, then| {
+            when.method(GET).path("/rewards/markets/current");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "rewards_max_spread": "0.05",
+                        "rewards_min_size": "20.0",
+                        "rewards_config": [
+                            {
+                                "asset_address": "0x0000000000000000000000000000000000000001",
+                                "start_date": "2024-01-01",
+                                "end_date": "2024-12-31",
+                                "rate_per_day": "20.0",
+                                "total_rewards": "750.0"
+                            }
+                        ]
+                    },
+                    {
+                        "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000002",
+                        "rewards_max_spread": "0.05",
+                        "rewards_min_size": "20.0",
+                        "rewards_config": null
+                    }
+                ],
+                "limit": 2,
+                "count": 2,
+                "next_cursor": "LTE="
+            }));
+        });
+        let percentages_mock = server.mock(|when, then| {
+            when.method(GET).path("/rewards/user/percentages");
+            then.status(StatusCode::OK).json_body(json!({
+                "0x0000000000000000000000000000000000000000000000000000000000000001": 25,
+                "0x0000000000000000000000000000000000000000000000000000000000000002": 50
+            }));
+        });
+        let earnings_mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/rewards/user")
+                .query_param("date", Utc::now().date_naive().to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "date": Utc::now().date_naive().to_string(),
+                        "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                        "asset_address": "0x0000000000000000000000000000000000000001",
+                        "maker_address": "0x0000000000000000000000000000000000000002",
+                        "earnings": "1.5",
+                        "asset_rate": "1"
+                    },
+                    {
+                        "date": Utc::now().date_naive().to_string(),
+                        "condition_id": "0x0000000000000000000000000000000000000000000000000000000000000003",
+                        "asset_address": "0x0000000000000000000000000000000000000001",
+                        "maker_address": "0x0000000000000000000000000000000000000002",
+                        "earnings": "0.5",
+                        "asset_rate": "1"
+                    }
+                ],
+                "limit": 2,
+                "count": 2,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let summary = client.my_rewards_summary().await?;
+
+        let expected = RewardsSummary::builder()
+            .date(Utc::now().date_naive())
+            .accrued(Decimal::TWO)
+            .projected_daily_total(dec!(5))
+            .markets(vec![
+                MarketRewardsSummary::builder()
+                    .condition_id(b256!(
+                        "0000000000000000000000000000000000000000000000000000000000000001"
+                    ))
+                    .accrued(dec!(1.5))
+                    .percentage(dec!(25))
+                    .rate_per_day(dec!(20))
+                    .projected_daily(dec!(5))
+                    .build(),
+                MarketRewardsSummary::builder()
+                    .condition_id(b256!(
+                        "0000000000000000000000000000000000000000000000000000000000000002"
+                    ))
+                    .accrued(Decimal::ZERO)
+                    .percentage(dec!(50))
+                    .rate_per_day(Decimal::ZERO)
+                    .projected_daily(Decimal::ZERO)
+                    .build(),
+                MarketRewardsSummary::builder()
+                    .condition_id(b256!(
+                        "0000000000000000000000000000000000000000000000000000000000000003"
+                    ))
+                    .accrued(dec!(0.5))
+                    .percentage(Decimal::ZERO)
+                    .rate_per_day(Decimal::ZERO)
+                    .projected_daily(Decimal::ZERO)
+                    .build(),
+            ])
+            .build();
+
+        assert_eq!(summary, expected);
+        rewards_mock.assert();
+        percentages_mock.assert();
+        earnings_mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn raw_rewards_for_market_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -2925,12 +4927,12 @@ mod builder_authenticated {
     use alloy::signers::Signer as _;
     use alloy::signers::local::LocalSigner;
     use httpmock::Method::DELETE;
-    use polymarket_client_sdk::auth::builder::Config as BuilderConfig;
+    use polymarket_client_sdk::auth::builder::{Config as BuilderConfig, Fee as BuilderFee};
     use polymarket_client_sdk::clob::types::request::TradesRequest;
     use polymarket_client_sdk::clob::types::response::{
         BuilderApiKeyResponse, BuilderTradeResponse, Page,
     };
-    use polymarket_client_sdk::clob::types::{OrderStatusType, Side};
+    use polymarket_client_sdk::clob::types::{OrderStatusType, Side, SignableOrder, TickSize};
     use polymarket_client_sdk::types::{address, b256};
 
     use super::*;
@@ -2965,7 +4967,10 @@ mod builder_authenticated {
                 .json_body(TIMESTAMP.parse::<i64>().unwrap());
         });
 
-        let config = Config::builder().use_server_time(true).build();
+        let config = Config::builder()
+            .use_server_time(true)
+            .server_time_cache(std::time::Duration::ZERO)
+            .build();
         let builder_config = BuilderConfig::remote(&server.base_url(), Some("token".to_owned()))?;
         let client = Client::new(&server.base_url(), config)?
             .authentication_builder(&signer)
@@ -3052,7 +5057,10 @@ mod builder_authenticated {
                 .json_body(TIMESTAMP.parse::<i64>().unwrap());
         });
 
-        let config = Config::builder().use_server_time(true).build();
+        let config = Config::builder()
+            .use_server_time(true)
+            .server_time_cache(std::time::Duration::ZERO)
+            .build();
         let builder_config = BuilderConfig::remote(&server.base_url(), Some("token".to_owned()))?;
         let client = Client::new(&server.base_url(), config)?
             .authentication_builder(&signer)
@@ -3122,7 +5130,10 @@ mod builder_authenticated {
                 .json_body(TIMESTAMP.parse::<i64>().unwrap());
         });
 
-        let config = Config::builder().use_server_time(true).build();
+        let config = Config::builder()
+            .use_server_time(true)
+            .server_time_cache(std::time::Duration::ZERO)
+            .build();
         let builder_config = BuilderConfig::remote(&server.base_url(), Some("token".to_owned()))?;
         let client = Client::new(&server.base_url(), config)?
             .authentication_builder(&signer)
@@ -3243,6 +5254,185 @@ mod builder_authenticated {
         mock3.assert();
         mock4.assert();
 
+        Ok(())
+    }
+    #[tokio::test]
+    async fn promote_to_builder_with_fee_should_keep_fee_off_the_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY,
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/")
+                .header("authorization", "Bearer token");
+            then.status(StatusCode::OK).json_body(json!({
+                POLY_BUILDER_API_KEY: BUILDER_API_KEY,
+                POLY_BUILDER_PASSPHRASE: BUILDER_PASSPHRASE,
+                POLY_BUILDER_SIGNATURE: "signature",
+                POLY_BUILDER_TIMESTAMP: "1",
+            }));
+        });
+        ensure_requirements(&server, U256::ZERO, TickSize::Hundredth);
+
+        let recipient = address!("00000000000000000000000000006275696c6431");
+        let builder_config = BuilderConfig::remote(&server.base_url(), Some("token".to_owned()))?;
+        let client = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?
+            .promote_to_builder_with_fee(builder_config, BuilderFee::new(25, recipient))
+            .await?;
+
+        assert_eq!(client.builder_fee(), Some(BuilderFee::new(25, recipient)));
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/order")
+                .header(POLY_BUILDER_API_KEY, BUILDER_API_KEY);
+            then.status(StatusCode::OK).json_body(json!({
+                "makingAmount": "100",
+                "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+                "status": "matched",
+                "success": true,
+                "takingAmount": "200"
+            }));
+        });
+
+        let signed_order = client.sign(&signer, SignableOrder::default()).await?;
+        let body = serde_json::to_value(&signed_order)?;
+        assert!(body.get("builderFeeRateBps").is_none());
+        assert!(body.get("builderFeeRecipient").is_none());
+
+        let response = client.post_order(signed_order).await?;
+
+        assert!(response.success);
+        // 25 basis points of the 100 USDC the buy matched
+        assert_eq!(response.builder_fee, Some(dec!(0.25)));
+        mock.assert();
+
+        Ok(())
+    }
+    #[cfg(feature = "data")]
+    #[tokio::test]
+    async fn builder_positions_and_activity_should_succeed() -> anyhow::Result<()> {
+        use polymarket_client_sdk::data::types::ActivityType;
+        use polymarket_client_sdk::data::types::request::{ActivityRequest, PositionsRequest};
+
+        let server = MockServer::start();
+
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key");
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY,
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/")
+                .header("authorization", "Bearer token");
+            then.status(StatusCode::OK).json_body(json!({
+                POLY_BUILDER_API_KEY: BUILDER_API_KEY,
+                POLY_BUILDER_PASSPHRASE: BUILDER_PASSPHRASE,
+                POLY_BUILDER_SIGNATURE: "signature",
+                POLY_BUILDER_TIMESTAMP: "1",
+            }));
+        });
+
+        let builder_config = BuilderConfig::remote(&server.base_url(), Some("token".to_owned()))?;
+        let client = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .authenticate()
+            .await?
+            .promote_to_builder(builder_config)
+            .await?;
+
+        let user = address!("1234567890abcdef1234567890abcdef12345678");
+        let positions_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/builder/positions")
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_BUILDER_API_KEY, BUILDER_API_KEY)
+                .query_param("user", user.to_string().to_lowercase());
+            then.status(StatusCode::OK).json_body(json!([{
+                "proxyWallet": user,
+                "asset": "1",
+                "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+                "size": 100,
+                "avgPrice": 0.65,
+                "initialValue": 65,
+                "currentValue": 70,
+                "cashPnl": 5,
+                "percentPnl": 7.69,
+                "totalBought": 100,
+                "realizedPnl": 0,
+                "percentRealizedPnl": 0,
+                "curPrice": 0.7,
+                "redeemable": false,
+                "mergeable": false,
+                "title": "Will BTC hit $100k?",
+                "slug": "btc-100k",
+                "icon": "",
+                "eventSlug": "crypto-prices",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "oppositeOutcome": "No",
+                "oppositeAsset": "2",
+                "endDate": "2025-12-31",
+                "negativeRisk": false
+            }]));
+        });
+        let activity_mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/builder/activity")
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_BUILDER_API_KEY, BUILDER_API_KEY)
+                .query_param("user", user.to_string().to_lowercase())
+                .query_param("type", "REDEEM");
+            then.status(StatusCode::OK).json_body(json!([{
+                "proxyWallet": user,
+                "timestamp": 1_703_980_900,
+                "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+                "type": "REDEEM",
+                "size": 100,
+                "usdcSize": 100,
+                "transactionHash": "0x2222222222222222222222222222222222222222222222222222222222222222"
+            }]));
+        });
+
+        let positions = client
+            .builder_positions(&PositionsRequest::builder().user(user).build())
+            .await?;
+        let activity = client
+            .builder_activity(
+                &ActivityRequest::builder()
+                    .user(user)
+                    .activity_types(vec![ActivityType::Redeem])
+                    .build(),
+            )
+            .await?;
+
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].size, dec!(100));
+        assert_eq!(activity.len(), 1);
+        assert_eq!(activity[0].activity_type, ActivityType::Redeem);
+        positions_mock.assert();
+        activity_mock.assert();
+
         Ok(())
     }
 }
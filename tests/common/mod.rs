@@ -89,7 +89,11 @@ pub async fn create_authenticated(server: &MockServer) -> anyhow::Result<TestCli
             .json_body(TIMESTAMP.parse::<i64>().unwrap());
     });
 
-    let config = Config::builder().use_server_time(true).build();
+    // Fetch the server time for every request, so that auth headers carry the mocked timestamp
+    let config = Config::builder()
+        .use_server_time(true)
+        .server_time_cache(std::time::Duration::ZERO)
+        .build();
     let client = Client::new(&server.base_url(), config)?
         .authentication_builder(&signer)
         .authenticate()
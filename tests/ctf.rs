@@ -269,7 +269,8 @@ mod binary_market_convenience_methods {
 }
 
 mod neg_risk {
-    use polymarket_client_sdk::ctf::types::RedeemNegRiskRequest;
+    use polymarket_client_sdk::ctf::types::{ConvertPositionsRequest, RedeemNegRiskRequest};
+    use polymarket_client_sdk::error::Validation;
 
     use super::*;
 
@@ -297,4 +298,32 @@ mod neg_risk {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn convert_positions_should_require_neg_risk_adapter() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::new(provider, POLYGON)?;
+
+        let request = ConvertPositionsRequest::for_questions(B256::ZERO, &[0], U256::from(1));
+        let err = client.convert_positions(&request).await.unwrap_err();
+
+        assert!(err.to_string().contains("NegRisk adapter not available"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn convert_positions_should_reject_empty_index_set() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let provider = ProviderBuilder::new().connect(&server.base_url()).await?;
+        let client = Client::with_neg_risk(provider, POLYGON)?;
+
+        let request = ConvertPositionsRequest::for_questions(B256::ZERO, &[], U256::from(1));
+        let err = client.convert_positions(&request).await.unwrap_err();
+
+        assert!(err.downcast_ref::<Validation>().is_some());
+
+        Ok(())
+    }
 }
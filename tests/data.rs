@@ -17,6 +17,7 @@ fn test_condition_id() -> B256 {
 mod health {
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::Client;
+    use polymarket_client_sdk::error::Kind;
     use reqwest::StatusCode;
     use serde_json::json;
 
@@ -39,11 +40,55 @@ mod health {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn health_with_non_ok_status_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": "MAINTENANCE"
+            }));
+        });
+
+        let err = client.health().await.unwrap_err();
+        assert_eq!(err.kind(), Kind::ServiceUnavailable);
+
+        let response = client.with_allow_non_ok_health(true).health().await?;
+        assert_eq!(response.data, "MAINTENANCE");
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn with_user_agent_should_override_default() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?.with_user_agent("my-bot/1.0")?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/")
+                .header("user-agent", "my-bot/1.0");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": "OK"
+            }));
+        });
+
+        client.health().await?;
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod positions {
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::data::types::MarketFilter;
     use polymarket_client_sdk::data::{Client, types::request::PositionsRequest};
+    use polymarket_client_sdk::types::{MarketId, U256};
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
     use serde_json::json;
@@ -135,14 +180,74 @@ mod positions {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn position_should_convert_to_market_id_and_filter() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/positions");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+                    "asset": "2",
+                    "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+                    "size": 1,
+                    "avgPrice": 0.5,
+                    "initialValue": 0.5,
+                    "currentValue": 0.5,
+                    "cashPnl": 0,
+                    "percentPnl": 0,
+                    "totalBought": 1,
+                    "realizedPnl": 0,
+                    "percentRealizedPnl": 0,
+                    "curPrice": 0.5,
+                    "redeemable": false,
+                    "mergeable": false,
+                    "title": "Will BTC hit $100k?",
+                    "slug": "btc-100k",
+                    "icon": "",
+                    "eventSlug": "crypto-prices",
+                    "outcome": "No",
+                    "outcomeIndex": 1,
+                    "oppositeOutcome": "Yes",
+                    "oppositeAsset": "1",
+                    "endDate": "2025-12-31",
+                    "negativeRisk": false
+                }
+            ]));
+        });
+
+        let request = PositionsRequest::builder().user(test_user()).build();
+        let response = client.positions(&request).await?;
+
+        let market_id = MarketId::from(&response[0]);
+        assert_eq!(
+            market_id,
+            MarketId::new(
+                test_condition_id(),
+                vec![U256::from(1), U256::from(2)],
+                Some("btc-100k".to_owned())
+            )
+        );
+        assert!(matches!(
+            MarketFilter::from(market_id),
+            MarketFilter::Markets(ids) if ids == [test_condition_id()]
+        ));
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod trades {
+    use futures::TryStreamExt as _;
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{Client, types::Side, types::request::TradesRequest};
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
-    use serde_json::json;
+    use serde_json::{Value, json};
 
     use super::{test_condition_id, test_user};
 
@@ -187,20 +292,79 @@ mod trades {
         assert_eq!(trade.side, Side::Buy);
         assert_eq!(trade.size, dec!(50.0));
         assert_eq!(trade.price, dec!(0.55));
-        assert_eq!(trade.timestamp, 1_703_980_800);
+        assert_eq!(trade.timestamp.timestamp(), 1_703_980_800);
         mock.assert();
 
         Ok(())
     }
+
+    fn trade(timestamp: i64) -> Value {
+        json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "side": "SELL",
+            "asset": "1",
+            "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+            "size": 10,
+            "price": 0.4,
+            "timestamp": timestamp,
+            "title": "Market Title",
+            "slug": "market-slug",
+            "icon": "",
+            "eventSlug": "event-slug",
+            "outcome": "No",
+            "outcomeIndex": 1,
+            "transactionHash": "0x2222222222222222222222222222222222222222222222222222222222222222"
+        })
+    }
+
+    #[tokio::test]
+    async fn trades_all_should_page_until_short_page() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678")
+                .query_param("limit", "2")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK)
+                .json_body(json!([trade(3), trade(2)]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("limit", "2")
+                .query_param("offset", "2");
+            then.status(StatusCode::OK).json_body(json!([trade(1)]));
+        });
+
+        let request = TradesRequest::builder().user(test_user()).limit(2)?.build();
+        let trades: Vec<_> = client.trades_all(&request).try_collect().await?;
+
+        assert_eq!(
+            trades
+                .iter()
+                .map(|t| t.timestamp.timestamp())
+                .collect::<Vec<_>>(),
+            [3, 2, 1]
+        );
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
 }
 
 mod activity {
+    use chrono::{TimeDelta, Utc};
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{
         Client,
         types::request::ActivityRequest,
         types::{ActivityType, Side},
     };
+    use polymarket_client_sdk::error::{Kind, Validation};
     use reqwest::StatusCode;
     use serde_json::json;
 
@@ -258,18 +422,45 @@ mod activity {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn activity_with_end_before_start_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/activity");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let start = Utc::now();
+        let request = ActivityRequest::builder()
+            .user(test_user())
+            .start(start)
+            .end(start - TimeDelta::hours(1))
+            .build();
+
+        let err = client.activity(&request).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        assert!(err.downcast_ref::<Validation>().is_some());
+        mock.assert_calls(0);
+
+        Ok(())
+    }
 }
 
 mod holders {
     use std::str::FromStr as _;
 
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::data::types::response::{HoldersExt as _, MetaHolder};
     use polymarket_client_sdk::data::{Client, types::request::HoldersRequest};
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
     use serde_json::json;
 
-    use super::{U256, address, test_condition_id, test_user};
+    use super::{Address, U256, address, test_condition_id, test_user};
 
     #[tokio::test]
     async fn holders_should_succeed() -> anyhow::Result<()> {
@@ -332,16 +523,63 @@ mod holders {
 
         Ok(())
     }
+
+    #[test]
+    fn holders_ext_should_summarize_holders() -> anyhow::Result<()> {
+        let response: Vec<MetaHolder> = serde_json::from_value(json!([
+            {
+                "token": "1",
+                "holders": [
+                    { "proxyWallet": test_user(), "asset": "1", "amount": 5, "outcomeIndex": 0 },
+                    { "proxyWallet": Address::ZERO, "asset": "1", "amount": 20, "outcomeIndex": 0 },
+                    { "proxyWallet": Address::repeat_byte(1), "asset": "1", "amount": 10, "outcomeIndex": 0 }
+                ]
+            },
+            {
+                "token": "2",
+                "holders": [
+                    { "proxyWallet": test_user(), "asset": "2", "amount": 7, "outcomeIndex": 1 }
+                ]
+            }
+        ]))?;
+
+        let top = response.top_holders(U256::from(1), 2);
+        assert_eq!(
+            top.iter().map(|h| h.proxy_wallet).collect::<Vec<_>>(),
+            [Address::ZERO, Address::repeat_byte(1)]
+        );
+        assert!(response.top_holders(U256::from(3), 2).is_empty());
+
+        let totals = response.total_by_token();
+        assert_eq!(totals[&U256::from(1)], dec!(35));
+        assert_eq!(totals[&U256::from(2)], dec!(7));
+
+        assert_eq!(
+            response
+                .iter_holders()
+                .map(|(token, holder)| (token, holder.amount))
+                .collect::<Vec<_>>(),
+            [
+                (U256::from(1), dec!(5)),
+                (U256::from(1), dec!(20)),
+                (U256::from(1), dec!(10)),
+                (U256::from(2), dec!(7)),
+            ]
+        );
+
+        Ok(())
+    }
 }
 
 mod value {
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{Client, types::request::ValueRequest};
+    use polymarket_client_sdk::types::{B256, Decimal};
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
     use serde_json::json;
 
-    use super::test_user;
+    use super::{test_condition_id, test_user};
 
     #[tokio::test]
     async fn value_should_succeed() -> anyhow::Result<()> {
@@ -371,6 +609,97 @@ mod value {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn portfolio_value_should_sum_and_default_to_zero() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mut mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/value")
+                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678")
+                .query_param_missing("market");
+            then.status(StatusCode::OK).json_body(json!([
+                { "user": "0x1234567890abcdef1234567890abcdef12345678", "value": 100.5 },
+                { "user": "0x1234567890abcdef1234567890abcdef12345678", "value": 20.25 }
+            ]));
+        });
+
+        assert_eq!(client.portfolio_value(test_user()).await?, dec!(120.75));
+        mock.assert();
+        mock.delete();
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/value");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        assert_eq!(client.portfolio_value(test_user()).await?, Decimal::ZERO);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn portfolio_value_by_market_should_group_positions() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let position = |condition_id: B256, current_value: f64| {
+            json!({
+                "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+                "asset": "1",
+                "conditionId": condition_id,
+                "size": 1,
+                "avgPrice": 0.5,
+                "initialValue": 0.5,
+                "currentValue": current_value,
+                "cashPnl": 0,
+                "percentPnl": 0,
+                "totalBought": 1,
+                "realizedPnl": 0,
+                "percentRealizedPnl": 0,
+                "curPrice": 0.5,
+                "redeemable": false,
+                "mergeable": false,
+                "title": "Market",
+                "slug": "market",
+                "icon": "",
+                "eventSlug": "event",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "oppositeOutcome": "No",
+                "oppositeAsset": "2",
+                "endDate": "2025-12-31",
+                "negativeRisk": false
+            })
+        };
+        let other_market = B256::repeat_byte(1);
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/positions")
+                .query_param("user", "0x1234567890abcdef1234567890abcdef12345678")
+                .query_param("sizeThreshold", "0")
+                .query_param("limit", "500")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK).json_body(json!([
+                position(test_condition_id(), 10.5),
+                position(test_condition_id(), 2.5),
+                position(other_market, 4.0)
+            ]));
+        });
+
+        let by_market = client.portfolio_value_by_market(test_user()).await?;
+
+        assert_eq!(by_market.len(), 2);
+        assert_eq!(by_market[&test_condition_id()], dec!(13));
+        assert_eq!(by_market[&other_market], dec!(4));
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod closed_positions {
@@ -435,7 +764,7 @@ mod leaderboard {
     use polymarket_client_sdk::data::{
         Client,
         types::request::TraderLeaderboardRequest,
-        types::{LeaderboardCategory, LeaderboardOrderBy, TimePeriod},
+        types::{LeaderboardCategory, LeaderboardOrderBy, Period},
     };
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
@@ -507,7 +836,7 @@ mod leaderboard {
 
         let request = TraderLeaderboardRequest::builder()
             .category(LeaderboardCategory::Politics)
-            .time_period(TimePeriod::Week)
+            .time_period(Period::Week)
             .order_by(LeaderboardOrderBy::Vol)
             .limit(10)?
             .build();
@@ -558,9 +887,9 @@ mod traded {
 
 mod open_interest {
     use httpmock::{Method::GET, MockServer};
-    use polymarket_client_sdk::data::types::response::Market;
+    use polymarket_client_sdk::data::types::response::{Market, total_open_interest};
     use polymarket_client_sdk::data::{Client, types::request::OpenInterestRequest};
-    use polymarket_client_sdk::types::b256;
+    use polymarket_client_sdk::types::{Decimal, b256};
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
     use serde_json::json;
@@ -649,9 +978,48 @@ mod open_interest {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn open_interest_by_event_should_total_markets() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/oi").query_param("eventId", "123");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "market": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+                    "value": 500_000.5
+                },
+                {
+                    "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                    "value": 250_000
+                },
+                {
+                    "market": "GLOBAL",
+                    "value": 9_999_999
+                }
+            ]));
+        });
+
+        let request = OpenInterestRequest::builder()
+            .event_ids(vec!["123".to_owned()])
+            .build();
+
+        let response = client.open_interest(&request).await?;
+
+        assert_eq!(total_open_interest(&response), dec!(750_000.5));
+        assert_eq!(total_open_interest(&[]), Decimal::ZERO);
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod live_volume {
+    use std::time::Duration;
+
+    use futures::StreamExt as _;
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::types::response::Market;
     use polymarket_client_sdk::data::{Client, types::request::LiveVolumeRequest};
@@ -711,12 +1079,51 @@ mod live_volume {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn live_volume_poll_should_skip_unchanged_snapshots() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mut mock = server.mock(|when, then| {
+            when.method(GET).path("/live-volume");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "total": 100.0, "markets": [] }]));
+        });
+
+        let request = LiveVolumeRequest::builder().id(123).build();
+        let stream = client.live_volume_poll(&request, Duration::from_millis(10));
+        let mut stream = std::pin::pin!(stream);
+
+        let first = stream.next().await.expect("stream never ends")?;
+        assert_eq!(first[0].total, dec!(100.0));
+
+        // Unchanged snapshots are polled but not yielded
+        tokio::time::timeout(Duration::from_millis(100), stream.next())
+            .await
+            .unwrap_err();
+        assert!(mock.calls() >= 2);
+
+        mock.delete();
+        server.mock(|when, then| {
+            when.method(GET).path("/live-volume");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "total": 150.0, "markets": [] }]));
+        });
+
+        let second = stream.next().await.expect("stream never ends")?;
+        assert_eq!(second[0].total, dec!(150.0));
+
+        Ok(())
+    }
 }
 
 mod builder_leaderboard {
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{
-        Client, types::TimePeriod, types::request::BuilderLeaderboardRequest,
+        Client,
+        types::request::BuilderLeaderboardRequest,
+        types::{ApiVersion, Period},
     };
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
@@ -777,7 +1184,7 @@ mod builder_leaderboard {
         });
 
         let request = BuilderLeaderboardRequest::builder()
-            .time_period(TimePeriod::Month)
+            .time_period(Period::Month)
             .limit(5)?
             .build();
 
@@ -788,6 +1195,25 @@ mod builder_leaderboard {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn builder_leaderboard_should_use_configured_api_version() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?.with_api_version(ApiVersion::V2);
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v2/builders/leaderboard");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = BuilderLeaderboardRequest::builder().build();
+        let response = client.builder_leaderboard(&request).await?;
+
+        assert!(response.is_empty());
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod builder_volume {
@@ -796,7 +1222,7 @@ mod builder_volume {
     use chrono::{DateTime, Utc};
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{
-        Client, types::TimePeriod, types::request::BuilderVolumeRequest,
+        Client, types::Period, types::request::BuilderVolumeRequest,
     };
     use reqwest::StatusCode;
     use rust_decimal_macros::dec;
@@ -861,7 +1287,7 @@ mod builder_volume {
         });
 
         let request = BuilderVolumeRequest::builder()
-            .time_period(TimePeriod::Week)
+            .time_period(Period::Week)
             .build();
 
         let response = client.builder_volume(&request).await?;
@@ -876,7 +1302,7 @@ mod builder_volume {
 mod error_handling {
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{Client, types::request::PositionsRequest};
-    use polymarket_client_sdk::error::Kind;
+    use polymarket_client_sdk::error::{Http, Kind, Method, Status};
     use reqwest::StatusCode;
     use serde_json::json;
 
@@ -906,6 +1332,59 @@ mod error_handling {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn too_many_requests_should_carry_rate_limit() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/positions");
+            then.status(StatusCode::TOO_MANY_REQUESTS)
+                .header("X-RateLimit-Limit", "50")
+                .header("X-RateLimit-Remaining", "0")
+                .header("X-RateLimit-Reset", "1700000000")
+                .body("rate limited");
+        });
+
+        let request = PositionsRequest::builder().user(test_user()).build();
+
+        let err = client.positions(&request).await.unwrap_err();
+
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.status_code, StatusCode::TOO_MANY_REQUESTS);
+        let rate_limit = status.rate_limit.unwrap();
+        assert_eq!(rate_limit.limit, Some(50));
+        assert_eq!(rate_limit.remaining, Some(0));
+        assert_eq!(
+            rate_limit.reset_at,
+            chrono::DateTime::from_timestamp(1_700_000_000, 0)
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_rate_limit_headers_should_be_none() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/positions");
+            then.status(StatusCode::TOO_MANY_REQUESTS);
+        });
+
+        let request = PositionsRequest::builder().user(test_user()).build();
+
+        let err = client.positions(&request).await.unwrap_err();
+
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.rate_limit, None);
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn server_error_should_return_error() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -952,10 +1431,60 @@ mod error_handling {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn malformed_body_should_carry_request() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/positions");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "unexpected": true }));
+        });
+
+        let request = PositionsRequest::builder().user(test_user()).build();
+
+        let err = client.positions(&request).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Internal);
+        let http = err.downcast_ref::<Http>().unwrap();
+        assert_eq!(http.method, Method::GET);
+        assert_eq!(
+            http.path,
+            format!("/positions?user={}", test_user().to_string().to_lowercase())
+        );
+        assert!(err.to_string().contains(&format!("GET {} -> ", http.path)));
+        err.downcast_ref::<serde_json::Error>().unwrap();
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unreachable_server_should_carry_request() -> anyhow::Result<()> {
+        let client = Client::new("http://127.0.0.1:1")?;
+
+        let request = PositionsRequest::builder().user(test_user()).build();
+
+        let err = client.positions(&request).await.unwrap_err();
+
+        let http = err.downcast_ref::<Http>().unwrap();
+        assert_eq!(http.method, Method::GET);
+        assert!(http.path.starts_with("/positions?"));
+        assert!(err.downcast_ref::<reqwest::Error>().unwrap().is_connect());
+
+        Ok(())
+    }
 }
 
 mod client {
+    use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::Client;
+    use polymarket_client_sdk::error::Status;
+    use reqwest::StatusCode;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
 
     #[test]
     fn client_default_should_succeed() {
@@ -974,6 +1503,61 @@ mod client {
     fn client_new_with_invalid_url_should_fail() {
         Client::new("not-a-valid-url").unwrap_err();
     }
+
+    #[tokio::test]
+    async fn get_raw_should_deserialize_into_caller_type() -> anyhow::Result<()> {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Unwrapped {
+            count: u32,
+        }
+
+        #[derive(Serialize)]
+        struct Query {
+            user: &'static str,
+        }
+
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/unwrapped")
+                .query_param("user", "0xabc");
+            then.status(StatusCode::OK).json_body(json!({ "count": 3 }));
+        });
+
+        let response: Unwrapped = client
+            .get_raw("/unwrapped", &Query { user: "0xabc" })
+            .await?;
+
+        assert_eq!(response, Unwrapped { count: 3 });
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_raw_should_surface_status_errors() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/unwrapped");
+            then.status(StatusCode::BAD_REQUEST).body("bad request");
+        });
+
+        let err = client
+            .get_raw::<_, serde_json::Value>("unwrapped", &())
+            .await
+            .unwrap_err();
+
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.status_code, StatusCode::BAD_REQUEST);
+        assert_eq!(status.message, "bad request");
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod types {
@@ -985,7 +1569,7 @@ mod types {
         },
         types::{
             ActivityType, BoundedIntError, LeaderboardCategory, LeaderboardOrderBy, MarketFilter,
-            PositionSortBy, Side, SortDirection, TimePeriod, TradeFilter,
+            Period, PositionSortBy, Side, SortDirection, TradeFilter,
         },
     };
     use rust_decimal_macros::dec;
@@ -1142,6 +1726,43 @@ mod types {
         assert!(qs.contains("type=TRADE%2CREDEEM")); // URL-encoded "TRADE,REDEEM"
     }
 
+    #[test]
+    fn activity_type_should_deserialize_known_and_unknown_values() {
+        let types: Vec<ActivityType> = serde_json::from_value(serde_json::json!([
+            "TRADE",
+            "SPLIT",
+            "MERGE",
+            "REDEEM",
+            "REWARD",
+            "CONVERSION",
+            "SOMETHING_NEW"
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            types,
+            [
+                ActivityType::Trade,
+                ActivityType::Split,
+                ActivityType::Merge,
+                ActivityType::Redeem,
+                ActivityType::Reward,
+                ActivityType::Conversion,
+                ActivityType::Unknown("SOMETHING_NEW".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn activity_types_query_string_should_be_omitted_when_empty() {
+        let req = ActivityRequest::builder()
+            .user(address!("56687bf447db6ffa42ffe2204a05edaa20f55839"))
+            .build();
+
+        let qs = req.query_params(None);
+        assert!(!qs.contains("type="));
+    }
+
     #[test]
     fn live_volume_request() {
         let req = LiveVolumeRequest::builder().id(123).build();
@@ -1164,7 +1785,7 @@ mod types {
     fn trader_leaderboard_request() {
         let req = TraderLeaderboardRequest::builder()
             .category(LeaderboardCategory::Politics)
-            .time_period(TimePeriod::Week)
+            .time_period(Period::Week)
             .order_by(LeaderboardOrderBy::Pnl)
             .limit(10)
             .unwrap()
@@ -1184,7 +1805,7 @@ mod types {
         assert_eq!(ActivityType::Trade.to_string(), "TRADE");
         assert_eq!(PositionSortBy::CashPnl.to_string(), "CASHPNL");
         assert_eq!(PositionSortBy::PercentPnl.to_string(), "PERCENTPNL");
-        assert_eq!(TimePeriod::All.to_string(), "ALL");
+        assert_eq!(Period::All.to_string(), "ALL");
         assert_eq!(LeaderboardCategory::Overall.to_string(), "OVERALL");
     }
 
@@ -1211,9 +1832,9 @@ mod types {
 
     #[test]
     fn all_time_periods_display() {
-        assert_eq!(TimePeriod::Day.to_string(), "DAY");
-        assert_eq!(TimePeriod::Week.to_string(), "WEEK");
-        assert_eq!(TimePeriod::Month.to_string(), "MONTH");
+        assert_eq!(Period::Day.to_string(), "DAY");
+        assert_eq!(Period::Week.to_string(), "WEEK");
+        assert_eq!(Period::Month.to_string(), "MONTH");
     }
 
     #[test]
@@ -1266,6 +1887,7 @@ mod error_display {
 }
 
 mod request_query_string_extended {
+    use chrono::DateTime;
     use polymarket_client_sdk::ToQueryParams as _;
     use polymarket_client_sdk::data::types::{
         ActivitySortBy, ClosedPositionSortBy, MarketFilter, PositionSortBy, Side, SortDirection,
@@ -1331,8 +1953,8 @@ mod request_query_string_extended {
             .filter(MarketFilter::event_ids(["1".to_owned()]))
             .limit(50)
             .unwrap()
-            .start(1000)
-            .end(2000)
+            .start(DateTime::from_timestamp(1000, 0).unwrap())
+            .end(DateTime::from_timestamp(2000, 0).unwrap())
             .sort_by(ActivitySortBy::Timestamp)
             .sort_direction(SortDirection::Asc)
             .side(Side::Sell)
@@ -1340,8 +1962,8 @@ mod request_query_string_extended {
 
         let qs = req.query_params(None);
         assert!(qs.contains("eventId="));
-        assert!(qs.contains("start="));
-        assert!(qs.contains("end="));
+        assert!(qs.contains("start=1000"));
+        assert!(qs.contains("end=2000"));
         assert!(qs.contains("sortBy="));
         assert!(qs.contains("sortDirection="));
         assert!(qs.contains("side="));
@@ -27,12 +27,19 @@
 //! - `profiles`: Public profile lookup
 //! - `search`: Search across events, markets, and profiles
 //! - `health`: API health check
+//! - `etag_cache`: Conditional requests with the `cache` feature
 
 pub mod common;
 
 mod sports {
     use httpmock::{Method::GET, MockServer};
-    use polymarket_client_sdk::gamma::{Client, types::request::TeamsRequest};
+    use polymarket_client_sdk::error::Status;
+    use polymarket_client_sdk::gamma::types::response::{Event, Market, SportsGame};
+    use polymarket_client_sdk::gamma::types::{GameStatus, League, SportsMarketType};
+    use polymarket_client_sdk::gamma::{
+        Client,
+        types::request::{TeamByIdRequest, TeamBySlugRequest, TeamsRequest},
+    };
     use reqwest::StatusCode;
     use serde_json::json;
 
@@ -74,7 +81,7 @@ mod sports {
         assert_eq!(response.len(), 2);
         assert_eq!(response[0].id, 1);
         assert_eq!(response[0].name, Some("Lakers".to_owned()));
-        assert_eq!(response[0].league, Some("NBA".to_owned()));
+        assert_eq!(response[0].league, Some(League::Nba));
         assert_eq!(response[1].id, 2);
         assert_eq!(response[1].name, Some("Celtics".to_owned()));
         mock.assert();
@@ -82,6 +89,107 @@ mod sports {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn teams_with_league_should_send_league() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/teams").query_param("league", "NBA");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": 1, "name": "Lakers", "league": "NBA" }]));
+        });
+
+        let request = TeamsRequest::builder().add_league(League::Nba).build();
+        let response = client.teams(&request).await?;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].league, Some(League::Nba));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn team_by_id_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/teams/1");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": 1,
+                "name": "Lakers",
+                "league": "NBA",
+                "abbreviation": "LAL",
+                "logo": "https://example.com/lakers.png"
+            }));
+        });
+
+        let team = client
+            .team_by_id(&TeamByIdRequest::builder().id("1").build())
+            .await?;
+
+        assert_eq!(team.id, 1);
+        assert_eq!(team.league, Some(League::Nba));
+        assert_eq!(team.abbreviation.as_deref(), Some("LAL"));
+        assert_eq!(team.logo.as_deref(), Some("https://example.com/lakers.png"));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn team_by_slug_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/teams/slug/lakers");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "id": 1, "name": "Lakers", "league": "NBA" }));
+        });
+
+        let team = client
+            .team_by_slug(&TeamBySlugRequest::builder().slug("lakers").build())
+            .await?;
+
+        assert_eq!(team.name.as_deref(), Some("Lakers"));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn team_by_id_unknown_should_return_not_found() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let missing = server.mock(|when, then| {
+            when.method(GET).path("/teams/999");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "team not found" }));
+        });
+        let null = server.mock(|when, then| {
+            when.method(GET).path("/teams/998");
+            then.status(StatusCode::OK).body("null");
+        });
+
+        for id in ["999", "998"] {
+            let err = client
+                .team_by_id(&TeamByIdRequest::builder().id(id).build())
+                .await
+                .unwrap_err();
+            let status = err.downcast_ref::<Status>().unwrap();
+            assert_eq!(status.status_code, StatusCode::NOT_FOUND);
+            assert_eq!(status.path, format!("/teams/{id}"));
+        }
+        missing.assert();
+        null.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn sports_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -97,14 +205,24 @@ mod sports {
                     "ordering": "home",
                     "tags": "1,2,3",
                     "series": "39"
+                },
+                {
+                    "sport": "IPL",
+                    "image": "https://example.com/cricket.png",
+                    "resolution": "https://example.com",
+                    "ordering": "home",
+                    "tags": "1",
+                    "series": "40"
                 }
             ]));
         });
 
         let response = client.sports().await?;
 
-        assert_eq!(response.len(), 1);
-        assert_eq!(response[0].sport, "ncaab");
+        assert_eq!(response.len(), 2);
+        assert_eq!(response[0].sport, League::Ncaab);
+        assert_eq!(response[1].sport, League::Unknown("IPL".to_owned()));
+        assert_eq!(response[1].sport.to_string(), "IPL");
         assert_eq!(response[0].image, "https://example.com/basketball.png");
         mock.assert();
 
@@ -119,7 +237,7 @@ mod sports {
         let mock = server.mock(|when, then| {
             when.method(GET).path("/sports/market-types");
             then.status(StatusCode::OK).json_body(json!({
-                "marketTypes": ["moneyline", "spreads", "totals"]
+                "marketTypes": ["moneyline", "spreads", "totals", "first_half_moneyline"]
             }));
         });
 
@@ -127,12 +245,88 @@ mod sports {
 
         assert_eq!(
             response.market_types,
-            vec!["moneyline", "spreads", "totals"]
+            vec![
+                SportsMarketType::Moneyline,
+                SportsMarketType::Spreads,
+                SportsMarketType::Totals,
+                SportsMarketType::Unknown("first_half_moneyline".to_owned()),
+            ]
         );
         mock.assert();
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn sports_live_should_return_live_games() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("tag_slug", "sports")
+                .query_param("active", "true")
+                .query_param("closed", "false")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "1",
+                    "title": "Lakers vs. Celtics",
+                    "gameId": 42,
+                    "homeTeamName": "Lakers",
+                    "awayTeamName": "Celtics",
+                    "score": "88-91",
+                    "period": "Q4",
+                    "elapsed": "03:12",
+                    "live": true,
+                    "ended": false,
+                    "markets": [{ "id": "100", "gameId": "42" }]
+                },
+                { "id": "2", "gameId": 43, "live": false, "ended": false },
+                { "id": "3", "gameId": 44, "live": true, "ended": true },
+                { "id": "4", "title": "Season winner" }
+            ]));
+        });
+
+        let games = client.sports_live().await?;
+
+        assert_eq!(games.len(), 1);
+        let game = &games[0];
+        assert_eq!(game.game_id, 42);
+        assert_eq!(game.event_id, "1");
+        assert_eq!(game.home_team.as_deref(), Some("Lakers"));
+        assert_eq!(game.away_team.as_deref(), Some("Celtics"));
+        assert_eq!(game.score.as_deref(), Some("88-91"));
+        assert_eq!(game.period.as_deref(), Some("Q4"));
+        assert_eq!(game.status, GameStatus::Live);
+        assert_eq!(game.markets.len(), 1);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[test]
+    fn sports_game_should_match_related_markets() -> anyhow::Result<()> {
+        let event: Event = serde_json::from_value(json!({
+            "id": "1",
+            "gameId": 42,
+            "ended": true
+        }))?;
+        let markets: Vec<Market> = serde_json::from_value(json!([
+            { "id": "100", "gameId": "42" },
+            { "id": "101", "gameId": "43" },
+            { "id": "102" }
+        ]))?;
+
+        let game = SportsGame::from_event(&event).expect("event has a game ID");
+        assert_eq!(game.status, GameStatus::Ended);
+
+        let related: Vec<_> = game.related_markets(&markets).map(|m| &m.id).collect();
+        assert_eq!(related, ["100"]);
+
+        Ok(())
+    }
 }
 
 mod tags {
@@ -354,6 +548,67 @@ mod tags {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn related_tags_bfs_should_dedupe_and_record_depth() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        // 1 -> {2, 3}, 2 -> {1, 4}, 3 -> {4}, 4 -> {2}
+        let edges = [
+            ("1", vec!["2", "3"]),
+            ("2", vec!["1", "4"]),
+            ("3", vec!["4"]),
+            ("4", vec!["2"]),
+        ];
+        let mocks: Vec<_> = edges
+            .iter()
+            .map(|(id, related)| {
+                let body: Vec<_> = related.iter().map(|r| json!({ "id": r })).collect();
+                server.mock(|when, then| {
+                    when.method(GET)
+                        .path(format!("/tags/{id}/related-tags/tags"));
+                    then.status(StatusCode::OK).json_body(json!(body));
+                })
+            })
+            .collect();
+
+        let tags = client.related_tags_bfs("1", 5).await?;
+
+        let found: Vec<_> = tags.iter().map(|(t, d)| (t.id.as_str(), *d)).collect();
+        assert_eq!(found, [("2", 1), ("3", 1), ("4", 2)]);
+        for mock in mocks {
+            mock.assert_calls(1);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn related_tags_bfs_should_stop_at_depth() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let root = server.mock(|when, then| {
+            when.method(GET).path("/tags/1/related-tags/tags");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "2" }]));
+        });
+        let child = server.mock(|when, then| {
+            when.method(GET).path("/tags/2/related-tags/tags");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "3" }]));
+        });
+
+        let tags = client.related_tags_bfs("1", 1).await?;
+
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].0.id, "2");
+        root.assert();
+        child.assert_calls(0);
+
+        Ok(())
+    }
 }
 
 mod events {
@@ -362,7 +617,9 @@ mod events {
         Client,
         types::request::{EventByIdRequest, EventBySlugRequest, EventsRequest},
     };
+    use polymarket_client_sdk::types::{Decimal, U256};
     use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
     use serde_json::json;
 
     #[tokio::test]
@@ -384,7 +641,7 @@ mod events {
             ]));
         });
 
-        let request = EventsRequest::builder().active(true).build();
+        let request = EventsRequest::builder().active(true).build()?;
         let response = client.events(&request).await?;
 
         assert_eq!(response.len(), 1);
@@ -395,6 +652,240 @@ mod events {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn events_should_validate_requests_built_without_builder() -> anyhow::Result<()> {
+        use chrono::{TimeZone as _, Utc};
+
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.any_request();
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let mut request = EventsRequest::default();
+        request.end_date_min = Some(Utc.with_ymd_and_hms(2025, 3, 10, 9, 30, 0).unwrap());
+        request.end_date_max = Some(Utc.with_ymd_and_hms(2025, 3, 3, 9, 30, 0).unwrap());
+
+        let err = client.events(&request).await.unwrap_err();
+        assert!(err.to_string().contains("end_date_min"), "{err}");
+        let err = client.events_page(&request).await.unwrap_err();
+        assert!(err.to_string().contains("end_date_min"), "{err}");
+        mock.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_with_tag_slug_should_send_tag_slug() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events")
+                .query_param("tag_slug", "politics");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "123", "slug": "test-event" }]));
+        });
+
+        let request = EventsRequest::builder().tag_slug("politics").build()?;
+        let response = client.events(&request).await?;
+
+        assert_eq!(response.len(), 1);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn event_by_id_should_deserialize_nested_markets() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events/789");
+            then.status(StatusCode::OK).json_body(json!({
+                "id": "789",
+                "title": "Who will win the election?",
+                "markets": [
+                    {
+                        "id": "1001",
+                        "question": "Will Alice win?",
+                        "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+                        "outcomes": "[\"Yes\", \"No\"]",
+                        "outcomePrices": "[\"0.62\", \"0.38\"]",
+                        "clobTokenIds": "[\"111\", \"222\"]",
+                        "active": true
+                    },
+                    {
+                        "id": "1002",
+                        "question": "Will Bob win?",
+                        "outcomePrices": "[\"0.35\", \"0.65\"]",
+                        "clobTokenIds": "[\"333\", \"444\"]"
+                    },
+                    { "id": "1003" },
+                    { "id": "1004", "clobTokenIds": "not a list" }
+                ]
+            }));
+        });
+
+        let request = EventByIdRequest::builder().id("789").build();
+        let event = client.event_by_id(&request).await?;
+
+        let markets = event.markets();
+        let ids: Vec<_> = markets.iter().map(|market| market.id.as_str()).collect();
+        assert_eq!(ids, ["1001", "1002", "1003"]);
+
+        assert_eq!(
+            markets[0].clob_token_ids,
+            Some(vec![U256::from(111), U256::from(222)])
+        );
+        assert_eq!(
+            markets[0].outcome_prices,
+            Some(vec![dec!(0.62), dec!(0.38)])
+        );
+        assert_eq!(
+            markets[0].outcomes,
+            Some(vec!["Yes".to_owned(), "No".to_owned()])
+        );
+        assert_eq!(
+            markets[1].clob_token_ids,
+            Some(vec![U256::from(333), U256::from(444)])
+        );
+        assert_eq!(markets[2].clob_token_ids, None);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn event_by_id_without_markets_should_succeed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let empty = server.mock(|when, then| {
+            when.method(GET).path("/events/1");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "id": "1", "markets": [] }));
+        });
+        let missing = server.mock(|when, then| {
+            when.method(GET).path("/events/2");
+            then.status(StatusCode::OK).json_body(json!({ "id": "2" }));
+        });
+
+        let event = client
+            .event_by_id(&EventByIdRequest::builder().id("1").build())
+            .await?;
+        assert_eq!(event.markets, Some(Vec::new()));
+        assert!(event.markets().is_empty());
+
+        let event = client
+            .event_by_id(&EventByIdRequest::builder().id("2").build())
+            .await?;
+        assert_eq!(event.markets, None);
+        assert!(event.markets().is_empty());
+
+        empty.assert();
+        missing.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_should_parse_volume_and_liquidity() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "1",
+                    "volume": "1250000.5",
+                    "volume24hr": 32000.25,
+                    "liquidity": "18000",
+                    "openInterest": ""
+                },
+                { "id": "2", "volume24hr": "100" }
+            ]));
+        });
+
+        let mut events = client.events(&EventsRequest::builder().build()?).await?;
+
+        assert_eq!(events[0].volume, dec!(1250000.5));
+        assert_eq!(events[0].volume_24hr, dec!(32000.25));
+        assert_eq!(events[0].liquidity, dec!(18000));
+        assert_eq!(events[0].open_interest, Decimal::ZERO);
+        assert_eq!(events[0].score(), dec!(50000.25));
+
+        assert_eq!(events[1].volume, Decimal::ZERO);
+        assert_eq!(events[1].liquidity, Decimal::ZERO);
+        assert_eq!(events[1].score(), dec!(100));
+
+        events.sort_by_key(|event| std::cmp::Reverse(event.score()));
+        assert_eq!(events[0].id, "1");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_page_should_return_total() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/events/pagination")
+                .query_param("active", "true")
+                .query_param("limit", "10")
+                .query_param("offset", "10");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [{ "id": "123", "slug": "test-event" }],
+                "pagination": { "hasMore": true, "totalResults": 165 }
+            }));
+        });
+
+        let request = EventsRequest::builder()
+            .active(true)
+            .limit(10)
+            .offset(10)
+            .build()?;
+        let page = client.events_page(&request).await?;
+
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.data[0].id, "123");
+        assert_eq!(page.total, Some(165));
+        assert_eq!(page.page_index(), Some(1));
+        assert_eq!(page.page_count(), Some(17));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn events_page_without_pagination_should_have_no_total() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events/pagination");
+            then.status(StatusCode::OK).json_body(json!({ "data": [] }));
+        });
+
+        let request = EventsRequest::builder().build()?;
+        let page = client.events_page(&request).await?;
+
+        assert!(page.data.is_empty());
+        assert_eq!(page.total, None);
+        assert_eq!(page.page_count(), None);
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn event_by_id_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -419,6 +910,45 @@ mod events {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn events_by_ids_should_preserve_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?.with_concurrency(2);
+
+        let mocks: Vec<_> = ["3", "1", "2"]
+            .into_iter()
+            .map(|id| {
+                server.mock(|when, then| {
+                    when.method(GET).path(format!("/events/{id}"));
+                    then.status(StatusCode::OK)
+                        .delay(std::time::Duration::from_millis(
+                            id.parse::<u64>().unwrap() * 20,
+                        ))
+                        .json_body(json!({ "id": id }));
+                })
+            })
+            .collect();
+        let missing = server.mock(|when, then| {
+            when.method(GET).path("/events/404");
+            then.status(StatusCode::NOT_FOUND)
+                .json_body(json!({ "error": "not found" }));
+        });
+
+        let results = client.events_by_ids(&["3", "404", "1", "2"]).await;
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap().id, "3");
+        results[1].as_ref().unwrap_err();
+        assert_eq!(results[2].as_ref().unwrap().id, "1");
+        assert_eq!(results[3].as_ref().unwrap().id, "2");
+        for mock in mocks {
+            mock.assert();
+        }
+        missing.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn event_by_slug_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -442,14 +972,41 @@ mod events {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn event_should_dispatch_on_id_or_slug() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let by_id = server.mock(|when, then| {
+            when.method(GET).path("/events/789");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "id": "789", "slug": "my-event" }));
+        });
+        let by_slug = server.mock(|when, then| {
+            when.method(GET).path("/events/slug/my-event-2024");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "id": "790", "slug": "my-event-2024" }));
+        });
+
+        assert_eq!(client.event("789").await?.id, "789");
+        assert_eq!(client.event("my-event-2024").await?.id, "790");
+        by_id.assert();
+        by_slug.assert();
+
+        Ok(())
+    }
 }
 
 mod markets {
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::error::Validation;
     use polymarket_client_sdk::gamma::{
         Client,
         types::request::{MarketByIdRequest, MarketBySlugRequest, MarketsRequest},
+        types::{SortField, StatusFilter},
     };
+    use polymarket_client_sdk::types::{B256, MarketId, b256};
     use reqwest::StatusCode;
     use serde_json::json;
 
@@ -482,6 +1039,113 @@ mod markets {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn markets_with_tag_slug_should_resolve_tag_id() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let tag_mock = server.mock(|when, then| {
+            when.method(GET).path("/tags/slug/crypto");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "id": "21", "slug": "crypto" }));
+        });
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("tag_id", "21")
+                .query_param_missing("tag_slug");
+            then.status(StatusCode::OK).json_body(json!([
+                { "id": "1", "question": "Crypto Market?", "slug": "crypto-market" }
+            ]));
+        });
+
+        let request = MarketsRequest::builder().tag_slug("crypto").build();
+        let response = client.markets(&request).await?;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(response[0].id, "1");
+        tag_mock.assert();
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_with_sort_by_should_send_order() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("order", "volume24hr")
+                .query_param("ascending", "false");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = MarketsRequest::builder()
+            .sort_by(SortField::Volume24hr)
+            .ascending(false)
+            .build();
+        client.markets(&request).await?;
+
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_with_sort_by_and_order_should_fail() -> anyhow::Result<()> {
+        let client = Client::new("http://localhost")?;
+
+        let request = MarketsRequest::builder()
+            .sort_by(SortField::Liquidity)
+            .order("volumeNum".to_owned())
+            .build();
+        let err = client.markets(&request).await.unwrap_err();
+
+        assert!(err.to_string().contains("sort_by"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_with_status_and_closed_should_fail() -> anyhow::Result<()> {
+        let client = Client::new("http://localhost")?;
+
+        let request = MarketsRequest::builder()
+            .status(StatusFilter::Resolved)
+            .closed(false)
+            .build();
+        let err = client.markets(&request).await.unwrap_err();
+
+        assert!(err.to_string().contains("status"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn markets_with_tag_id_and_tag_slug_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.any_request();
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = MarketsRequest::builder()
+            .tag_id("21")
+            .tag_slug("crypto")
+            .build();
+        let err = client.markets(&request).await.unwrap_err();
+
+        assert!(err.to_string().contains("tag_slug"));
+        mock.assert_calls(0);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn market_by_id_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -530,6 +1194,50 @@ mod markets {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn market_by_slug_should_encode_slug_as_one_segment() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets/slug/a%2Fb%3Fc%23d%20e");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "id": "99", "slug": "a/b?c#d e" }));
+        });
+
+        let request = MarketBySlugRequest::builder().slug("a/b?c#d e").build();
+        let response = client.market_by_slug(&request).await?;
+
+        assert_eq!(response.id, "99");
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_should_dispatch_on_id_or_slug() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let by_id = server.mock(|when, then| {
+            when.method(GET).path("/markets/99");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "id": "99", "slug": "my-market" }));
+        });
+        let by_slug = server.mock(|when, then| {
+            when.method(GET).path("/markets/slug/my-market");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "id": "99", "slug": "my-market" }));
+        });
+
+        assert_eq!(client.market("99").await?.id, "99");
+        assert_eq!(client.market("my-market").await?.id, "99");
+        by_id.assert();
+        by_slug.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn markets_empty_request() -> anyhow::Result<()> {
         // Tests (true, true): no base params, no clob_token_ids
@@ -577,6 +1285,31 @@ mod markets {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn markets_by_condition_ids_should_repeat_param() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+        let condition_1 = B256::with_last_byte(1);
+        let condition_2 = B256::with_last_byte(2);
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/markets")
+                .query_param("condition_ids", condition_1.to_string())
+                .query_param("condition_ids", condition_2.to_string());
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = MarketsRequest::builder()
+            .condition_ids([condition_1, condition_2])
+            .build();
+        client.markets(&request).await?;
+
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn markets_with_base_and_clob_params() -> anyhow::Result<()> {
         // Tests (false, false): both base params and clob_token_ids
@@ -590,18 +1323,57 @@ mod markets {
                 .query_param("clob_token_ids", token_1().to_string())
                 .query_param("clob_token_ids", token_2().to_string());
             then.status(StatusCode::OK).json_body(json!([
-                {"id": "1", "question": "Market 1?", "slug": "market-1"},
-                {"id": "2", "question": "Market 2?", "slug": "market-2"}
+                {"id": "1", "question": "Market 1?", "slug": "market-1"},
+                {"id": "2", "question": "Market 2?", "slug": "market-2"}
+            ]));
+        });
+
+        let request = MarketsRequest::builder()
+            .limit(50)
+            .clob_token_ids(vec![token_1(), token_2()])
+            .build();
+        let response = client.markets(&request).await?;
+
+        assert_eq!(response.len(), 2);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_should_convert_to_market_id() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let condition_id =
+            b256!("dd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917");
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/markets");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "1",
+                    "slug": "market-1",
+                    "conditionId": condition_id,
+                    "clobTokenIds": json!([token_1().to_string(), token_2().to_string()]).to_string()
+                },
+                {"id": "2", "slug": "market-2", "conditionId": condition_id}
             ]));
         });
 
-        let request = MarketsRequest::builder()
-            .limit(50)
-            .clob_token_ids(vec![token_1(), token_2()])
-            .build();
-        let response = client.markets(&request).await?;
+        let response = client.markets(&MarketsRequest::default()).await?;
 
-        assert_eq!(response.len(), 2);
+        let market_id = MarketId::try_from(&response[0])?;
+        assert_eq!(
+            market_id,
+            MarketId::new(
+                condition_id,
+                vec![token_1(), token_2()],
+                Some("market-1".to_owned())
+            )
+        );
+
+        let err = MarketId::try_from(&response[1]).unwrap_err();
+        assert!(err.downcast_ref::<Validation>().is_some());
         mock.assert();
 
         Ok(())
@@ -644,12 +1416,84 @@ mod search {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn search_should_encode_reserved_characters() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/public-search")
+                .query_param("q", "trump & biden")
+                .query_param("limit_per_type", "5");
+            then.status(StatusCode::OK).json_body(json!({}));
+        });
+
+        let request = SearchRequest::builder()
+            .q("trump & biden")
+            .limit_per_type(5)
+            .build();
+        client.search(&request).await?;
+
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn search_accessors_should_split_categories() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/public-search")
+                .query_param("q", "bitcoin")
+                .query_param("limit_per_type", "5")
+                .query_param("search_profiles", "true");
+            then.status(StatusCode::OK).json_body(json!({
+                "events": [
+                    {
+                        "id": "1",
+                        "markets": [
+                            { "id": "10", "question": "BTC above 100k?" },
+                            { "id": "11", "question": "BTC above 200k?" }
+                        ]
+                    },
+                    { "id": "2" }
+                ],
+                "profiles": [{ "id": "7", "name": "bitcoiner" }]
+            }));
+        });
+
+        let request = SearchRequest::builder()
+            .q("bitcoin")
+            .limit_per_type(5)
+            .search_profiles(true)
+            .build();
+        let response = client.search(&request).await?;
+
+        assert_eq!(response.events().len(), 2);
+        let markets: Vec<_> = response.markets().map(|m| m.id.as_str()).collect();
+        assert_eq!(markets, ["10", "11"]);
+        assert!(response.tags().is_empty());
+        assert_eq!(response.profiles().len(), 1);
+        assert_eq!(response.profiles()[0].name.as_deref(), Some("bitcoiner"));
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod health {
     use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::error::{Kind, ServiceUnavailable};
     use polymarket_client_sdk::gamma::Client;
+    use polymarket_client_sdk::gamma::types::request::TeamsRequest;
+    use polymarket_client_sdk::gamma::types::response::Team;
     use reqwest::StatusCode;
+    use serde_json::json;
 
     #[tokio::test]
     async fn status_should_succeed() -> anyhow::Result<()> {
@@ -668,6 +1512,69 @@ mod health {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn status_with_non_ok_body_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/status");
+            then.status(StatusCode::OK)
+                .body("Down for scheduled maintenance");
+        });
+
+        let err = client.status().await.unwrap_err();
+        assert_eq!(err.kind(), Kind::ServiceUnavailable);
+        let unavailable = err.downcast_ref::<ServiceUnavailable>().unwrap();
+        assert_eq!(unavailable.message, "Down for scheduled maintenance");
+
+        let response = client.with_allow_non_ok_health(true).status().await?;
+        assert_eq!(response, "Down for scheduled maintenance");
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn with_user_agent_should_override_default() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?.with_user_agent("my-bot/1.0")?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/status")
+                .header("user-agent", "my-bot/1.0");
+            then.status(StatusCode::OK).body("OK");
+        });
+
+        client.status().await?;
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn get_with_meta_should_return_status_without_rate_limit() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/teams").query_param("limit", "1");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let response = client
+            .get_with_meta::<_, Vec<Team>>("teams", &TeamsRequest::builder().limit(1).build())
+            .await?;
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert!(response.data.is_empty());
+        assert_eq!(response.rate_limit, None);
+        mock.assert();
+
+        Ok(())
+    }
 }
 
 mod series {
@@ -738,11 +1645,13 @@ mod series {
 }
 
 mod comments {
+    use futures::TryStreamExt as _;
     use httpmock::{Method::GET, MockServer};
-    use polymarket_client_sdk::gamma::types::ParentEntityType;
+    use polymarket_client_sdk::gamma::types::{CommentSort, ParentEntityType};
     use polymarket_client_sdk::gamma::{
         Client,
         types::request::{CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest},
+        types::response::top_level_only,
     };
     use polymarket_client_sdk::types::address;
     use reqwest::StatusCode;
@@ -781,6 +1690,102 @@ mod comments {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn comments_should_default_counts_and_filter_replies() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/comments");
+            then.status(StatusCode::OK).json_body(json!([
+                {
+                    "id": "1",
+                    "reactions": [{ "id": "9", "reactionType": "HEART" }],
+                    "reactionCount": 1,
+                    "replyCount": 2
+                },
+                { "id": "2", "parentCommentID": "1", "reactions": null, "reactionCount": null },
+                { "id": "3", "parentCommentID": "1" }
+            ]));
+        });
+
+        let request = CommentsRequest::builder()
+            .parent_entity_type(ParentEntityType::Event)
+            .parent_entity_id("123")
+            .build();
+        let response = client.comments(&request).await?;
+
+        assert_eq!(response[0].reactions.len(), 1);
+        assert_eq!(response[0].reaction_count, 1);
+        assert_eq!(response[0].reply_count, 2);
+        assert!(response[1].reactions.is_empty());
+        assert_eq!(response[1].reaction_count, 0);
+        assert_eq!(response[2].reply_count, 0);
+
+        let top_level: Vec<_> = top_level_only(&response).map(|c| c.id.as_str()).collect();
+        assert_eq!(top_level, ["1"]);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn comments_all_should_page_until_short_page() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/comments")
+                .query_param("limit", "2")
+                .query_param("offset", "0")
+                .query_param("order", "createdAt")
+                .query_param("ascending", "false");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "1" }, { "id": "2" }]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/comments")
+                .query_param("limit", "2")
+                .query_param("offset", "2");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "3" }]));
+        });
+
+        let request = CommentsRequest::builder()
+            .parent_entity_type(ParentEntityType::Market)
+            .parent_entity_id("123")
+            .sort_by(CommentSort::Newest)
+            .limit(2)
+            .build();
+        let comments: Vec<_> = client.comments_all(&request).try_collect().await?;
+
+        let ids: Vec<_> = comments.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3"]);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn comments_with_sort_by_and_order_should_fail() -> anyhow::Result<()> {
+        let client = Client::new("http://localhost")?;
+
+        let request = CommentsRequest::builder()
+            .parent_entity_type(ParentEntityType::Event)
+            .parent_entity_id("123")
+            .sort_by(CommentSort::Oldest)
+            .order("reactionCount".to_owned())
+            .build();
+        let err = client.comments(&request).await.unwrap_err();
+
+        assert!(err.to_string().contains("sort_by"));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn comments_with_filters_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -993,6 +1998,126 @@ mod market_tags {
 // Unit Tests for QueryParams and Common Types
 // =============================================================================
 
+mod error_handling {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::error::{Kind, Status};
+    use polymarket_client_sdk::gamma::{Client, types::request::EventByIdRequest};
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn bad_request_json_body_should_return_status_error() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/events/1");
+            then.status(StatusCode::BAD_REQUEST)
+                .json_body(json!({ "id": "1", "error": "invalid id" }));
+        });
+
+        let request = EventByIdRequest::builder().id("1").build();
+        let err = client.event_by_id(&request).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Status);
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.status_code, StatusCode::BAD_REQUEST);
+        assert_eq!(status.method, Method::GET);
+        assert_eq!(status.path, "/events/1");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&status.message)?,
+            json!({ "id": "1", "error": "invalid id" })
+        );
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn status_bad_request_should_match_generic_error() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/status");
+            then.status(StatusCode::BAD_REQUEST)
+                .json_body(json!({ "error": "bad request" }));
+        });
+
+        let err = client.status().await.unwrap_err();
+
+        let status = err.downcast_ref::<Status>().unwrap();
+        assert_eq!(status.status_code, StatusCode::BAD_REQUEST);
+        assert_eq!(status.method, Method::GET);
+        assert_eq!(status.path, "/status");
+        mock.assert();
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "cache")]
+mod etag_cache {
+    use httpmock::{Method::GET, MockServer};
+    use polymarket_client_sdk::gamma::{Client, types::request::TagByIdRequest};
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn not_modified_should_return_cached_body() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?.with_etag_cache(16);
+
+        let fresh = server.mock(|when, then| {
+            when.method(GET)
+                .path("/tags/1")
+                .header_missing("if-none-match");
+            then.status(StatusCode::OK)
+                .header("etag", "\"v1\"")
+                .json_body(json!({ "id": "1", "label": "Politics", "slug": "politics" }));
+        });
+        let revalidated = server.mock(|when, then| {
+            when.method(GET)
+                .path("/tags/1")
+                .header("if-none-match", "\"v1\"");
+            then.status(StatusCode::NOT_MODIFIED);
+        });
+
+        let request = TagByIdRequest::builder().id("1").build();
+        let first = client.tag_by_id(&request).await?;
+        let second = client.tag_by_id(&request).await?;
+
+        assert_eq!(first.label.as_deref(), Some("Politics"));
+        assert_eq!(second.label.as_deref(), Some("Politics"));
+        fresh.assert_calls(1);
+        revalidated.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn responses_without_etag_should_not_be_cached() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?.with_etag_cache(16);
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/tags/1")
+                .header_missing("if-none-match");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "id": "1", "label": "Politics", "slug": "politics" }));
+        });
+
+        let request = TagByIdRequest::builder().id("1").build();
+        client.tag_by_id(&request).await?;
+        client.tag_by_id(&request).await?;
+
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+}
+
 mod query_string {
     use chrono::{TimeZone as _, Utc};
     use polymarket_client_sdk::ToQueryParams as _;
@@ -1003,8 +2128,10 @@ mod query_string {
         RelatedTagsByIdRequest, RelatedTagsBySlugRequest, SearchRequest, SeriesByIdRequest,
         SeriesListRequest, TagByIdRequest, TagBySlugRequest, TagsRequest, TeamsRequest,
     };
-    use polymarket_client_sdk::gamma::types::{ParentEntityType, RelatedTagsStatus};
-    use polymarket_client_sdk::types::{address, b256};
+    use polymarket_client_sdk::gamma::types::{
+        CommentSort, ParentEntityType, RelatedTagsStatus, SortField, StatusFilter,
+    };
+    use polymarket_client_sdk::types::{U256, address, b256};
     use rust_decimal_macros::dec;
 
     use crate::common::{token_1, token_2};
@@ -1159,7 +2286,8 @@ mod query_string {
             .start_date_max(end_date)
             .end_date_min(start_date)
             .end_date_max(end_date)
-            .build();
+            .build()
+            .unwrap();
 
         let qs = request.query_params(None);
         assert!(qs.contains("limit=50"));
@@ -1195,13 +2323,143 @@ mod query_string {
         assert!(qs.contains("end_date_max="));
     }
 
+    #[test]
+    fn events_request_date_range_encoding() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 3, 9, 30, 0).unwrap();
+        let week = Utc.with_ymd_and_hms(2025, 3, 10, 9, 30, 0).unwrap();
+
+        let request = EventsRequest::builder()
+            .end_date_min(now)
+            .end_date_max(week)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.query_params(None),
+            "?end_date_min=2025-03-03T09%3A30%3A00Z&end_date_max=2025-03-10T09%3A30%3A00Z"
+        );
+
+        let request = EventsRequest::builder()
+            .start_date_min(now)
+            .start_date_max(now)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.query_params(None),
+            "?start_date_min=2025-03-03T09%3A30%3A00Z&start_date_max=2025-03-03T09%3A30%3A00Z"
+        );
+    }
+
+    #[test]
+    fn events_request_inverted_date_range_should_fail() {
+        let now = Utc.with_ymd_and_hms(2025, 3, 3, 9, 30, 0).unwrap();
+        let week = Utc.with_ymd_and_hms(2025, 3, 10, 9, 30, 0).unwrap();
+
+        let err = EventsRequest::builder()
+            .start_date_min(week)
+            .start_date_max(now)
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("start_date_min"));
+
+        let err = EventsRequest::builder()
+            .end_date_min(week)
+            .end_date_max(now)
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("end_date_min"));
+
+        // A single bound is not validated against anything
+        EventsRequest::builder().end_date_min(week).build().unwrap();
+    }
+
+    #[test]
+    fn events_request_sort_by() {
+        let cases = [
+            (SortField::Volume, "order=volume"),
+            (SortField::Volume24hr, "order=volume24hr"),
+            (SortField::Volume1wk, "order=volume1wk"),
+            (SortField::Volume1mo, "order=volume1mo"),
+            (SortField::Volume1yr, "order=volume1yr"),
+            (SortField::Liquidity, "order=liquidity"),
+            (SortField::Competitive, "order=competitive"),
+            (SortField::StartDate, "order=startDate"),
+            (SortField::EndDate, "order=endDate"),
+            (SortField::CreatedAt, "order=createdAt"),
+        ];
+
+        for (sort, expected) in cases {
+            let request = EventsRequest::builder()
+                .sort_by(sort)
+                .ascending(false)
+                .build()
+                .unwrap();
+
+            assert_eq!(
+                request.query_params(None),
+                format!("?{expected}&ascending=false"),
+                "{sort:?}"
+            );
+        }
+
+        EventsRequest::builder()
+            .sort_by(SortField::Volume)
+            .order(vec!["liquidity".to_owned()])
+            .build()
+            .unwrap_err();
+    }
+
+    #[test]
+    fn events_request_status() {
+        let cases = [
+            (
+                StatusFilter::Active,
+                "?active=true&closed=false&archived=false",
+            ),
+            (StatusFilter::Closed, "?closed=true&archived=false"),
+            (StatusFilter::Archived, "?archived=true"),
+            (
+                StatusFilter::Resolved,
+                "?closed=true&archived=false&uma_resolution_status=resolved",
+            ),
+        ];
+
+        for (status, expected) in cases {
+            let request = EventsRequest::builder().status(status).build().unwrap();
+
+            assert_eq!(request.query_params(None), expected, "{status:?}");
+        }
+
+        let request = MarketsRequest::builder()
+            .status(StatusFilter::Active)
+            .limit(5)
+            .build();
+        assert_eq!(
+            request.query_params(None),
+            "?limit=5&active=true&closed=false&archived=false"
+        );
+
+        EventsRequest::builder()
+            .status(StatusFilter::Active)
+            .closed(true)
+            .build()
+            .unwrap_err();
+        EventsRequest::builder()
+            .status(StatusFilter::Closed)
+            .archived(false)
+            .build()
+            .unwrap_err();
+    }
+
     #[test]
     fn events_request_empty_arrays_not_included() {
         let request = EventsRequest::builder()
             .id(vec![])
             .exclude_tag_id(vec![])
             .slug(vec![])
-            .build();
+            .build()
+            .unwrap();
 
         let qs = request.query_params(None);
         assert!(!qs.contains("id="));
@@ -1326,6 +2584,19 @@ mod query_string {
         assert!(qs.contains("closed=false"));
     }
 
+    #[test]
+    fn markets_request_token_ids_exact() {
+        let request = MarketsRequest::builder()
+            .clob_token_ids([U256::from(1), U256::from(23)])
+            .limit(5)
+            .build();
+
+        assert_eq!(
+            request.query_params(None),
+            "?limit=5&clob_token_ids=1&clob_token_ids=23"
+        );
+    }
+
     #[test]
     fn markets_request_empty_arrays_not_included() {
         let request = MarketsRequest::builder()
@@ -1459,6 +2730,29 @@ mod query_string {
         assert!(qs.contains("holders_only=true"));
     }
 
+    #[test]
+    fn comments_request_sort_by() {
+        let cases = [
+            (CommentSort::Newest, "order=createdAt&ascending=false"),
+            (CommentSort::Oldest, "order=createdAt&ascending=true"),
+            (
+                CommentSort::MostReactions,
+                "order=reactionCount&ascending=false",
+            ),
+        ];
+
+        for (sort, expected) in cases {
+            let request = CommentsRequest::builder()
+                .parent_entity_type(ParentEntityType::Event)
+                .parent_entity_id("123")
+                .sort_by(sort)
+                .build();
+
+            let qs = request.query_params(None);
+            assert!(qs.contains(expected), "{sort:?}: {qs}");
+        }
+    }
+
     #[test]
     fn comments_request_series_entity_type() {
         let request = CommentsRequest::builder()
@@ -0,0 +1,112 @@
+#![cfg(all(feature = "clob", feature = "data", feature = "gamma"))]
+
+use std::time::Duration;
+
+use httpmock::{Method::GET, MockServer};
+use polymarket_client_sdk::error::{Kind, Timeout};
+use polymarket_client_sdk::health::{check_all, check_all_with_timeout};
+use polymarket_client_sdk::{clob, data, gamma};
+use reqwest::StatusCode;
+use serde_json::json;
+
+struct Servers {
+    gamma: MockServer,
+    data: MockServer,
+    clob: MockServer,
+}
+
+impl Servers {
+    fn start() -> Self {
+        let servers = Self {
+            gamma: MockServer::start(),
+            data: MockServer::start(),
+            clob: MockServer::start(),
+        };
+
+        servers.data.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "data": "OK" }));
+        });
+        servers.clob.mock(|when, then| {
+            when.method(GET).path("/");
+            then.status(StatusCode::OK).body("\"OK\"");
+        });
+
+        servers
+    }
+
+    fn clients(&self) -> anyhow::Result<(gamma::Client, data::Client, clob::Client)> {
+        Ok((
+            gamma::Client::new(&self.gamma.base_url())?,
+            data::Client::new(&self.data.base_url())?,
+            clob::Client::new(&self.clob.base_url(), clob::Config::default())?,
+        ))
+    }
+}
+
+#[tokio::test]
+async fn check_all_should_report_every_service() -> anyhow::Result<()> {
+    let servers = Servers::start();
+    servers.gamma.mock(|when, then| {
+        when.method(GET).path("/status");
+        then.status(StatusCode::OK).body("OK");
+    });
+    let (gamma, data, clob) = servers.clients()?;
+
+    let report = check_all(&gamma, &data, &clob).await;
+
+    assert!(report.is_healthy());
+    assert_eq!(report.gamma.status?, "OK");
+    assert_eq!(report.data.status?, "OK");
+    assert_eq!(report.clob.status?, "OK");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn check_all_should_report_degraded_service() -> anyhow::Result<()> {
+    let servers = Servers::start();
+    servers.gamma.mock(|when, then| {
+        when.method(GET).path("/status");
+        then.status(StatusCode::OK).body("Down for maintenance");
+    });
+    let (gamma, data, clob) = servers.clients()?;
+
+    let report = check_all(&gamma, &data, &clob).await;
+
+    assert!(!report.is_healthy());
+    assert!(!report.gamma.is_healthy());
+    assert_eq!(
+        report.gamma.status.unwrap_err().kind(),
+        Kind::ServiceUnavailable
+    );
+    assert!(report.data.is_healthy());
+    assert!(report.clob.is_healthy());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn check_all_with_timeout_should_time_out_slow_service() -> anyhow::Result<()> {
+    let servers = Servers::start();
+    servers.gamma.mock(|when, then| {
+        when.method(GET).path("/status");
+        then.status(StatusCode::OK)
+            .body("OK")
+            .delay(Duration::from_secs(2));
+    });
+    let (gamma, data, clob) = servers.clients()?;
+
+    let timeout = Duration::from_millis(100);
+    let report = check_all_with_timeout(&gamma, &data, &clob, timeout).await;
+
+    let err = report.gamma.status.unwrap_err();
+    assert_eq!(err.kind(), Kind::Timeout);
+    assert_eq!(err.downcast_ref::<Timeout>().unwrap().duration, timeout);
+    assert!(report.gamma.latency < Duration::from_secs(2));
+    assert!(report.data.is_healthy());
+    assert!(report.clob.is_healthy());
+
+    Ok(())
+}
@@ -595,6 +595,57 @@ mod limit {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn should_fail_below_minimum_order_size() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements(&server, token_1(), TickSize::Tenth);
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", token_1().to_string());
+            then.status(StatusCode::OK).json_body(serde_json::json!({
+                "market": "0x00000000000000000000000000000000000000000000000000000000aabbcc00",
+                "asset_id": token_1(),
+                "tick_size": "0.1",
+                "min_order_size": "5",
+                "neg_risk": false,
+                "timestamp": "123456789"
+            }));
+        });
+
+        let constraints = client.order_constraints(token_1()).await?;
+        assert_eq!(constraints.min_order_size, dec!(5));
+
+        let err = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(dec!(4.99))
+            .side(Side::Buy)
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Unable to build Order: Size 4.99 is below the minimum order size 5"
+        );
+
+        client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(dec!(5))
+            .side(Side::Buy)
+            .build()
+            .await?;
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn should_fail_on_post_only_for_non_gtc_gtd() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -3153,26 +3204,372 @@ mod market {
     }
 
     #[tokio::test]
-    async fn market_sell_with_usdc_should_fail() -> anyhow::Result<()> {
+    async fn market_sell_with_usdc_should_convert_to_shares() -> anyhow::Result<()> {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        ensure_requirements_for_market_price(&server, token_1(), &[], &[]);
+        ensure_requirements_for_market_price(
+            &server,
+            token_1(),
+            &[
+                OrderSummary::builder()
+                    .price(dec!(0.3))
+                    .size(Decimal::ONE_HUNDRED)
+                    .build(),
+                OrderSummary::builder()
+                    .price(dec!(0.4))
+                    .size(Decimal::ONE_HUNDRED)
+                    .build(),
+            ],
+            &[],
+        );
 
-        let err = client
+        // The best bid alone covers $30: sell 30 / 0.4 = 75 shares
+        let signable_order = client
             .market_order()
             .token_id(token_1())
-            .amount(Amount::usdc(Decimal::ONE_HUNDRED)?)
+            .amount(Amount::usdc(dec!(30))?)
             .side(Side::Sell)
             .build()
-            .await
-            .unwrap_err();
-        let msg = &err
-            .downcast_ref::<polymarket_client_sdk::error::Validation>()
-            .unwrap()
-            .reason;
+            .await?;
+
+        assert_eq!(signable_order.order.makerAmount, U256::from(75_000_000));
+        assert_eq!(signable_order.order.takerAmount, U256::from(30_000_000));
+
+        // $50 takes the 100 shares at 0.4 for $40, then 10 / 0.3 = 33.333... shares at 0.3, rounded
+        // down to 133.33 shares in total. The cutoff 0.3 is the lowest price accepted.
+        let signable_order = client
+            .market_order()
+            .token_id(token_1())
+            .amount(Amount::usdc(dec!(50))?)
+            .side(Side::Sell)
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.order.makerAmount, U256::from(133_330_000));
+        assert_eq!(signable_order.order.takerAmount, U256::from(39_999_000));
+
+        Ok(())
+    }
+
+    mod slippage {
+        use polymarket_client_sdk::error::{InsufficientLiquidity, Kind};
+
+        use super::*;
+
+        fn asks() -> [OrderSummary; 2] {
+            [
+                OrderSummary::builder()
+                    .price(dec!(0.5))
+                    .size(dec!(100))
+                    .build(),
+                OrderSummary::builder()
+                    .price(dec!(0.4))
+                    .size(dec!(300))
+                    .build(),
+            ]
+        }
+
+        fn bids() -> [OrderSummary; 2] {
+            [
+                OrderSummary::builder()
+                    .price(dec!(0.3))
+                    .size(dec!(100))
+                    .build(),
+                OrderSummary::builder()
+                    .price(dec!(0.5))
+                    .size(dec!(100))
+                    .build(),
+            ]
+        }
+
+        #[tokio::test]
+        async fn buy_with_worst_price_should_price_at_bound() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements_for_market_price(&server, token_1(), &[], &asks());
+
+            let signable_order = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::shares(dec!(250))?)
+                .side(Side::Buy)
+                .worst_price(dec!(0.5))
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.order.makerAmount, U256::from(125_000_000)); // 250 * 0.5
+            assert_eq!(signable_order.order.takerAmount, U256::from(250_000_000));
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn buy_beyond_worst_price_should_report_fillable_amount() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements_for_market_price(&server, token_1(), &[], &asks());
+
+            let err = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::shares(dec!(350))?)
+                .side(Side::Buy)
+                .worst_price(dec!(0.45))
+                .build()
+                .await
+                .unwrap_err();
+
+            assert_eq!(err.kind(), Kind::InsufficientLiquidity);
+            let liquidity = err.downcast_ref::<InsufficientLiquidity>().unwrap();
+            assert_eq!(liquidity.worst_price, dec!(0.45));
+            assert_eq!(liquidity.requested, dec!(350));
+            assert_eq!(liquidity.fillable, dec!(300));
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn buy_with_max_slippage_should_price_from_best_ask() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements_for_market_price(&server, token_1(), &[], &asks());
+
+            // best ask 0.4 * 1.25 = 0.5, which covers both levels
+            let signable_order = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::usdc(dec!(170))?)
+                .side(Side::Buy)
+                .max_slippage(dec!(0.25))
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.order.makerAmount, U256::from(170_000_000));
+            assert_eq!(signable_order.order.takerAmount, U256::from(340_000_000)); // 170 / 0.5
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn sell_with_max_slippage_should_round_bound_up_to_tick() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements_for_market_price(&server, token_1(), &bids(), &[]);
+
+            // best bid 0.5 * 0.5 = 0.25, rounded up to the 0.1 tick
+            let signable_order = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::shares(dec!(150))?)
+                .side(Side::Sell)
+                .max_slippage(dec!(0.5))
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.order.makerAmount, U256::from(150_000_000));
+            assert_eq!(signable_order.order.takerAmount, U256::from(45_000_000)); // 150 * 0.3
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn max_slippage_should_clamp_bound_to_valid_prices() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements_for_market_price(&server, token_1(), &bids(), &asks());
+
+            // best ask 0.4 * 3 = 1.2, clamped to 1 - 0.1
+            let signable_order = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::usdc(dec!(90))?)
+                .side(Side::Buy)
+                .max_slippage(dec!(2))
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.order.makerAmount, U256::from(90_000_000));
+            assert_eq!(signable_order.order.takerAmount, U256::from(100_000_000)); // 90 / 0.9
+
+            // best bid 0.5 * -0.5 = -0.25, clamped to 0.1
+            let signable_order = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::shares(dec!(150))?)
+                .side(Side::Sell)
+                .max_slippage(dec!(1.5))
+                .build()
+                .await?;
+
+            assert_eq!(signable_order.order.makerAmount, U256::from(150_000_000));
+            assert_eq!(signable_order.order.takerAmount, U256::from(15_000_000)); // 150 * 0.1
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn sell_beyond_max_slippage_should_fail() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements_for_market_price(&server, token_1(), &bids(), &[]);
+
+            let err = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::shares(dec!(150))?)
+                .side(Side::Sell)
+                .max_slippage(dec!(0.3))
+                .build()
+                .await
+                .unwrap_err();
+
+            let liquidity = err.downcast_ref::<InsufficientLiquidity>().unwrap();
+            assert_eq!(liquidity.worst_price, dec!(0.35));
+            assert_eq!(liquidity.fillable, dec!(100));
+            Ok(())
+        }
+
+        #[tokio::test]
+        async fn conflicting_bounds_should_fail() -> anyhow::Result<()> {
+            let server = MockServer::start();
+            let client = create_authenticated(&server).await?;
+
+            ensure_requirements_for_market_price(&server, token_1(), &[], &asks());
+
+            let err = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::shares(dec!(100))?)
+                .side(Side::Buy)
+                .price(dec!(0.5))
+                .worst_price(dec!(0.5))
+                .build()
+                .await
+                .unwrap_err();
+            let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+            assert_eq!(
+                msg,
+                "Cannot set a price together with a worst price or maximum slippage"
+            );
+
+            let err = client
+                .market_order()
+                .token_id(token_1())
+                .amount(Amount::shares(dec!(100))?)
+                .side(Side::Buy)
+                .worst_price(dec!(0.5))
+                .max_slippage(dec!(0.1))
+                .build()
+                .await
+                .unwrap_err();
+            let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+            assert_eq!(
+                msg,
+                "Cannot set both a worst price and a maximum slippage for a market order"
+            );
+            Ok(())
+        }
+    }
+}
+
+mod sign {
+    use alloy::dyn_abi::Eip712Domain;
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use alloy::sol_types::SolStruct as _;
+    use polymarket_client_sdk::{POLYGON, contract_config};
+    use serde_json::json;
+
+    use super::*;
+    use crate::common::PRIVATE_KEY;
+
+    fn domain(neg_risk: bool) -> Eip712Domain {
+        Eip712Domain::new(
+            Some("Polymarket CTF Exchange".into()),
+            Some("1".into()),
+            Some(U256::from(POLYGON)),
+            Some(contract_config(POLYGON, neg_risk).unwrap().exchange),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn neg_risk_order_should_be_signed_against_neg_risk_exchange() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/neg-risk");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "neg_risk": true }));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/fee-rate");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "base_fee": 0 }));
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/tick-size");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.01" }));
+        });
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(Decimal::TEN)
+            .side(Side::Buy)
+            .build()
+            .await?;
+        let signed_order = client.sign(&signer, order).await?;
+
+        let neg_risk_hash = signed_order.order.eip712_signing_hash(&domain(true));
+        let hash = signed_order.order.eip712_signing_hash(&domain(false));
+
+        assert_ne!(neg_risk_hash, hash);
+        assert_eq!(
+            signed_order
+                .signature
+                .recover_address_from_prehash(&neg_risk_hash)?,
+            signer.address()
+        );
+        assert_ne!(
+            signed_order.signature.recover_address_from_prehash(&hash)?,
+            signer.address()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn order_should_be_signed_against_exchange() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        ensure_requirements(&server, token_1(), TickSize::Hundredth);
+
+        let order = client
+            .limit_order()
+            .token_id(token_1())
+            .price(dec!(0.5))
+            .size(Decimal::TEN)
+            .side(Side::Buy)
+            .build()
+            .await?;
+        let signed_order = client.sign(&signer, order).await?;
+
+        let hash = signed_order.order.eip712_signing_hash(&domain(false));
+
+        assert_eq!(
+            signed_order.signature.recover_address_from_prehash(&hash)?,
+            signer.address()
+        );
 
-        assert_eq!(msg, "Sell Orders must specify their `amount`s in shares");
         Ok(())
     }
 }
@@ -0,0 +1,113 @@
+#![cfg(all(feature = "clob", feature = "data"))]
+
+use httpmock::{Method::POST, MockServer};
+use polymarket_client_sdk::clob;
+use polymarket_client_sdk::data::types::response::Position;
+use polymarket_client_sdk::portfolio::{PriceSource, enrich_positions};
+use polymarket_client_sdk::types::Decimal;
+use reqwest::StatusCode;
+use rust_decimal_macros::dec;
+use serde_json::json;
+
+fn position(asset: &str, size: f64, avg_price: f64, cur_price: f64, redeemable: bool) -> Position {
+    serde_json::from_value(json!({
+        "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+        "asset": asset,
+        "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+        "size": size,
+        "avgPrice": avg_price,
+        "initialValue": 0,
+        "currentValue": 0,
+        "cashPnl": 0,
+        "percentPnl": 0,
+        "totalBought": size,
+        "realizedPnl": 0,
+        "percentRealizedPnl": 0,
+        "curPrice": cur_price,
+        "redeemable": redeemable,
+        "mergeable": false,
+        "title": "Will BTC hit $100k?",
+        "slug": "btc-100k",
+        "icon": "https://example.com/btc.png",
+        "eventSlug": "crypto-prices",
+        "outcome": "Yes",
+        "outcomeIndex": 0,
+        "oppositeOutcome": "No",
+        "oppositeAsset": "1",
+        "endDate": "2025-12-31",
+        "negativeRisk": false
+    }))
+    .expect("valid position")
+}
+
+#[test]
+fn position_should_compute_value_and_pnl() {
+    let position = position("100", 100.0, 0.4, 0.5, false);
+
+    assert_eq!(position.market_value(dec!(0.55)), dec!(55));
+    assert_eq!(position.unrealized_pnl(dec!(0.55)), dec!(15));
+    assert_eq!(position.unrealized_pnl(Decimal::ZERO), dec!(-40));
+    assert_eq!(position.unrealized_pnl(Decimal::ONE), dec!(60));
+}
+
+#[tokio::test]
+async fn enrich_positions_should_value_at_midpoints() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let client = clob::Client::new(&server.base_url(), clob::Config::default())?;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path("/midpoints")
+            .json_body(json!([{ "token_id": "100" }, { "token_id": "200" }]));
+        then.status(StatusCode::OK)
+            .json_body(json!({ "100": "0.55" }));
+    });
+
+    let positions = vec![
+        position("100", 100.0, 0.4, 0.5, false),
+        position("300", 10.0, 0.3, 1.0, true),
+        position("200", 20.0, 0.5, 0.25, false),
+    ];
+
+    let enriched = enrich_positions(&client, positions).await?;
+
+    assert_eq!(enriched.len(), 3);
+
+    assert_eq!(enriched[0].price_source, PriceSource::Midpoint);
+    assert_eq!(enriched[0].current_price, dec!(0.55));
+    assert_eq!(enriched[0].market_value, dec!(55));
+    assert_eq!(enriched[0].unrealized_pnl, dec!(15));
+
+    assert_eq!(enriched[1].price_source, PriceSource::Settlement);
+    assert_eq!(enriched[1].current_price, Decimal::ONE);
+    assert_eq!(enriched[1].market_value, dec!(10));
+    assert_eq!(enriched[1].unrealized_pnl, dec!(7));
+
+    assert_eq!(enriched[2].price_source, PriceSource::DataApi);
+    assert_eq!(enriched[2].current_price, dec!(0.25));
+    assert_eq!(enriched[2].unrealized_pnl, dec!(-5));
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn enrich_positions_should_skip_request_when_all_resolved() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let client = clob::Client::new(&server.base_url(), clob::Config::default())?;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/midpoints");
+        then.status(StatusCode::OK).json_body(json!({}));
+    });
+
+    let enriched = enrich_positions(&client, vec![position("300", 10.0, 0.3, 0.0, true)]).await?;
+
+    assert_eq!(enriched[0].price_source, PriceSource::Settlement);
+    assert_eq!(enriched[0].market_value, Decimal::ZERO);
+    assert_eq!(enriched[0].unrealized_pnl, dec!(-3));
+    mock.assert_calls(0);
+
+    Ok(())
+}
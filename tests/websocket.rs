@@ -12,7 +12,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use futures_util::{SinkExt as _, StreamExt as _};
-use polymarket_client_sdk::clob::ws::{Client, WsMessage};
+use polymarket_client_sdk::clob::ws::{ChannelType, Client, StreamEvent, WsMessage};
 use polymarket_client_sdk::types::{Address, U256, b256};
 use polymarket_client_sdk::ws::config::Config;
 use serde_json::json;
@@ -923,6 +923,35 @@ mod reconnection {
         );
     }
 
+    #[tokio::test]
+    async fn market_stream_resubscribes_current_tokens_after_reconnect() {
+        let mut server = ReconnectableMockServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, config()).unwrap();
+
+        let asset1 = payloads::asset_id();
+        let asset2 = payloads::other_asset_id();
+
+        let stream = client.subscribe_market_stream(vec![asset1]).unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+
+        stream.subscribe_tokens(&[asset2]).unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+        stream.unsubscribe_tokens(&[asset1]).unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+
+        server.disconnect_all();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        server.allow_reconnect();
+
+        let resub = server.recv_subscription().await.unwrap();
+        assert!(
+            resub.contains(&asset2.to_string()) && !resub.contains(&asset1.to_string()),
+            "Re-subscription should contain only the current tokens, got: {resub}"
+        );
+    }
+
     #[tokio::test]
     async fn preserves_custom_features_after_reconnect() {
         let mut server = ReconnectableMockServer::start().await;
@@ -1035,6 +1064,84 @@ mod reconnection {
             "Should receive best_bid_ask message after reconnection - this was the bug in issue #185"
         );
     }
+
+    #[tokio::test]
+    async fn with_reconnects_reports_reconnection_before_new_messages() {
+        let mut server = ReconnectableMockServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, config()).unwrap();
+
+        let asset_id = payloads::asset_id();
+        let stream = client.subscribe_orderbook(vec![asset_id]).unwrap();
+        let stream = client.with_reconnects(ChannelType::Market, stream).unwrap();
+        let mut stream = Box::pin(stream);
+
+        let _: Option<String> = server.recv_subscription().await;
+
+        // The initial connection is not reported as a reconnection
+        server.send(&payloads::book().to_string());
+        let event = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert!(matches!(event, StreamEvent::Message(_)));
+
+        server.disconnect_all();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        server.allow_reconnect();
+
+        let resub = server.recv_subscription().await;
+        assert!(resub.is_some(), "Should receive re-subscription");
+
+        server.send(&payloads::book().to_string());
+        let event = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert!(matches!(event, StreamEvent::Reconnected));
+
+        let event = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert!(matches!(event, StreamEvent::Message(_)));
+    }
+
+    #[tokio::test]
+    async fn reconnects_when_pong_is_not_received() {
+        // The mock server never answers PING, which looks like a half-open connection
+        let mut server = ReconnectableMockServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let mut config = config();
+        config.stream.ping_interval = Duration::from_millis(50);
+        config.stream.pong_timeout = Duration::from_millis(100);
+
+        let client = Client::new(&endpoint, config).unwrap();
+
+        let asset_id = payloads::asset_id();
+        let stream = client.subscribe_orderbook(vec![asset_id]).unwrap();
+        let mut stream = Box::pin(stream);
+
+        let sub_request = server.recv_subscription().await.unwrap();
+        assert!(sub_request.contains(&asset_id.to_string()));
+
+        // No explicit disconnect: the missing PONG alone must trigger a reconnect
+        let resub = server.recv_subscription().await;
+        assert!(
+            resub.is_some(),
+            "Should re-subscribe after the PONG timeout forces a reconnect"
+        );
+        assert!(resub.unwrap().contains(&asset_id.to_string()));
+
+        server.send(&payloads::book().to_string());
+        let msg = timeout(Duration::from_secs(2), stream.next()).await;
+        assert!(msg.is_ok(), "Should receive messages on the new connection");
+    }
 }
 
 mod unsubscribe {
@@ -1766,3 +1873,463 @@ mod message_parsing {
         assert_eq!(ltp.timestamp, 1_750_428_146_322);
     }
 }
+
+mod market_stream {
+    use super::*;
+    use crate::payloads::{ASSET_ID_STR, OTHER_ASSET_ID_STR};
+
+    #[tokio::test]
+    async fn subscribe_tokens_sends_delta_and_receives_messages() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let mut stream = client
+            .subscribe_market_stream(vec![payloads::asset_id()])
+            .unwrap();
+        let sub = server.recv_subscription().await.unwrap();
+        assert!(sub.contains(ASSET_ID_STR));
+
+        stream
+            .subscribe_tokens(&[payloads::other_asset_id()])
+            .unwrap();
+        let delta = server.recv_subscription().await.unwrap();
+        assert!(delta.contains(OTHER_ASSET_ID_STR));
+        assert!(!delta.contains(ASSET_ID_STR));
+
+        server.send(&payloads::last_trade_price(OTHER_ASSET_ID_STR).to_string());
+
+        let message = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        let WsMessage::LastTradePrice(ltp) = message else {
+            panic!("Expected a last trade price, got {message:?}");
+        };
+        assert_eq!(ltp.asset_id, payloads::other_asset_id());
+
+        let mut tokens = stream.tokens();
+        tokens.sort_unstable();
+        let mut expected = vec![payloads::asset_id(), payloads::other_asset_id()];
+        expected.sort_unstable();
+        assert_eq!(tokens, expected);
+    }
+
+    #[tokio::test]
+    async fn already_subscribed_tokens_are_ignored() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let stream = client
+            .subscribe_market_stream(vec![payloads::asset_id()])
+            .unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+
+        // Already followed by this stream, so nothing is sent
+        stream.subscribe_tokens(&[payloads::asset_id()]).unwrap();
+
+        // A single removal is enough to stop following the asset
+        stream.unsubscribe_tokens(&[payloads::asset_id()]).unwrap();
+
+        let unsub = server.recv_subscription().await.unwrap();
+        assert!(
+            unsub.contains("\"operation\":\"unsubscribe\""),
+            "Should send unsubscribe request, got: {unsub}"
+        );
+        assert!(unsub.contains(ASSET_ID_STR));
+        assert!(stream.tokens().is_empty());
+        assert_eq!(client.subscription_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn unsubscribe_tokens_stops_delivering_messages() {
+        let mut server = MockWsServer::start().await;
+        let endpoint = server.ws_url("/ws/market");
+
+        let client = Client::new(&endpoint, Config::default()).unwrap();
+
+        let mut stream = client
+            .subscribe_market_stream(vec![payloads::asset_id(), payloads::other_asset_id()])
+            .unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+
+        stream.unsubscribe_tokens(&[payloads::asset_id()]).unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+
+        server.send(&payloads::last_trade_price(ASSET_ID_STR).to_string());
+        server.send(&payloads::last_trade_price(OTHER_ASSET_ID_STR).to_string());
+
+        let message = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        let WsMessage::LastTradePrice(ltp) = message else {
+            panic!("Expected a last trade price, got {message:?}");
+        };
+        assert_eq!(ltp.asset_id, payloads::other_asset_id());
+    }
+}
+
+mod backpressure {
+    use polymarket_client_sdk::ws::WsError;
+    use polymarket_client_sdk::ws::config::BackpressurePolicy;
+
+    use super::*;
+
+    const CAPACITY: usize = 2;
+    const MESSAGES: usize = 10;
+
+    fn config(policy: BackpressurePolicy) -> Config {
+        let mut config = Config::default();
+        config.buffer.capacity = CAPACITY;
+        config.buffer.policy = policy;
+        config
+    }
+
+    /// Sends more messages than the buffer holds and gives the client time to read them, without
+    /// polling the stream.
+    async fn flood(server: &mut MockWsServer) {
+        let _: Option<String> = server.recv_subscription().await;
+
+        for _ in 0..MESSAGES {
+            server.send(&payloads::book().to_string());
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+
+    fn lagged_count<T>(item: Option<polymarket_client_sdk::Result<T>>) -> u64 {
+        let err = item.unwrap().err().unwrap();
+        match err.downcast_ref::<WsError>().unwrap() {
+            WsError::Lagged { count } => *count,
+            other => panic!("expected Lagged, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_reports_lag_and_continues() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(
+            &server.ws_url("/ws/market"),
+            config(BackpressurePolicy::DropOldest),
+        )
+        .unwrap();
+        let stream = client
+            .subscribe_orderbook(vec![payloads::asset_id()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        flood(&mut server).await;
+
+        let next = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap();
+        assert_eq!(lagged_count(next), (MESSAGES - CAPACITY) as u64);
+
+        for _ in 0..CAPACITY {
+            let next = timeout(Duration::from_secs(2), stream.next())
+                .await
+                .unwrap();
+            next.unwrap().unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn error_reports_lag_and_ends_stream() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(
+            &server.ws_url("/ws/market"),
+            config(BackpressurePolicy::Error),
+        )
+        .unwrap();
+        let stream = client
+            .subscribe_orderbook(vec![payloads::asset_id()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        flood(&mut server).await;
+
+        let next = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap();
+        assert_eq!(lagged_count(next), (MESSAGES - CAPACITY) as u64);
+
+        let next = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap();
+        assert!(next.is_none());
+    }
+
+    #[tokio::test]
+    async fn block_delivers_every_message() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(
+            &server.ws_url("/ws/market"),
+            config(BackpressurePolicy::Block),
+        )
+        .unwrap();
+        let stream = client
+            .subscribe_orderbook(vec![payloads::asset_id()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        flood(&mut server).await;
+
+        for _ in 0..MESSAGES {
+            let next = timeout(Duration::from_secs(2), stream.next())
+                .await
+                .unwrap();
+            next.unwrap().unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn block_resumes_once_a_stalled_stream_is_dropped() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(
+            &server.ws_url("/ws/market"),
+            config(BackpressurePolicy::Block),
+        )
+        .unwrap();
+        let stalled = client
+            .subscribe_orderbook(vec![payloads::asset_id()])
+            .unwrap();
+        let stream = client
+            .subscribe_orderbook(vec![payloads::asset_id()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+
+        flood(&mut server).await;
+
+        for _ in 0..CAPACITY {
+            let next = timeout(Duration::from_secs(2), stream.next())
+                .await
+                .unwrap();
+            next.unwrap().unwrap();
+        }
+
+        // The stalled stream holds the connection back until it is dropped
+        timeout(Duration::from_millis(200), stream.next())
+            .await
+            .unwrap_err();
+        drop(stalled);
+
+        for _ in CAPACITY..MESSAGES {
+            let next = timeout(Duration::from_secs(2), stream.next())
+                .await
+                .unwrap();
+            next.unwrap().unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn zero_capacity_is_rejected() {
+        let mut config = Config::default();
+        config.buffer.capacity = 0;
+
+        let client = Client::new("ws://127.0.0.1:1/ws/market", config).unwrap();
+        let Err(err) = client.subscribe_orderbook(vec![payloads::asset_id()]) else {
+            panic!("expected zero capacity to be rejected");
+        };
+
+        assert!(err.to_string().contains("buffer capacity"));
+    }
+}
+
+mod shutdown {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Mock WebSocket server that counts connections and reports the close frames it receives.
+    struct ClosingMockServer {
+        addr: SocketAddr,
+        connections: Arc<AtomicUsize>,
+        close_rx: mpsc::UnboundedReceiver<()>,
+    }
+
+    impl ClosingMockServer {
+        async fn start() -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+
+            let connections = Arc::new(AtomicUsize::new(0));
+            let (close_tx, close_rx) = mpsc::unbounded_channel::<()>();
+
+            let accepted = Arc::clone(&connections);
+
+            tokio::spawn(async move {
+                loop {
+                    let Ok((stream, _)) = listener.accept().await else {
+                        break;
+                    };
+
+                    let Ok(mut ws_stream) = tokio_tungstenite::accept_async(stream).await else {
+                        continue;
+                    };
+
+                    accepted.fetch_add(1, Ordering::SeqCst);
+                    let close_tx = close_tx.clone();
+
+                    // Reading a close frame queues the acknowledgment, which is flushed by the
+                    // next read
+                    tokio::spawn(async move {
+                        while let Some(Ok(msg)) = ws_stream.next().await {
+                            if matches!(msg, Message::Close(_)) {
+                                _ = close_tx.send(());
+                            }
+                        }
+                    });
+                }
+            });
+
+            Self {
+                addr,
+                connections,
+                close_rx,
+            }
+        }
+
+        fn ws_url(&self, path: &str) -> String {
+            format!("ws://{}{}", self.addr, path)
+        }
+
+        fn connections(&self) -> usize {
+            self.connections.load(Ordering::SeqCst)
+        }
+
+        async fn recv_close(&mut self) -> Option<()> {
+            timeout(Duration::from_secs(2), self.close_rx.recv())
+                .await
+                .ok()
+                .flatten()
+        }
+    }
+
+    fn config() -> Config {
+        let mut config = Config::default();
+        config.reconnect.initial_backoff = Duration::from_millis(10);
+        config.reconnect.max_backoff = Duration::from_millis(20);
+        config
+    }
+
+    async fn wait_until_connected(client: &Client) {
+        timeout(Duration::from_secs(2), async {
+            while !client.connection_state(ChannelType::Market).is_connected() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn close_sends_close_frame_and_ends_streams() {
+        let mut server = ClosingMockServer::start().await;
+        let client = Client::new(&server.ws_url("/ws/market"), config()).unwrap();
+
+        let stream = client
+            .subscribe_orderbook(vec![payloads::asset_id()])
+            .unwrap();
+        let mut stream = Box::pin(stream);
+        wait_until_connected(&client).await;
+
+        timeout(Duration::from_secs(2), client.close())
+            .await
+            .expect("close should complete once the server acknowledges");
+
+        assert!(server.recv_close().await.is_some());
+        assert!(
+            timeout(Duration::from_secs(2), stream.next())
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        // The background task must not bring the connection back
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(server.connections(), 1);
+        assert!(!client.is_connected(ChannelType::Market));
+    }
+
+    #[tokio::test]
+    async fn close_is_bounded_by_close_timeout() {
+        // Accept the connection but never read from it, so the close frame goes unanswered
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let _ws_stream = tokio_tungstenite::accept_async(stream).await.unwrap();
+            std::future::pending::<()>().await;
+        });
+
+        let mut config = config();
+        config.stream.close_timeout = Duration::from_millis(100);
+        let client = Client::new(&format!("ws://{addr}/ws/market"), config).unwrap();
+
+        let _stream = client
+            .subscribe_orderbook(vec![payloads::asset_id()])
+            .unwrap();
+        wait_until_connected(&client).await;
+
+        timeout(Duration::from_secs(2), client.close())
+            .await
+            .expect("close should give up after the close timeout");
+    }
+
+    #[tokio::test]
+    async fn market_stream_close_ends_the_stream() {
+        let mut server = ClosingMockServer::start().await;
+        let client = Client::new(&server.ws_url("/ws/market"), config()).unwrap();
+
+        let mut stream = client
+            .subscribe_market_stream(vec![payloads::asset_id()])
+            .unwrap();
+        wait_until_connected(&client).await;
+
+        stream.close().await;
+
+        assert!(server.recv_close().await.is_some());
+        assert!(
+            timeout(Duration::from_secs(2), stream.next())
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(!client.is_connected(ChannelType::Market));
+        assert_eq!(client.subscription_count(), 0);
+
+        // A new subscription replaces the closed connection
+        let _stream = client
+            .subscribe_market_stream(vec![payloads::asset_id()])
+            .unwrap();
+        wait_until_connected(&client).await;
+        assert_eq!(server.connections(), 2);
+    }
+
+    #[tokio::test]
+    async fn dropping_client_and_streams_closes_connection() {
+        let mut server = ClosingMockServer::start().await;
+        let client = Client::new(&server.ws_url("/ws/market"), config()).unwrap();
+
+        let stream = client
+            .subscribe_market_stream(vec![payloads::asset_id()])
+            .unwrap();
+        wait_until_connected(&client).await;
+
+        // A live stream keeps the connection open without the client
+        drop(client);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(server.close_rx.try_recv().is_err());
+
+        drop(stream);
+
+        assert!(server.recv_close().await.is_some());
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(server.connections(), 1);
+    }
+}